@@ -5,17 +5,35 @@
 //! On the other side, egui should provide a function to be called in every frame.
 //! All these works be done in `init` function.
 
-use std::time::Instant;
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::panic::{self, AssertUnwindSafe};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+use std::sync::Once;
+use std::time::{Duration, Instant};
 
-use egui::epaint::{ImageDelta, Primitive};
-use egui::output::OutputEvent;
+use ab_glyph::{FontRef, InvalidFont};
+use egui::epaint::textures::TexturesDelta;
+use egui::epaint::{ImageDelta, Mesh, Primitive, TessellationOptions};
+use egui::output::{OpenUrl, OutputEvent};
 use egui::{
-    ClippedPrimitive, Context, ImageData, PlatformOutput, TextureFilter, TextureId, WidgetType,
+    ClippedPrimitive, Context, CursorIcon, Event, FontData, FontFamily, FullOutput, ImageData, Key,
+    PlatformOutput, PointerButton, Pos2, RawInput, TextureFilter, TextureId, WidgetType,
 };
 use log::{set_logger, set_max_level, Level, LevelFilter, Metadata, Record};
+use protobuf::Message;
 
-use crate::input::parse_input;
-use crate::{App, Buffer};
+use crate::input::{
+    input_from_pb_to_native, key_from_pb_code, merge_pb_input_bytes, modifier_from_native_to_pb,
+    parse_pb_input_bytes, ButtonMap, ImeComposition, InputOptions, InputTransform,
+    KeyTextConvention, MultiClick,
+};
+use crate::mesh::{repack_vertices, reverse_winding, split_mesh, MeshOptions, VertexColorFormat};
+use crate::proto::input::Input;
+use crate::{App, Buffer, Frame, UeguiError};
 
 /// Unity provided functions for painting.
 /// `set_texture` add or update texture in unity.
@@ -23,126 +41,1534 @@ use crate::{App, Buffer};
 /// `begin_paint` called before paint begin, clear data for last frame.
 /// `paint_mesh` generate and paint mesh in unity.
 /// `end_paint` do something after paint in unity.
-/// `show_keyboard` show ime in android.
+/// `show_keyboard` show ime in android, the text is utf8 and its length in bytes, not the
+/// character count of a C# string, e.g. `Encoding.UTF8.GetString(text, len)` in unity.
+/// `paint_callback` paint custom content for a `UnityCallback`.
+/// `set_cursor` change cursor icon, see `cursor_icon_to_u32` for values.
+/// `open_url` open a clicked hyperlink.
+/// `set_clipboard` copied or cut text, unity should write it to `GUIUtility.systemCopyBuffer`,
+/// and send the buffer back as a paste event when pasting.
+/// `set_textures_batch` add or update several textures at once, see `UnityContext::set_textures`.
+/// `request_close` the app called `Frame::close`, unity should destroy the context.
+/// `paint_frame` paint meshes of a frame at once instead of `paint_mesh`, see
+/// `UnityContext::paint_frame`.
+/// `capture_ready` textures and meshes of a frame captured by `UnityContext::request_capture`.
+/// `set_ime_position` screen position of the text cursor in points for the IME candidate window,
+/// only called when it changes.
+/// `widget_event` every output event of widgets, e.g. clicks and value changes, see
+/// `UnityContext::widget_event`.
+/// `frame_stats` statistics of every painted frame, see `FrameStats`.
+/// `request_repaint_after` milliseconds egui is content to wait before the next `update`, the
+/// same as the exported `repaint_after` but pushed every frame.
+/// `set_render_target` render target of the context, called before every `begin_paint`, see
+/// `UnityContext::set_render_target`.
+/// `pointer_consumed` whether egui used the pointer in the frame just computed, called every frame
+/// so unity can decide to forward the next click to the scene, see `PointerState`.
+/// `show_keyboard_utf16` the same as `show_keyboard` with the length of the text in utf16 units
+/// too, the `Length` of the C# string, called instead of `show_keyboard` if not null.
+///
+/// Vertex positions and paint callback rects are always in egui points. Clip rects of
+/// `paint_mesh`, `paint_frame` and `paint_callback` are in points too unless
+/// `UnityContext::set_input_transform` set a transform, then they are in input coordinates of
+/// unity, transformed back by its inverse, while vertices stay in points.
+///
+/// A null callback is skipped instead of called. `set_texture`, `rem_texture`, `begin_paint`,
+/// `paint_mesh` and `end_paint` are required, nothing is painted without them, a null one is
+/// reported as an error when a `UnityContext` is created, see `missing_callbacks`. All others are
+/// optional and only reported at debug level, see `null_optional_callbacks`, e.g. without
+/// `set_textures_batch` textures are uploaded one by one with `set_texture`, and without
+/// `paint_frame` meshes are painted one by one with `paint_mesh`.
+///
+/// `update_accessibility` receives the `accesskit::TreeUpdate` of every frame serialized as json
+/// for the screen reader of the host, accessibility is only enabled in egui if it is not null. It
+/// is part of the layout with or without the `accesskit` feature and never called without it, so
+/// unity sees the same layout for one `ABI_VERSION`.
 #[repr(C)]
 pub struct UnityInitializer {
-    /// set_texture(id, offsetX, offsetY, width, height, filter_mode, data)
-    set_texture: extern "system" fn(u64, u32, u32, u32, u32, u32, *const u8),
+    /// set_texture(id, offsetX, offsetY, width, height, min_filter, mag_filter, format, data),
+    /// filters are 1 for point and 2 for bilinear, see `TextureFormat` for formats
+    pub set_texture: Option<extern "system" fn(u64, u32, u32, u32, u32, u32, u32, u32, *const u8)>,
     /// rem_texture(id)
-    rem_texture: extern "system" fn(u64),
+    pub rem_texture: Option<extern "system" fn(u64)>,
     /// begin_paint()
-    begin_paint: extern "system" fn(),
-    /// paint_mesh(texture_id, vertex_count, vertex_buffer, index_count, index_buffer, bound_min_x, bound_min_y, bound_max_x, bound_max_y)
-    paint_mesh: extern "system" fn(u64, u32, *const u8, u32, *const u8, f32, f32, f32, f32),
+    pub begin_paint: Option<extern "system" fn()>,
+    /// paint_mesh(texture_id, vertex_count, vertex_buffer, index_count, index_buffer, bound_min_x, bound_min_y, bound_max_x, bound_max_y),
+    /// vertex positions are in points, unity multiplies them by pixels_per_point to get pixels,
+    /// the clip rect is in points too without an input transform and in input coordinates with
+    /// one, see `UnityContext::set_input_transform`, e.g. for scissoring. The clip rect is narrowed
+    /// to the bounds of the vertices so unity can cull meshes by it, meshes entirely clipped are
+    /// not sent
+    pub paint_mesh:
+        Option<extern "system" fn(u64, u32, *const u8, u32, *const u8, f32, f32, f32, f32)>,
     /// end_paint()
-    end_paint: extern "system" fn(),
-    /// show_keyboard(show, string)
-    show_keyboard: extern "system" fn(u32, *const u8, u32),
-    /// show_log(show, string)
-    show_log: extern "system" fn(i32, *const u8, i32),
+    pub end_paint: Option<extern "system" fn()>,
+    /// show_keyboard(show, utf8, utf8_len)
+    pub show_keyboard: Option<extern "system" fn(u32, *const u8, u32)>,
+    /// show_log(level, string), see `log_level_to_unity` for levels
+    pub show_log: Option<extern "system" fn(i32, *const u8, i32)>,
+    /// paint_callback(handle, rect_min_x, rect_min_y, rect_max_x, rect_max_y, clip_min_x, clip_min_y, clip_max_x, clip_max_y),
+    /// the rect is in points and the clip rect in the space of `paint_mesh`
+    pub paint_callback: Option<extern "system" fn(u64, f32, f32, f32, f32, f32, f32, f32, f32)>,
+    /// set_cursor(cursor_icon)
+    pub set_cursor: Option<extern "system" fn(u32)>,
+    /// open_url(new_tab, url)
+    pub open_url: Option<extern "system" fn(u32, *const u8, u32)>,
+    /// set_clipboard(text)
+    pub set_clipboard: Option<extern "system" fn(*const u8, u32)>,
+    /// set_textures_batch(data, len, count)
+    pub set_textures_batch: Option<extern "system" fn(*const u8, u32, u32)>,
+    /// request_close()
+    pub request_close: Option<extern "system" fn()>,
+    /// paint_frame(data, len, count), clip rects are narrowed and in the space of `paint_mesh`
+    pub paint_frame: Option<extern "system" fn(*const u8, u32, u32)>,
+    /// capture_ready(textures, textures_len, textures_count, meshes, meshes_len, meshes_count)
+    pub capture_ready: Option<extern "system" fn(*const u8, u32, u32, *const u8, u32, u32)>,
+    /// set_ime_position(visible, x, y), visible is 0 when no text edit has focus
+    pub set_ime_position: Option<extern "system" fn(u32, f32, f32)>,
+    /// widget_event(event, widget_type, label, label_len, text, text_len, value, selected)
+    pub widget_event:
+        Option<extern "system" fn(u32, u32, *const u8, u32, *const u8, u32, f64, u32)>,
+    /// frame_stats(mesh_count, vertex_count, index_count, texture_bytes, cpu_micros,
+    /// tessellate_micros)
+    pub frame_stats: Option<extern "system" fn(u32, u32, u32, u64, u64, u64)>,
+    /// set_render_target(target)
+    pub set_render_target: Option<extern "system" fn(u64)>,
+    /// request_repaint_after(millis), 0 for immediately and `u64::MAX` for no repaint until input
+    pub request_repaint_after: Option<extern "system" fn(u64)>,
+    /// pointer_consumed(over_area, using_pointer, wants_pointer_input), see `PointerState`
+    pub pointer_consumed: Option<extern "system" fn(u32, u32, u32)>,
+    /// show_keyboard_utf16(show, utf8, utf8_len, utf16_len)
+    pub show_keyboard_utf16: Option<extern "system" fn(u32, *const u8, u32, u32)>,
+    /// update_accessibility(json, len), ignored without the `accesskit` feature
+    pub update_accessibility: Option<extern "system" fn(*const u8, u32)>,
 }
 
+impl UnityInitializer {
+    /// Names of required callbacks unity left null, reported as errors when a `UnityContext` is
+    /// created.
+    pub fn missing_callbacks(&self) -> Vec<&'static str> {
+        [
+            ("set_texture", self.set_texture.is_none()),
+            ("rem_texture", self.rem_texture.is_none()),
+            ("begin_paint", self.begin_paint.is_none()),
+            ("paint_mesh", self.paint_mesh.is_none()),
+            ("end_paint", self.end_paint.is_none()),
+        ]
+        .into_iter()
+        .filter_map(|(name, missing)| missing.then_some(name))
+        .collect()
+    }
+
+    /// Names of optional callbacks unity left null, reported at debug level when a
+    /// `UnityContext` is created, `show_keyboard` is null only if `show_keyboard_utf16` is too.
+    pub fn null_optional_callbacks(&self) -> Vec<&'static str> {
+        [
+            (
+                "show_keyboard",
+                self.show_keyboard.is_none() && self.show_keyboard_utf16.is_none(),
+            ),
+            ("show_log", self.show_log.is_none()),
+            ("paint_callback", self.paint_callback.is_none()),
+            ("set_cursor", self.set_cursor.is_none()),
+            ("open_url", self.open_url.is_none()),
+            ("set_clipboard", self.set_clipboard.is_none()),
+            ("set_textures_batch", self.set_textures_batch.is_none()),
+            ("request_close", self.request_close.is_none()),
+            ("paint_frame", self.paint_frame.is_none()),
+            ("capture_ready", self.capture_ready.is_none()),
+            ("set_ime_position", self.set_ime_position.is_none()),
+            ("widget_event", self.widget_event.is_none()),
+            ("frame_stats", self.frame_stats.is_none()),
+            ("set_render_target", self.set_render_target.is_none()),
+            (
+                "request_repaint_after",
+                self.request_repaint_after.is_none(),
+            ),
+            ("pointer_consumed", self.pointer_consumed.is_none()),
+            ("update_accessibility", self.update_accessibility.is_none()),
+        ]
+        .into_iter()
+        .filter_map(|(name, missing)| missing.then_some(name))
+        .collect()
+    }
+
+    /// Log required callbacks unity left null as errors and optional ones at debug level.
+    fn report_null_callbacks(&self) {
+        for name in self.missing_callbacks() {
+            log::error!("unity callback `{}` is null, skipped", name);
+        }
+        for name in self.null_optional_callbacks() {
+            log::debug!("optional unity callback `{}` is null", name);
+        }
+    }
+
+    /// Send logs to `show_log` of this initializer, the logger is installed on the first call and
+    /// later calls only replace the callback.
+    pub fn init_log(&self) {
+        LOGGER.show_log.store(
+            self.show_log
+                .map_or(std::ptr::null_mut(), |show_log| show_log as *mut ()),
+            Ordering::Release,
+        );
+        INSTALL_LOGGER.call_once(|| {
+            if set_logger(&LOGGER).is_ok() {
+                set_max_level(LevelFilter::Trace);
+            }
+        });
+    }
+}
+
+/// Paint callback implemented in unity, `handle` is passed to `paint_callback` so unity knows
+/// what to paint, e.g. a 3D viewport rendered into the rect.
+/// ```
+/// # fn ui(ui: &mut egui::Ui) {
+/// let rect = ui.available_rect_before_wrap();
+/// ui.painter().add(uegui::UnityCallback::paint_callback(rect, 1));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnityCallback {
+    pub handle: u64,
+}
+
+impl UnityCallback {
+    /// Create a paint callback which will be forwarded to unity with `handle`.
+    pub fn paint_callback(rect: egui::Rect, handle: u64) -> egui::PaintCallback {
+        egui::PaintCallback {
+            rect,
+            callback: std::sync::Arc::new(Self { handle }),
+        }
+    }
+}
+
+/// Built-in egui windows for inspecting egui internals.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DebugWindow {
+    /// `Context::settings_ui`
+    Settings,
+    /// `Context::inspection_ui`
+    Inspection,
+    /// `Context::memory_ui`
+    Memory,
+}
+
+#[derive(Default)]
+struct DebugWindows {
+    settings: bool,
+    inspection: bool,
+    memory: bool,
+}
+
+/// Logger forwarding records to unity, installed once for the whole process by `init_log`.
 pub struct UnityLogger {
-    show_log: extern "system" fn(i32, *const u8, i32),
-    log_level: LevelFilter,
+    /// `show_log` of the latest initialized context, used outside of frames, null before
+    /// `init_log` and after the last context is dropped.
+    show_log: AtomicPtr<()>,
+    log_level: AtomicUsize,
+    /// Live contexts, `show_log` is cleared when the last one is dropped, so logs from threads
+    /// left over don't call into a unity domain which may be unloaded.
+    contexts: AtomicUsize,
+}
+
+static LOGGER: UnityLogger = UnityLogger {
+    show_log: AtomicPtr::new(std::ptr::null_mut()),
+    log_level: AtomicUsize::new(LevelFilter::Trace as usize),
+    contexts: AtomicUsize::new(0),
+};
+
+static INSTALL_LOGGER: Once = Once::new();
+
+type ShowLog = extern "system" fn(i32, *const u8, i32);
+
+thread_local! {
+    /// `show_log` of the context running a frame on this thread, preferred over the latest
+    /// initialized one, so logs of every context go to its own unity side.
+    static SCOPED_SHOW_LOG: Cell<Option<ShowLog>> = const { Cell::new(None) };
+    /// A record of this thread is being logged, see `LogGuard`.
+    static IN_LOG: Cell<bool> = const { Cell::new(false) };
+    /// A panic unwound out of the logger on this thread and wasn't reported yet.
+    static LOG_PANICKED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Marks a record being logged, a record logged meanwhile, e.g. by `show_log` calling back into
+/// rust, is dropped, and a panic unwinding out of the logger is remembered for
+/// `panicked_in_log`.
+struct LogGuard;
+
+impl LogGuard {
+    fn enter() -> Option<Self> {
+        IN_LOG
+            .try_with(|in_log| !in_log.replace(true))
+            .ok()?
+            .then_some(Self)
+    }
+}
+
+impl Drop for LogGuard {
+    fn drop(&mut self) {
+        let _ = IN_LOG.try_with(|in_log| in_log.set(false));
+        if std::thread::panicking() {
+            let _ = LOG_PANICKED.try_with(|panicked| panicked.set(true));
+        }
+    }
+}
+
+/// Returns true once after a panic unwound out of the logger on this thread, e.g. formatting a
+/// record panicked, the panic is then not logged again so a broken logger degrades to silence
+/// instead of panicking while panicking, which aborts the process.
+pub fn panicked_in_log() -> bool {
+    LOG_PANICKED
+        .try_with(|panicked| panicked.replace(false))
+        .unwrap_or(true)
+}
+
+/// Route logs of this thread to `show_log` until dropped.
+struct LogScope(Option<ShowLog>);
+
+impl LogScope {
+    fn enter(show_log: Option<ShowLog>) -> Self {
+        Self(SCOPED_SHOW_LOG.with(|scoped| scoped.replace(show_log)))
+    }
+}
+
+impl Drop for LogScope {
+    fn drop(&mut self) {
+        SCOPED_SHOW_LOG.with(|scoped| scoped.set(self.0));
+    }
+}
+
+/// Consecutive panicking frames entering safe mode, see `UnityContext::is_safe_mode`.
+pub const SAFE_MODE_FAILURES: usize = 3;
+
+/// Bit of `UnityContext::peek_input_state`, egui wanted keyboard input in the last frame.
+pub const WANTS_KEYBOARD_INPUT: u32 = 1;
+/// Bit of `UnityContext::peek_input_state`, egui wanted pointer input in the last frame.
+pub const WANTS_POINTER_INPUT: u32 = 2;
+/// Bit of `UnityContext::peek_input_state`, a text edit had the IME open in the last frame.
+pub const WANTS_IME: u32 = 4;
+
+/// Statistics of a painted frame for profiling, sent to `frame_stats`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FrameStats {
+    /// Meshes painted, after splitting for 16 bit indices.
+    pub mesh_count: u32,
+    pub vertex_count: u32,
+    pub index_count: u32,
+    /// Bytes of texture pixels uploaded.
+    pub texture_bytes: u64,
+    /// Time spent in `App::update` and egui.
+    pub cpu_micros: u64,
+    /// Time spent tessellating shapes into meshes.
+    pub tessellate_micros: u64,
+}
+
+/// How egui used the pointer in a frame, sent to `pointer_consumed` after `end_frame`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PointerState {
+    /// The pointer is over an area of egui, e.g. a window or panel, a click there belongs to
+    /// egui.
+    pub over_area: bool,
+    /// A widget of egui is being interacted with, e.g. dragged.
+    pub using_pointer: bool,
+    /// egui wants pointer input, see `egui::Context::wants_pointer_input`.
+    pub wants_pointer_input: bool,
+}
+
+impl FrameStats {
+    fn add_mesh(&mut self, mesh: &Mesh) {
+        self.mesh_count += 1;
+        self.vertex_count += mesh.vertices.len() as u32;
+        self.index_count += mesh.indices.len() as u32;
+    }
+}
+
+/// Pixel format of textures sent to unity in `set_texture`, `set_textures_batch` and
+/// `capture_ready`, 4 bytes per pixel in sRGB, bit 0 of the value is set for BGRA order and bit 1
+/// for straight alpha.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextureFormat {
+    /// RGBA with premultiplied alpha as egui keeps it, `TextureFormat.RGBA32` in unity.
+    #[default]
+    Rgba32 = 0,
+    /// BGRA with premultiplied alpha, `TextureFormat.BGRA32` in unity.
+    Bgra32 = 1,
+    /// RGBA with straight alpha, `TextureFormat.RGBA32` in unity for shaders blending
+    /// with `SrcAlpha OneMinusSrcAlpha`.
+    Rgba32Straight = 2,
+    /// BGRA with straight alpha, `TextureFormat.BGRA32` in unity.
+    Bgra32Straight = 3,
+}
+
+impl TextureFormat {
+    /// Format of a value sent by unity, `None` if unknown.
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Rgba32),
+            1 => Some(Self::Bgra32),
+            2 => Some(Self::Rgba32Straight),
+            3 => Some(Self::Bgra32Straight),
+            _ => None,
+        }
+    }
+
+    /// Convert premultiplied RGBA pixels of egui to this format in place.
+    pub fn convert(self, pixels: &mut [egui::Color32]) {
+        if self == Self::Rgba32 {
+            return;
+        }
+        let bgra = self as u32 & 1 != 0;
+        let straight = self as u32 & 2 != 0;
+        // divided in gamma space, unity blends colors of sRGB textures as they are stored
+        let unmultiply = |c: u8, a: u8| match a {
+            0 => 0,
+            a => ((c as u32 * 255 + a as u32 / 2) / a as u32).min(255) as u8,
+        };
+        for pixel in pixels {
+            let [r, g, b, a] = pixel.to_array();
+            let [r, g, b] = if straight {
+                [unmultiply(r, a), unmultiply(g, a), unmultiply(b, a)]
+            } else {
+                [r, g, b]
+            };
+            let [r, b] = if bgra { [b, r] } else { [r, b] };
+            *pixel = egui::Color32::from_rgba_premultiplied(r, g, b, a);
+        }
+    }
+}
+
+/// Texture delta converted for unity.
+struct TextureUpload {
+    id: u64,
+    offset_x: u32,
+    offset_y: u32,
+    width: u32,
+    height: u32,
+    min_filter: u32,
+    mag_filter: u32,
+    pixels: Vec<egui::Color32>,
+}
+
+/// App with the configuration of its context, returned by the app closure of `init!` instead of
+/// the app to configure the context in one place, the app alone converts to a builder keeping
+/// the defaults.
+/// ```
+/// # struct MyApp;
+/// # impl uegui::App for MyApp {
+/// #     fn update(&mut self, _ctx: &egui::Context) {}
+/// # }
+/// uegui::init!(MyApp, |_cc| uegui::UnityContextBuilder::new(MyApp)
+///     .with_gamma(1.2)
+///     .with_log_level(log::LevelFilter::Warn));
+/// ```
+pub struct UnityContextBuilder<T> {
+    app: T,
+    font_gamma: Option<f32>,
+    log_level: Option<LevelFilter>,
+    render_target: u64,
+    tessellation_options: Option<TessellationOptions>,
+    max_texture_side: Option<usize>,
+    catch_panics: bool,
+}
+
+impl<T: App> UnityContextBuilder<T> {
+    pub fn new(app: T) -> Self {
+        Self {
+            app,
+            font_gamma: None,
+            log_level: None,
+            render_target: 0,
+            tessellation_options: None,
+            max_texture_side: None,
+            catch_panics: true,
+        }
+    }
+
+    /// Gamma of the font atlas, see `UnityContext::set_font_gamma`.
+    pub fn with_gamma(mut self, gamma: f32) -> Self {
+        self.font_gamma = Some(gamma);
+        self
+    }
+
+    /// Level of logs sent to unity, see `UnityContext::set_log_level`.
+    pub fn with_log_level(mut self, level: LevelFilter) -> Self {
+        self.log_level = Some(level);
+        self
+    }
+
+    /// RenderTexture meshes are painted to, see `UnityContext::set_render_target`.
+    pub fn with_render_target(mut self, target: u64) -> Self {
+        self.render_target = target;
+        self
+    }
+
+    /// Options used to tessellate shapes, see `UnityContext::set_tessellation_options`, they
+    /// replace the options set in `App::setup`.
+    pub fn with_tessellation_options(mut self, options: TessellationOptions) -> Self {
+        self.tessellation_options = Some(options);
+        self
+    }
+
+    /// Largest texture side used when input doesn't provide one, see
+    /// `UnityContext::set_max_texture_side`.
+    pub fn with_max_texture_side(mut self, side: usize) -> Self {
+        self.max_texture_side = Some(side);
+        self
+    }
+
+    /// Whether panics of frames are caught, see `UnityContext::set_catch_panics`.
+    pub fn with_catch_panics(mut self, catch: bool) -> Self {
+        self.catch_panics = catch;
+        self
+    }
+
+    /// Create the context driven by unity, used by `UnityContext::from_builder`.
+    fn build(self, initializer: UnityInitializer, context: Context) -> UnityContext<T> {
+        let mut unity_context = UnityContext::with_app(initializer, context, self.app);
+        unity_context.set_font_gamma(self.font_gamma);
+        if let Some(level) = self.log_level {
+            unity_context.set_log_level(level);
+        }
+        unity_context.set_render_target(self.render_target);
+        if let Some(options) = self.tessellation_options {
+            unity_context.set_tessellation_options(options);
+        }
+        unity_context.set_max_texture_side(self.max_texture_side);
+        unity_context.set_catch_panics(self.catch_panics);
+        unity_context
+    }
+}
+
+impl<T: App> From<T> for UnityContextBuilder<T> {
+    fn from(app: T) -> Self {
+        Self::new(app)
+    }
 }
 
 /// Context used by unity.
 pub struct UnityContext<T: App> {
     context: Context,
     unity: UnityInitializer,
-    logger: UnityLogger,
     app: T,
+    /// Text of the focused text edit.
     text: String,
+    /// Keyboard state and text sent in `show_keyboard` last time.
+    keyboard: Option<(bool, String)>,
+    consumed_keys: Vec<Key>,
+    input_options: InputOptions,
+    /// Size of every texture fully uploaded to unity and not removed yet.
+    textures: HashMap<TextureId, [usize; 2]>,
+    /// Focus state of the last frame.
+    has_focus: bool,
+    /// How long egui is content to wait before the next frame.
+    repaint_after: Duration,
+    mesh_options: MeshOptions,
+    texture_format: TextureFormat,
+    /// Reused buffer for repacked vertices.
+    vertices: Vec<u8>,
+    /// Reused buffer for converted texture pixels.
+    pixels: Vec<egui::Color32>,
+    debug_windows: DebugWindows,
+    /// Log frames after which egui wants to repaint immediately.
+    debug_repaint: bool,
+    /// Safe area insets in pixels.
+    safe_area: egui::Margin,
+    /// Screen rect from unity before applying the safe area.
+    screen_rect: Option<egui::Rect>,
+    /// Cursor icon sent to unity last time.
+    cursor_icon: Option<CursorIcon>,
+    /// Text cursor position sent to unity last time.
+    ime_position: Option<egui::Pos2>,
+    composition: ImeComposition,
+    multi_click: MultiClick,
+    /// Consecutive frames `App::update` panicked.
+    failures: usize,
+    /// Catch panics of frames, only turned off in debug builds.
+    catch_panics: bool,
+    /// Textures of frames ended by a panic, sent with the next frame.
+    aborted_textures: Rc<RefCell<TexturesDelta>>,
+    /// Gamma applied to font coverage when uploading font textures.
+    font_gamma: Option<f32>,
+    frame: Frame,
+    /// RenderTexture meshes are painted to, 0 for the target bound by unity.
+    render_target: u64,
+    /// Time of the last frame, later input never goes before it.
+    last_time: Option<f64>,
+    /// Added to input time after unity's clock went back, see `keep_time_monotonic`.
+    time_offset: f64,
+    /// Last pointer position from unity, used for button events without position.
+    pointer_pos: Option<Pos2>,
+    /// Events queued by unity outside of input, added to the next frame.
+    queued_events: Vec<Event>,
+    /// Input chunks pushed by unity, not complete until the last one.
+    input_chunks: Vec<u8>,
+    /// Input assembled from chunks, merged into the input of the next frame.
+    chunked_input: Option<Vec<u8>>,
+    /// Events pushed by the app with `Frame::push_event`, added before the input of the next frame.
+    app_events: Vec<Event>,
+    /// Pixels per point set by unity at runtime, used instead of the one in input.
+    pixels_per_point: Option<f32>,
+    /// Statistics of the current or last frame.
+    stats: FrameStats,
+    /// Pointer state of the last frame.
+    pointer: PointerState,
+    /// Capture the next frame for `capture_ready`.
+    capture_requested: bool,
+    /// Fonts added by unity, installed after the next frame.
+    fonts: Vec<(String, FontData, FontFamily)>,
+    /// Fallback order of families set by unity, applied after added fonts.
+    font_fallbacks: Vec<(FontFamily, Vec<String>)>,
 }
 
-fn texture_id_to_u64(id: TextureId) -> u64 {
+/// Encode texture id passed to unity, the lowest bit is set for user textures.
+pub fn texture_id_to_u64(id: TextureId) -> u64 {
     match id {
         TextureId::Managed(id) => id << 1,
-        TextureId::User(id) => id << 1 + 1,
+        TextureId::User(id) => (id << 1) | 1,
+    }
+}
+
+/// Stable value of every cursor icon passed to `set_cursor`.
+pub fn cursor_icon_to_u32(icon: CursorIcon) -> u32 {
+    match icon {
+        CursorIcon::Default => 0,
+        CursorIcon::None => 1,
+        CursorIcon::ContextMenu => 2,
+        CursorIcon::Help => 3,
+        CursorIcon::PointingHand => 4,
+        CursorIcon::Progress => 5,
+        CursorIcon::Wait => 6,
+        CursorIcon::Cell => 7,
+        CursorIcon::Crosshair => 8,
+        CursorIcon::Text => 9,
+        CursorIcon::VerticalText => 10,
+        CursorIcon::Alias => 11,
+        CursorIcon::Copy => 12,
+        CursorIcon::Move => 13,
+        CursorIcon::NoDrop => 14,
+        CursorIcon::NotAllowed => 15,
+        CursorIcon::Grab => 16,
+        CursorIcon::Grabbing => 17,
+        CursorIcon::AllScroll => 18,
+        CursorIcon::ResizeHorizontal => 19,
+        CursorIcon::ResizeNeSw => 20,
+        CursorIcon::ResizeNwSe => 21,
+        CursorIcon::ResizeVertical => 22,
+        CursorIcon::ResizeEast => 23,
+        CursorIcon::ResizeSouthEast => 24,
+        CursorIcon::ResizeSouth => 25,
+        CursorIcon::ResizeSouthWest => 26,
+        CursorIcon::ResizeWest => 27,
+        CursorIcon::ResizeNorthWest => 28,
+        CursorIcon::ResizeNorth => 29,
+        CursorIcon::ResizeNorthEast => 30,
+        CursorIcon::ResizeColumn => 31,
+        CursorIcon::ResizeRow => 32,
+        CursorIcon::ZoomIn => 33,
+        CursorIcon::ZoomOut => 34,
+    }
+}
+
+/// Stable value of every widget type passed to `widget_event`.
+pub fn widget_type_to_u32(typ: WidgetType) -> u32 {
+    match typ {
+        WidgetType::Label => 0,
+        WidgetType::Link => 1,
+        WidgetType::TextEdit => 2,
+        WidgetType::Button => 3,
+        WidgetType::Checkbox => 4,
+        WidgetType::RadioButton => 5,
+        WidgetType::SelectableLabel => 6,
+        WidgetType::ComboBox => 7,
+        WidgetType::Slider => 8,
+        WidgetType::DragValue => 9,
+        WidgetType::ColorButton => 10,
+        WidgetType::ImageButton => 11,
+        WidgetType::CollapsingHeader => 12,
+        WidgetType::Other => 13,
+    }
+}
+
+/// Stable value of every output event passed to `widget_event`.
+pub fn output_event_to_u32(event: &OutputEvent) -> u32 {
+    match event {
+        OutputEvent::Clicked(_) => 0,
+        OutputEvent::DoubleClicked(_) => 1,
+        OutputEvent::TripleClicked(_) => 2,
+        OutputEvent::FocusGained(_) => 3,
+        OutputEvent::TextSelectionChanged(_) => 4,
+        OutputEvent::ValueChanged(_) => 5,
+    }
+}
+
+/// Decode texture id encoded by `texture_id_to_u64`.
+pub fn u64_to_texture_id(id: u64) -> TextureId {
+    if id & 1 == 0 {
+        TextureId::Managed(id >> 1)
+    } else {
+        TextureId::User(id >> 1)
     }
 }
 
 impl<T: App> UnityContext<T> {
     pub fn new<C: FnOnce(&Context) -> T>(initializer: UnityInitializer, creator: C) -> Self {
+        Self::from_builder(initializer, creator)
+    }
+
+    /// Same as `new` with `creator` returning either the app or a `UnityContextBuilder`.
+    pub fn from_builder<B: Into<UnityContextBuilder<T>>, C: FnOnce(&Context) -> B>(
+        initializer: UnityInitializer,
+        creator: C,
+    ) -> Self {
+        match Self::try_new(initializer, |context| Ok::<_, Infallible>(creator(context))) {
+            Ok(context) => context,
+            Err(never) => match never {},
+        }
+    }
+
+    /// Same as `from_builder` with a fallible `creator`, its error is returned and nothing is
+    /// kept.
+    pub fn try_new<B: Into<UnityContextBuilder<T>>, E, C: FnOnce(&Context) -> Result<B, E>>(
+        initializer: UnityInitializer,
+        creator: C,
+    ) -> Result<Self, E> {
         let context = Context::default();
-        let app = creator(&context);
+        #[cfg(feature = "accesskit")]
+        if initializer.update_accessibility.is_some() {
+            context.enable_accesskit();
+        }
+        let builder = creator(&context)?.into();
+        Ok(builder.build(initializer, context))
+    }
+
+    /// Call `App::setup` and create the context with default configuration.
+    fn with_app(initializer: UnityInitializer, context: Context, mut app: T) -> Self {
+        initializer.report_null_callbacks();
+        app.setup(&context);
+        LOGGER.contexts.fetch_add(1, Ordering::AcqRel);
         Self {
             text: "".into(),
-            logger: UnityLogger {
-                show_log: initializer.show_log,
-                log_level: LevelFilter::Trace,
-            },
+            keyboard: None,
+            consumed_keys: Vec::new(),
+            input_options: Default::default(),
+            textures: HashMap::new(),
+            has_focus: false,
+            repaint_after: Duration::ZERO,
+            mesh_options: Default::default(),
+            texture_format: Default::default(),
+            vertices: Vec::new(),
+            pixels: Vec::new(),
+            debug_windows: Default::default(),
+            debug_repaint: false,
+            safe_area: Default::default(),
+            screen_rect: None,
+            cursor_icon: None,
+            ime_position: None,
+            composition: Default::default(),
+            multi_click: Default::default(),
+            failures: 0,
+            catch_panics: true,
+            aborted_textures: Default::default(),
+            font_gamma: Some(1.0),
+            frame: Default::default(),
+            stats: Default::default(),
+            pointer: Default::default(),
+            pixels_per_point: None,
+            queued_events: Vec::new(),
+            input_chunks: Vec::new(),
+            chunked_input: None,
+            app_events: Vec::new(),
+            render_target: 0,
+            last_time: None,
+            time_offset: 0.0,
+            pointer_pos: None,
+            capture_requested: false,
+            fonts: Vec::new(),
+            font_fallbacks: Vec::new(),
             unity: initializer,
             context,
             app,
         }
     }
 
+    /// The egui context driven by unity.
+    pub fn context(&self) -> &Context {
+        &self.context
+    }
+
+    /// The application created in `init`.
+    pub fn app(&self) -> &T {
+        &self.app
+    }
+
+    /// Mutable access to the application created in `init`.
+    pub fn app_mut(&mut self) -> &mut T {
+        &mut self.app
+    }
+
     /// Update function called very frame from unity.
     /// 1. get input from unity
-    /// 2. call `begin_frame` in egui
-    /// 3. call `App::update` in egui
-    /// 4. call `end_frame` in egui
-    /// 5. return if not paint immediately
-    /// 6. call `begin_paint` from unity
-    /// 7. call `rem_texture` from unity
-    /// 8. call `set_texture` from unity
-    /// 9. call `paint_mesh` from unity
-    /// 10. call `end_paint` from unity
-    pub fn update(&mut self, buffer: Buffer) -> Result<(), protobuf::Error> {
-        let input = parse_input(buffer)?;
+    /// 2. call `App::before_frame` and `begin_frame` in egui
+    /// 3. call `App::update` in egui(see `is_safe_mode` if it panics), then show enabled debug
+    ///    windows
+    /// 4. call `end_frame` in egui and `App::after_frame`, and keep `repaint_after` for unity to
+    ///    query
+    /// 5. call `set_render_target` and `begin_paint` from unity
+    /// 6. call `rem_texture` from unity
+    /// 7. call `set_texture` from unity
+    /// 8. call `paint_mesh` from unity
+    /// 9. call `end_paint` from unity
+    /// 10. call `capture_ready` from unity if `request_capture` was called
+    ///
+    /// A null or empty buffer runs a frame without input.
+    ///
+    /// # Safety
+    /// A buffer not null must point to `len` readable bytes for the duration of the call.
+    pub unsafe fn update(&mut self, buffer: Buffer) -> Result<(), UeguiError> {
+        let bytes = crate::bytes(buffer.data, buffer.len);
+        let pb_input = match self.chunked_input.take() {
+            Some(chunked) => {
+                let mut pb_input = Input::default();
+                pb_input.merge_from_bytes(&chunked)?;
+                if !merge_pb_input_bytes(&mut pb_input, bytes)? {
+                    // only the chunks, focus and modifiers are kept like in `empty_input`
+                    pb_input.has_focus = self.has_focus;
+                    if pb_input.modifier.is_none() {
+                        let modifiers = self.context.input(|i| i.modifiers);
+                        pb_input.modifier = Some(modifier_from_native_to_pb(&modifiers)).into();
+                    }
+                }
+                Some(pb_input)
+            }
+            None => parse_pb_input_bytes(bytes)?,
+        };
+        match pb_input {
+            Some(pb_input) => self.run_pb_frame(pb_input),
+            None => self.run_frame(self.empty_input()),
+        }
+        Ok(())
+    }
+
+    /// Input of a frame unity sent no input for, focus, modifiers and the screen of the last frame
+    /// are kept, so it is not mistaken for a focus loss releasing held keys and buttons. The screen
+    /// rect is left unset, `apply_safe_area` takes the last full one from unity, egui's own is
+    /// already shrunk by the safe area.
+    fn empty_input(&self) -> RawInput {
+        self.context.input(|i| RawInput {
+            pixels_per_point: Some(i.pixels_per_point),
+            modifiers: i.modifiers,
+            has_focus: self.has_focus,
+            ..Default::default()
+        })
+    }
+
+    /// Push a chunk of protobuf input too large for one buffer, e.g. with dropped file bytes, the
+    /// chunks are assembled until `is_last` and merged into the input of the next `update`, a frame
+    /// updated before the last chunk only gets its own input.
+    pub fn push_input_chunk(&mut self, chunk: &[u8], is_last: bool) {
+        self.input_chunks.extend_from_slice(chunk);
+        if is_last {
+            let input = std::mem::take(&mut self.input_chunks);
+            match &mut self.chunked_input {
+                Some(chunked) => chunked.extend_from_slice(&input),
+                None => self.chunked_input = Some(input),
+            }
+            self.repaint_after = Duration::ZERO;
+        }
+    }
+
+    /// Run a frame with protobuf input, multi clicks counted by unity are added for egui first,
+    /// see `MultiClick`.
+    pub fn run_pb_frame(&mut self, mut pb_input: Input) {
+        let predicted_dt = match pb_input.predicted_dt {
+            dt if dt > 0.0 => dt,
+            _ => RawInput::default().predicted_dt,
+        };
+        let time = match self.frame_time(pb_input.time, predicted_dt).0 {
+            Some(time) => time,
+            None => self.context.input(|i| i.time) + predicted_dt as f64,
+        };
+        self.multi_click.process(&mut pb_input.events, time);
+        let input = input_from_pb_to_native(pb_input, &self.input_options, self.pointer_pos);
+        self.run_frame(input);
+    }
+
+    /// Run a frame with input already converted to egui, see `update` for the steps.
+    pub fn run_frame(&mut self, mut input: RawInput) {
+        let _scope = LogScope::enter(self.unity.show_log);
+        input
+            .events
+            .splice(0..0, std::mem::take(&mut self.app_events));
+        self.handle_focus_change(&mut input);
+        self.keep_time_monotonic(&mut input);
+        self.fill_pointer_positions(&mut input);
+        input.events.append(&mut self.queued_events);
+        if self.pixels_per_point.is_some() {
+            input.pixels_per_point = self.pixels_per_point;
+        }
+        self.apply_safe_area(&mut input);
+        self.composition.process(&mut input.events);
+        let pressed = pressed_keys(&input.events);
+        let typing = self.context.wants_keyboard_input();
+        self.stats = FrameStats::default();
+        self.app.before_frame(&mut input);
         let begin = Instant::now();
-        self.context.begin_frame(input);
-        self.app.update(&self.context);
-        let output = self.context.end_frame();
-        log::info!("frame cpu cost:{}", begin.elapsed().as_micros());
-        if !output.repaint_after.is_zero() {
-            return Ok(());
+        let output = self.update_app(input);
+        self.stats.cpu_micros = begin.elapsed().as_micros() as u64;
+        log::info!("frame cpu cost:{}", self.stats.cpu_micros);
+        self.app.after_frame(&output);
+        self.pointer_consumed();
+        self.update_consumed_keys(pressed, typing);
+        self.repaint_after = output.repaint_after;
+        if self.debug_repaint && self.repaint_after.is_zero() {
+            self.log_repaint();
+        }
+        self.install_fonts();
+        if let Some(request_repaint_after) = self.unity.request_repaint_after {
+            request_repaint_after(self.repaint_after_millis());
         }
         self.update_platform(&output.platform_output);
+        self.set_cursor(output.platform_output.cursor_icon);
+        self.set_ime_position(output.platform_output.text_cursor_pos);
+        if let Some(url) = &output.platform_output.open_url {
+            self.open_url(url);
+        }
+        if !output.platform_output.copied_text.is_empty() {
+            self.set_clipboard(&output.platform_output.copied_text);
+        }
+        let frame = std::mem::take(&mut self.frame);
+        if frame.close_requested() {
+            self.request_close();
+        }
+        let (events, keyboard_text) = frame.into_parts();
+        self.app_events = events;
+        if let Some(text) = keyboard_text {
+            self.set_keyboard_text(text);
+        }
+        #[cfg(feature = "accesskit")]
+        if let Some(update) = &output.platform_output.accesskit_update {
+            self.update_accessibility(update);
+        }
         self.show_keyboard(self.context.wants_keyboard_input());
         let begin = Instant::now();
+        if let Some(set_render_target) = self.unity.set_render_target {
+            set_render_target(self.render_target);
+        }
         self.begin_paint();
         for id in output.textures_delta.free {
             self.rem_texture(id);
         }
-        for (id, image) in output.textures_delta.set {
-            self.set_texture(id, image);
-        }
+        let capture =
+            std::mem::take(&mut self.capture_requested).then(|| output.textures_delta.set.clone());
+        self.set_textures(output.textures_delta.set);
+        let tessellate = Instant::now();
         let cps = self.context.tessellate(output.shapes);
-        for cp in cps {
-            self.paint_mesh(cp);
-        }
+        self.stats.tessellate_micros = tessellate.elapsed().as_micros() as u64;
+        let captured = capture.map(|textures| (textures, cps.clone()));
+        self.paint_primitives(cps);
         self.end_paint();
         log::info!("frame gpu cost:{}", begin.elapsed().as_micros());
-        Ok(())
+        self.frame_stats();
+        if let Some((textures, cps)) = captured {
+            self.capture_ready(textures, cps);
+        }
+    }
+
+    /// How egui used the pointer in the last frame.
+    pub fn pointer_state(&self) -> PointerState {
+        self.pointer
+    }
+
+    /// Input egui wanted in the last frame as `WANTS_*` bits, read from state cached after the
+    /// frame, cheap enough for unity to route input before every `update`.
+    pub fn peek_input_state(&self) -> u32 {
+        let mut state = 0;
+        if matches!(self.keyboard, Some((true, _))) {
+            state |= WANTS_KEYBOARD_INPUT;
+        }
+        if self.pointer.wants_pointer_input {
+            state |= WANTS_POINTER_INPUT;
+        }
+        if self.ime_position.is_some() {
+            state |= WANTS_IME;
+        }
+        state
+    }
+
+    /// Update the pointer state after `end_frame` and send it to `pointer_consumed` from unity.
+    fn pointer_consumed(&mut self) {
+        self.pointer = PointerState {
+            over_area: self.context.is_pointer_over_area(),
+            using_pointer: self.context.is_using_pointer(),
+            wants_pointer_input: self.context.wants_pointer_input(),
+        };
+        if let Some(pointer_consumed) = self.unity.pointer_consumed {
+            pointer_consumed(
+                self.pointer.over_area as u32,
+                self.pointer.using_pointer as u32,
+                self.pointer.wants_pointer_input as u32,
+            );
+        }
+    }
+
+    /// Statistics of the last painted frame.
+    pub fn stats(&self) -> FrameStats {
+        self.stats
+    }
+
+    /// Wrapper function for `frame_stats` from unity.
+    pub fn frame_stats(&self) {
+        if let Some(frame_stats) = self.unity.frame_stats {
+            let stats = self.stats;
+            frame_stats(
+                stats.mesh_count,
+                stats.vertex_count,
+                stats.index_count,
+                stats.texture_bytes,
+                stats.cpu_micros,
+                stats.tessellate_micros,
+            );
+        }
+    }
+
+    /// Capture the next frame, after it is painted the texture deltas and the tessellated meshes
+    /// of the frame are sent to `capture_ready`, e.g. for snapshots in automated tests.
+    pub fn request_capture(&mut self) {
+        self.capture_requested = true;
+        self.repaint_after = Duration::ZERO;
+    }
+
+    /// Wrapper function for `capture_ready` from unity, textures are encoded as in
+    /// `set_textures_batch` and meshes as in `paint_frame`, paint callbacks are left out.
+    pub fn capture_ready(
+        &mut self,
+        textures: Vec<(TextureId, ImageDelta)>,
+        cps: Vec<ClippedPrimitive>,
+    ) {
+        let Some(capture_ready) = self.unity.capture_ready else {
+            return;
+        };
+        let (textures, textures_count) = self.encode_textures(textures);
+        let mut meshes = Vec::new();
+        for cp in cps {
+            if let Primitive::Mesh(mesh) = cp.primitive {
+                for mesh in self.unity_meshes(mesh) {
+                    if let Some(clip_rect) = mesh_clip_rect(&mesh, cp.clip_rect) {
+                        meshes.push((mesh, clip_rect));
+                    }
+                }
+            }
+        }
+        let meshes_count = meshes.len() as u32;
+        let meshes = self.encode_meshes(&meshes);
+        capture_ready(
+            textures.as_ptr(),
+            textures.len() as u32,
+            textures_count,
+            meshes.as_ptr(),
+            meshes.len() as u32,
+            meshes_count,
+        );
+    }
+
+    /// Show or hide a built-in egui window above the app's UI.
+    pub fn show_debug_window(&mut self, window: DebugWindow, show: bool) {
+        let open = match window {
+            DebugWindow::Settings => &mut self.debug_windows.settings,
+            DebugWindow::Inspection => &mut self.debug_windows.inspection,
+            DebugWindow::Memory => &mut self.debug_windows.memory,
+        };
+        *open = show;
+    }
+
+    /// Let panics of the app and of `update` propagate instead of discarding the frame, so they
+    /// abort with the full backtrace to the native crash handler while debugging, panics are
+    /// always caught in release builds.
+    pub fn set_catch_panics(&mut self, catch: bool) {
+        self.catch_panics = catch;
+    }
+
+    /// Whether panics of frames are caught, see `set_catch_panics`.
+    pub fn catch_panics(&self) -> bool {
+        self.catch_panics || !cfg!(debug_assertions)
+    }
+
+    /// Log every frame after which egui wants to repaint immediately, to find what keeps the ui
+    /// repainting, off by default.
+    pub fn set_debug_repaint(&mut self, debug: bool) {
+        self.debug_repaint = debug;
+    }
+
+    /// Log the state which usually explains an immediate repaint, egui doesn't record who
+    /// requested it, an idle pointer without events points at an animation or the app itself.
+    fn log_repaint(&self) {
+        let (events, time) = self.context.input(|i| (i.events.len(), i.time));
+        log::info!(
+            "repaint requested at {:.3}s, events:{}, using pointer:{}, dragging:{}, keyboard:{}",
+            time,
+            events,
+            self.context.is_using_pointer(),
+            self.context.memory(|m| m.is_anything_being_dragged()),
+            self.context.wants_keyboard_input(),
+        );
+    }
+
+    /// Run `App::update` in a frame, a panicking frame is discarded and run again without the
+    /// app, after `SAFE_MODE_FAILURES` consecutive panics a diagnostic message is shown instead.
+    /// The frame run again gets the screen, time and modifiers of the input but no events or
+    /// files, which egui already handled in the discarded frame.
+    fn update_app(&mut self, input: RawInput) -> FullOutput {
+        let retry = RawInput {
+            screen_rect: input.screen_rect,
+            pixels_per_point: input.pixels_per_point,
+            max_texture_side: input.max_texture_side,
+            time: input.time,
+            predicted_dt: input.predicted_dt,
+            modifiers: input.modifiers,
+            has_focus: input.has_focus,
+            ..Default::default()
+        };
+        let frame = FrameGuard::begin(&self.context, input, &self.aborted_textures);
+        if !self.catch_panics() {
+            self.app.update_frame(&self.context, &mut self.frame);
+            self.failures = 0;
+            self.show_debug_windows();
+            return frame.end();
+        }
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            self.app.update_frame(&self.context, &mut self.frame)
+        }));
+        let Err(err) = result else {
+            self.failures = 0;
+            self.show_debug_windows();
+            return frame.end();
+        };
+        self.failures += 1;
+        if !panicked_in_log() {
+            log::error!(
+                "app update panicked {} times:{}",
+                self.failures,
+                panic_message(&*err)
+            );
+        }
+        let discarded = frame.end();
+        let frame = FrameGuard::begin(&self.context, retry, &self.aborted_textures);
+        if self.is_safe_mode() {
+            egui::CentralPanel::default().show(&self.context, |ui| {
+                ui.centered_and_justified(|ui| {
+                    let color = ui.visuals().error_fg_color;
+                    ui.colored_label(color, "egui render error, see logs");
+                });
+            });
+        }
+        self.show_debug_windows();
+        let mut output = frame.end();
+        let mut textures_delta = discarded.textures_delta;
+        textures_delta.append(output.textures_delta);
+        output.textures_delta = textures_delta;
+        output
+    }
+
+    /// Send a panic aborting the whole frame to `show_log` as an error, bypassing the log level,
+    /// so it is visible even if logs from rust are not.
+    pub fn report_panic(&self, payload: &(dyn Any + Send)) {
+        if panicked_in_log() {
+            return;
+        }
+        let message = format!("egui frame aborted by panic:{}", panic_message(payload));
+        if let Some(show_log) = self.unity.show_log {
+            show_log(
+                log_level_to_unity(Level::Error),
+                message.as_ptr(),
+                message.len() as i32,
+            );
+        }
+    }
+
+    /// Returns true if the app panicked in the last `SAFE_MODE_FAILURES` frames, and a
+    /// diagnostic message is shown instead, any frame succeeded leaves safe mode.
+    pub fn is_safe_mode(&self) -> bool {
+        self.failures >= SAFE_MODE_FAILURES
+    }
+
+    fn show_debug_windows(&mut self) {
+        let ctx = &self.context;
+        let windows = &mut self.debug_windows;
+        egui::Window::new("Settings")
+            .open(&mut windows.settings)
+            .vscroll(true)
+            .show(ctx, |ui| ctx.settings_ui(ui));
+        egui::Window::new("Inspection")
+            .open(&mut windows.inspection)
+            .vscroll(true)
+            .show(ctx, |ui| ctx.inspection_ui(ui));
+        egui::Window::new("Memory")
+            .open(&mut windows.memory)
+            .vscroll(true)
+            .show(ctx, |ui| ctx.memory_ui(ui));
+    }
+
+    /// How long egui is content to wait before the next frame, requested by the last frame.
+    /// Zero means egui wants to repaint immediately, e.g. during animations.
+    pub fn repaint_after(&self) -> Duration {
+        self.repaint_after
+    }
+
+    /// `repaint_after` in milliseconds as passed to unity, `u64::MAX` if egui needs no repaint
+    /// until new input arrives.
+    pub fn repaint_after_millis(&self) -> u64 {
+        self.repaint_after
+            .as_millis()
+            .try_into()
+            .unwrap_or(u64::MAX)
+    }
+
+    /// Set safe area insets in pixels for notched screens, egui lays out inside the screen rect
+    /// shrunk by the insets, e.g. from `Screen.safeArea` in unity.
+    pub fn set_safe_area(&mut self, left: f32, top: f32, right: f32, bottom: f32) {
+        self.safe_area = egui::Margin {
+            left,
+            right,
+            top,
+            bottom,
+        };
+    }
+
+    fn apply_safe_area(&mut self, input: &mut RawInput) {
+        self.screen_rect = input.screen_rect.or(self.screen_rect);
+        let Some(rect) = self.screen_rect else {
+            return;
+        };
+        let pixels_per_point = input
+            .pixels_per_point
+            .unwrap_or_else(|| self.context.pixels_per_point());
+        let inset = self.safe_area;
+        input.screen_rect = Some(egui::Rect::from_min_max(
+            rect.min + egui::vec2(inset.left, inset.top) / pixels_per_point,
+            rect.max - egui::vec2(inset.right, inset.bottom) / pixels_per_point,
+        ));
+    }
+
+    /// Time egui gets for input `time` and the new offset if unity's clock went back, see
+    /// `keep_time_monotonic`.
+    fn frame_time(&self, time: Option<f64>, predicted_dt: f32) -> (Option<f64>, Option<f64>) {
+        let Some(time) = time else {
+            return (None, None);
+        };
+        match self.last_time {
+            Some(last) if time + self.time_offset < last => {
+                let offset = last - time + predicted_dt as f64;
+                (Some(time + offset), Some(offset))
+            }
+            _ => (Some(time + self.time_offset), None),
+        }
+    }
+
+    /// Shift input time by an offset kept since unity's clock last went back, e.g. on a scene
+    /// reload, so the frame after the reset comes `predicted_dt` after the last one and the
+    /// following frames advance with unity's clock, egui animations keep running smoothly.
+    fn keep_time_monotonic(&mut self, input: &mut RawInput) {
+        let (time, offset) = self.frame_time(input.time, input.predicted_dt);
+        if let (Some(offset), Some(time), Some(last)) = (offset, input.time, self.last_time) {
+            log::warn!(
+                "input time {} goes back from {}, later times are shifted by {}",
+                time,
+                last,
+                offset
+            );
+            self.time_offset = offset;
+        }
+        input.time = time;
+        self.last_time = time.or(self.last_time);
     }
 
+    /// Move pointer buttons and enters without position to the last known pointer position, or
+    /// the top left corner if unity never sent one, and keep the last position for the next frame.
+    fn fill_pointer_positions(&mut self, input: &mut RawInput) {
+        crate::input::fill_pointer_positions(&mut input.events, &mut self.pointer_pos);
+    }
+
+    /// When unity loses focus, release all held keys, modifiers and pointer buttons and remove the
+    /// pointer, so modifiers, hover highlights, tooltips and drags are not stuck until focus comes
+    /// back, e.g. after alt-tab with shift held.
+    fn handle_focus_change(&mut self, input: &mut RawInput) {
+        let lost = self.has_focus && !input.has_focus;
+        self.has_focus = input.has_focus;
+        if !lost {
+            return;
+        }
+        let (pos, buttons, keys) = self.context.input(|i| {
+            let buttons: Vec<_> = [
+                PointerButton::Primary,
+                PointerButton::Secondary,
+                PointerButton::Middle,
+                PointerButton::Extra1,
+                PointerButton::Extra2,
+            ]
+            .into_iter()
+            .filter(|button| i.pointer.button_down(*button))
+            .collect();
+            let keys: Vec<_> = i.keys_down.iter().copied().collect();
+            (i.pointer.interact_pos().unwrap_or_default(), buttons, keys)
+        });
+        input.modifiers = Default::default();
+        for key in keys {
+            input.events.push(Event::Key {
+                key,
+                pressed: false,
+                repeat: false,
+                modifiers: input.modifiers,
+            });
+        }
+        for button in buttons {
+            input.events.push(Event::PointerButton {
+                pos,
+                button,
+                pressed: false,
+                modifiers: input.modifiers,
+            });
+        }
+        input.events.push(Event::PointerGone);
+    }
+
+    /// Keys pressed in the last frame which egui consumed, e.g. arrow keys handled by a focused
+    /// widget, or all of them while a text edit has focus. Unity should not handle these keys
+    /// again.
+    pub fn consumed_keys(&self) -> &[Key] {
+        &self.consumed_keys
+    }
+
+    /// Returns true if `key` was pressed in the last frame and consumed by egui.
+    pub fn key_consumed(&self, key: Key) -> bool {
+        self.consumed_keys.contains(&key)
+    }
+
+    /// Same as `key_consumed` but takes a `KeyType` value used in the input protocol.
+    pub fn key_code_consumed(&self, code: u32) -> bool {
+        key_from_pb_code(code)
+            .map(|key| self.key_consumed(key))
+            .unwrap_or_default()
+    }
+
+    /// A pressed key is consumed if egui removed it from the input events during the frame,
+    /// see `egui::InputState::consume_key`. `TextEdit` reads keys without removing them, so all
+    /// keys are consumed if a widget wanted keyboard input before or after the frame, `typing`
+    /// is the state before.
+    fn update_consumed_keys(&mut self, pressed: Vec<Key>, typing: bool) {
+        if typing || self.context.wants_keyboard_input() {
+            self.consumed_keys = pressed;
+            return;
+        }
+        let remaining = self.context.input(|i| pressed_keys(&i.events));
+        self.consumed_keys = pressed
+            .into_iter()
+            .filter(|key| !remaining.contains(key))
+            .collect();
+    }
+
+    /// Keep the text of the focused text edit from output events, the text is kept until another
+    /// text edit reports its text or the keyboard is hidden.
     pub fn update_platform(&mut self, platform: &PlatformOutput) {
-        self.text.clear();
         for e in &platform.events {
-            let info = match e {
-                OutputEvent::Clicked(info) => info,
-                OutputEvent::DoubleClicked(info) => info,
-                OutputEvent::FocusGained(info) => info,
-                OutputEvent::TripleClicked(info) => info,
-                OutputEvent::TextSelectionChanged(info) => info,
-                OutputEvent::ValueChanged(info) => info,
-            };
-            match (info.typ, &info.current_text_value) {
-                (WidgetType::TextEdit, Some(text)) => {
-                    self.text = text.clone();
-                }
-                _ => (),
+            let info = e.widget_info();
+            if let (WidgetType::TextEdit, Some(text)) = (info.typ, &info.current_text_value) {
+                self.text = text.clone();
+            }
+            self.widget_event(e);
+        }
+    }
+
+    /// Wrapper function for `widget_event` from unity, with `output_event_to_u32` of the event
+    /// and `widget_type_to_u32` of the widget. Label and text are null if not available, value
+    /// is NaN if the widget has no numeric value, selected is 0 or 1 for selectable widgets and 2
+    /// otherwise.
+    pub fn widget_event(&self, event: &OutputEvent) {
+        let Some(widget_event) = self.unity.widget_event else {
+            return;
+        };
+        let info = event.widget_info();
+        let (label, label_len) = match &info.label {
+            Some(label) => (label.as_ptr(), label.len() as u32),
+            None => (std::ptr::null(), 0),
+        };
+        let (text, text_len) = match &info.current_text_value {
+            Some(text) => (text.as_ptr(), text.len() as u32),
+            None => (std::ptr::null(), 0),
+        };
+        widget_event(
+            output_event_to_u32(event),
+            widget_type_to_u32(info.typ),
+            label,
+            label_len,
+            text,
+            text_len,
+            info.value.unwrap_or(f64::NAN),
+            info.selected.map_or(2, |selected| selected as u32),
+        );
+    }
+
+    /// Wrapper function for `set_cursor` from unity, only called when the icon changes.
+    pub fn set_cursor(&mut self, icon: CursorIcon) {
+        if self.cursor_icon != Some(icon) {
+            self.cursor_icon = Some(icon);
+            if let Some(set_cursor) = self.unity.set_cursor {
+                set_cursor(cursor_icon_to_u32(icon));
+            }
+        }
+    }
+
+    /// Wrapper function for `set_ime_position` from unity, only called when the position changes.
+    pub fn set_ime_position(&mut self, pos: Option<egui::Pos2>) {
+        if self.ime_position != pos {
+            self.ime_position = pos;
+            if let Some(set_ime_position) = self.unity.set_ime_position {
+                let pos = pos.unwrap_or(egui::Pos2::ZERO);
+                set_ime_position(self.ime_position.is_some() as u32, pos.x, pos.y);
             }
         }
     }
 
+    /// Wrapper function for `open_url` from unity, `new_tab` is a hint for unity to choose
+    /// between an in-app webview and the system browser.
+    pub fn open_url(&self, url: &OpenUrl) {
+        if let Some(open_url) = self.unity.open_url {
+            open_url(url.new_tab as u32, url.url.as_ptr(), url.url.len() as u32);
+        }
+    }
+
+    /// Wrapper function for `request_close` from unity.
+    pub fn request_close(&self) {
+        if let Some(request_close) = self.unity.request_close {
+            request_close();
+        }
+    }
+
+    /// Wrapper function for `update_accessibility` from unity, the update is sent as json.
+    #[cfg(feature = "accesskit")]
+    pub fn update_accessibility(&self, update: &accesskit::TreeUpdate) {
+        let Some(update_accessibility) = self.unity.update_accessibility else {
+            return;
+        };
+        match serde_json::to_vec(update) {
+            Ok(json) => update_accessibility(json.as_ptr(), json.len() as u32),
+            Err(err) => log::error!("serialize accessibility update failed:{:?}", err),
+        }
+    }
+
+    /// Wrapper function for `set_clipboard` from unity.
+    pub fn set_clipboard(&self, text: &str) {
+        if let Some(set_clipboard) = self.unity.set_clipboard {
+            set_clipboard(text.as_ptr(), text.len() as u32);
+        }
+    }
+
     /// Wrapper function for `set_texture` from unity.
-    pub fn set_texture(&self, id: TextureId, image: ImageDelta) {
-        let id = texture_id_to_u64(id);
-        let filter_mode = match image.options.minification {
+    pub fn set_texture(&mut self, tid: TextureId, image: ImageDelta) {
+        if !self.check_texture_delta(tid, &image) {
+            return;
+        }
+        let upload = self.texture_upload(tid, image);
+        self.stats.texture_bytes += upload.pixels.len() as u64 * 4;
+        let Some(set_texture) = self.unity.set_texture else {
+            return;
+        };
+        set_texture(
+            upload.id,
+            upload.offset_x,
+            upload.offset_y,
+            upload.width,
+            upload.height,
+            upload.min_filter,
+            upload.mag_filter,
+            self.texture_format as u32,
+            upload.pixels.as_ptr() as *const u8,
+        );
+        self.pixels = upload.pixels;
+    }
+
+    /// Upload all changed textures of a frame, a single texture goes through `set_texture` and
+    /// more are packed into one `set_textures_batch` call.
+    ///
+    /// Every texture in the batch starts with a native endian header, `id: u64`, then
+    /// `offset_x`, `offset_y`, `width`, `height`, `min_filter`, `mag_filter` as `u32`, followed by
+    /// `width * height * 4` bytes of pixels in the format set by `set_texture_format`, headers are
+    /// not aligned.
+    pub fn set_textures(&mut self, textures: Vec<(TextureId, ImageDelta)>) {
+        if textures.len() < 2 || self.unity.set_textures_batch.is_none() {
+            for (id, image) in textures {
+                self.set_texture(id, image);
+            }
+            return;
+        }
+        let (batch, count) = self.encode_textures(textures);
+        if count == 0 {
+            return;
+        }
+        self.stats.texture_bytes += (batch.len() - count as usize * 32) as u64;
+        if let Some(set_textures_batch) = self.unity.set_textures_batch {
+            set_textures_batch(batch.as_ptr(), batch.len() as u32, count);
+        }
+    }
+
+    /// Encode textures for `set_textures_batch`, see `set_textures`, returns the buffer and the
+    /// count of textures in it, deltas rejected by `check_texture_delta` are left out.
+    fn encode_textures(&mut self, textures: Vec<(TextureId, ImageDelta)>) -> (Vec<u8>, u32) {
+        let mut batch = Vec::new();
+        let mut count = 0;
+        for (id, image) in textures {
+            if !self.check_texture_delta(id, &image) {
+                continue;
+            }
+            count += 1;
+            let upload = self.texture_upload(id, image);
+            batch.extend_from_slice(&upload.id.to_ne_bytes());
+            for v in [
+                upload.offset_x,
+                upload.offset_y,
+                upload.width,
+                upload.height,
+                upload.min_filter,
+                upload.mag_filter,
+            ] {
+                batch.extend_from_slice(&v.to_ne_bytes());
+            }
+            batch.extend_from_slice(bytemuck::cast_slice(&upload.pixels));
+            self.pixels = upload.pixels;
+        }
+        (batch, count)
+    }
+
+    /// Returns false for a partial delta of a texture unity doesn't have in full, e.g. after a
+    /// context reset, or one outside of the texture, unity would write out of bounds otherwise.
+    fn check_texture_delta(&self, tid: TextureId, image: &ImageDelta) -> bool {
+        let Some(pos) = image.pos else {
+            return true;
+        };
+        let Some(size) = self.textures.get(&tid) else {
+            log::warn!("partial update of unknown texture {:?} skipped", tid);
+            return false;
+        };
+        let max = [pos[0] + image.image.width(), pos[1] + image.image.height()];
+        if max[0] > size[0] || max[1] > size[1] {
+            log::warn!(
+                "partial update of texture {:?} at {:?} outside of size {:?} skipped",
+                tid,
+                pos,
+                size
+            );
+            return false;
+        }
+        true
+    }
+
+    /// Convert a texture delta to what unity uploads and record the memory of the texture.
+    ///
+    /// A partial delta of the font atlas only holds the updated rect at `pos`, so only new glyphs
+    /// are converted, e.g. a few KB for some new CJK glyphs instead of the 2048x2048 atlas(16MB),
+    /// and they are converted into the reused `pixels` buffer, so no allocation is needed once
+    /// it is large enough.
+    fn texture_upload(&mut self, tid: TextureId, image: ImageDelta) -> TextureUpload {
+        let filter_mode = |filter| match filter {
             TextureFilter::Nearest => 1,
             TextureFilter::Linear => 2,
         };
@@ -150,93 +1576,556 @@ impl<T: App> UnityContext<T> {
             Some(pos) => (pos[0] as u32, pos[1] as u32),
             _ => (0, 0),
         };
-        let (width, height, data) = match image.image {
+        let (width, height, mut pixels) = match image.image {
             ImageData::Color(color) => (color.size[0] as u32, color.size[1] as u32, color.pixels),
-            ImageData::Font(font) => (
-                font.size[0] as u32,
-                font.size[1] as u32,
-                font.srgba_pixels(Some(1.0)).collect(),
-            ),
+            ImageData::Font(font) => {
+                let mut pixels = std::mem::take(&mut self.pixels);
+                pixels.clear();
+                pixels.extend(font.srgba_pixels(self.font_gamma));
+                (font.size[0] as u32, font.size[1] as u32, pixels)
+            }
         };
-        (self.unity.set_texture)(
-            id,
+        if image.pos.is_none() {
+            self.textures.insert(tid, [width as usize, height as usize]);
+        }
+        self.texture_format.convert(&mut pixels);
+        TextureUpload {
+            id: texture_id_to_u64(tid),
             offset_x,
             offset_y,
             width,
             height,
-            filter_mode,
-            data.as_ptr() as *const u8,
-        )
+            min_filter: filter_mode(image.options.minification),
+            mag_filter: filter_mode(image.options.magnification),
+            pixels,
+        }
     }
 
     /// Wrapper function for `rem_texture` from unity.
-    pub fn rem_texture(&self, id: TextureId) {
+    pub fn rem_texture(&mut self, id: TextureId) {
+        self.textures.remove(&id);
         let id = texture_id_to_u64(id);
-        (self.unity.rem_texture)(id);
+        if let Some(rem_texture) = self.unity.rem_texture {
+            rem_texture(id);
+        }
+    }
+
+    /// Ids of all textures uploaded to unity and not removed yet, including the font atlas.
+    pub fn live_textures(&self) -> impl Iterator<Item = TextureId> + '_ {
+        self.textures.keys().copied()
+    }
+
+    /// Call `rem_texture` for every live texture, so unity doesn't leak textures egui never
+    /// freed, e.g. when the context is destroyed or reset. Called on drop.
+    pub fn release_textures(&mut self) {
+        let ids: Vec<_> = self.live_textures().collect();
+        for id in ids {
+            self.rem_texture(id);
+        }
+    }
+
+    /// Total bytes of all live textures uploaded to unity, including the font atlas.
+    pub fn texture_memory(&self) -> usize {
+        self.textures.values().map(|[w, h]| w * h * 4).sum()
+    }
+
+    /// Switch to the built-in dark or light visuals of egui.
+    pub fn set_dark_mode(&self, dark: bool) {
+        let visuals = if dark {
+            egui::Visuals::dark()
+        } else {
+            egui::Visuals::light()
+        };
+        self.context.set_visuals(visuals);
+    }
+
+    /// Style of egui serialized as json.
+    #[cfg(feature = "persistence")]
+    pub fn get_style(&self) -> Result<Vec<u8>, serde_json::Error> {
+        serde_json::to_vec(&*self.context.style())
+    }
+
+    /// Apply a style serialized as json by `get_style`, the current style is kept if json is
+    /// malformed.
+    #[cfg(feature = "persistence")]
+    pub fn set_style(&self, style: &[u8]) -> Result<(), serde_json::Error> {
+        let style: egui::Style = serde_json::from_slice(style)?;
+        self.context.set_style(style);
+        Ok(())
+    }
+
+    /// Memory of egui(window positions, collapsing states, scroll offsets...) serialized as json.
+    #[cfg(feature = "persistence")]
+    pub fn save_memory(&self) -> Result<Vec<u8>, serde_json::Error> {
+        self.context.memory(serde_json::to_vec)
+    }
+
+    /// Restore memory saved by `save_memory`, the current memory is kept if json is malformed.
+    #[cfg(feature = "persistence")]
+    pub fn load_memory(&self, memory: &[u8]) -> Result<(), serde_json::Error> {
+        let memory: egui::Memory = serde_json::from_slice(memory)?;
+        self.context.memory_mut(|m| *m = memory);
+        Ok(())
     }
 
     /// Wrapper function for `begin_paint` from unity.
     pub fn begin_paint(&self) {
-        (self.unity.begin_paint)()
+        if let Some(begin_paint) = self.unity.begin_paint {
+            begin_paint();
+        }
+    }
+
+    /// Paint all primitives of a frame, meshes are batched into `paint_frame` calls if unity
+    /// provides it, a run of meshes is flushed before every paint callback to keep the order,
+    /// otherwise every primitive goes through `paint_mesh`.
+    pub fn paint_primitives(&mut self, cps: Vec<ClippedPrimitive>) {
+        if self.unity.paint_frame.is_none() {
+            for cp in cps {
+                self.paint_mesh(cp);
+            }
+            return;
+        }
+        let mut meshes = Vec::new();
+        for cp in cps {
+            match cp.primitive {
+                Primitive::Mesh(mesh) => {
+                    for mesh in self.unity_meshes(mesh) {
+                        if let Some(clip_rect) = mesh_clip_rect(&mesh, cp.clip_rect) {
+                            meshes.push((mesh, clip_rect));
+                        }
+                    }
+                }
+                Primitive::Callback(_) => {
+                    self.paint_frame(std::mem::take(&mut meshes));
+                    self.paint_mesh(cp);
+                }
+            }
+        }
+        self.paint_frame(meshes);
+    }
+
+    /// Wrapper function for `paint_frame` from unity, sends meshes in one buffer.
+    ///
+    /// The buffer starts with a table of `count` native endian entries, `texture_id: u64`, clip
+    /// rect `min_x`, `min_y`, `max_x`, `max_y` as `f32`, then `vertex_offset`, `vertex_count`,
+    /// `index_offset`, `index_count` as `u32`, 40 bytes each. Offsets are in bytes from the start
+    /// of the buffer, vertices are in the format of `paint_mesh` and indices are `u32`. Clip rects
+    /// are in input coordinates with an input transform like in `paint_mesh`, vertices in points.
+    pub fn paint_frame(&mut self, meshes: Vec<(Mesh, egui::Rect)>) {
+        let Some(paint_frame) = self.unity.paint_frame else {
+            return;
+        };
+        if meshes.is_empty() {
+            return;
+        }
+        for (mesh, _) in &meshes {
+            self.stats.add_mesh(mesh);
+        }
+        let buffer = self.encode_meshes(&meshes);
+        paint_frame(buffer.as_ptr(), buffer.len() as u32, meshes.len() as u32);
+    }
+
+    /// Encode meshes for `paint_frame`, see `paint_frame`, clip rects are already narrowed by
+    /// `mesh_clip_rect`.
+    fn encode_meshes(&mut self, meshes: &[(Mesh, egui::Rect)]) -> Vec<u8> {
+        let mut table = Vec::with_capacity(meshes.len() * 40);
+        let mut data = Vec::new();
+        let data_offset = meshes.len() * 40;
+        for (mesh, clip_rect) in meshes {
+            let clip_rect = self.unity_clip_rect(*clip_rect);
+            let vertices = repack_vertices(
+                &mesh.vertices,
+                self.mesh_options.color_format,
+                &mut self.vertices,
+            );
+            let vertex_offset = data_offset + data.len();
+            data.extend_from_slice(vertices);
+            let index_offset = data_offset + data.len();
+            data.extend_from_slice(bytemuck::cast_slice(&mesh.indices));
+            table.extend_from_slice(&texture_id_to_u64(mesh.texture_id).to_ne_bytes());
+            for v in [
+                clip_rect.min.x,
+                clip_rect.min.y,
+                clip_rect.max.x,
+                clip_rect.max.y,
+            ] {
+                table.extend_from_slice(&v.to_ne_bytes());
+            }
+            for v in [
+                vertex_offset,
+                mesh.vertices.len(),
+                index_offset,
+                mesh.indices.len(),
+            ] {
+                table.extend_from_slice(&(v as u32).to_ne_bytes());
+            }
+        }
+        table.extend_from_slice(&data);
+        table
     }
 
-    /// Wrapper function for `paint_mesh` from unity.
-    pub fn paint_mesh(&self, cp: ClippedPrimitive) {
+    /// Split a mesh to fit unity meshes with 16 bit index format, see `split_mesh`, and apply
+    /// `MeshOptions::reverse_winding`.
+    fn unity_meshes(&self, mesh: Mesh) -> Vec<Mesh> {
+        let mut meshes = split_mesh(mesh);
+        if self.mesh_options.reverse_winding {
+            for mesh in &mut meshes {
+                reverse_winding(&mut mesh.indices);
+            }
+        }
+        meshes
+    }
+
+    /// Wrapper function for `paint_mesh` from unity, meshes are split to fit unity meshes with
+    /// 16 bit index format, see `split_mesh`.
+    pub fn paint_mesh(&mut self, cp: ClippedPrimitive) {
         match cp.primitive {
             Primitive::Mesh(mesh) => {
-                let id = texture_id_to_u64(mesh.texture_id);
-                (self.unity.paint_mesh)(
-                    id,
-                    mesh.vertices.len() as u32,
-                    mesh.vertices.as_ptr() as *const u8,
-                    mesh.indices.len() as u32,
-                    mesh.indices.as_ptr() as *const u8,
-                    cp.clip_rect.min.x,
-                    cp.clip_rect.min.y,
-                    cp.clip_rect.max.x,
-                    cp.clip_rect.max.y,
-                );
+                let Some(paint_mesh) = self.unity.paint_mesh else {
+                    return;
+                };
+                for mesh in self.unity_meshes(mesh) {
+                    let Some(clip_rect) = mesh_clip_rect(&mesh, cp.clip_rect) else {
+                        continue;
+                    };
+                    let clip_rect = self.unity_clip_rect(clip_rect);
+                    self.stats.add_mesh(&mesh);
+                    let id = texture_id_to_u64(mesh.texture_id);
+                    let vertices = repack_vertices(
+                        &mesh.vertices,
+                        self.mesh_options.color_format,
+                        &mut self.vertices,
+                    );
+                    paint_mesh(
+                        id,
+                        mesh.vertices.len() as u32,
+                        vertices.as_ptr(),
+                        mesh.indices.len() as u32,
+                        mesh.indices.as_ptr() as *const u8,
+                        clip_rect.min.x,
+                        clip_rect.min.y,
+                        clip_rect.max.x,
+                        clip_rect.max.y,
+                    );
+                }
             }
-            Primitive::Callback(_) => {
-                unimplemented!("callback not supported");
+            Primitive::Callback(callback) => {
+                let Some(unity) = callback.callback.downcast_ref::<UnityCallback>() else {
+                    log::warn!("paint callback not created by UnityCallback, skipped");
+                    return;
+                };
+                let Some(paint_callback) = self.unity.paint_callback else {
+                    return;
+                };
+                let clip_rect = self.unity_clip_rect(cp.clip_rect);
+                paint_callback(
+                    unity.handle,
+                    callback.rect.min.x,
+                    callback.rect.min.y,
+                    callback.rect.max.x,
+                    callback.rect.max.y,
+                    clip_rect.min.x,
+                    clip_rect.min.y,
+                    clip_rect.max.x,
+                    clip_rect.max.y,
+                );
             }
         }
     }
 
     /// Wrapper function for `end_paint` from unity.
     pub fn end_paint(&self) {
-        (self.unity.end_paint)()
+        if let Some(end_paint) = self.unity.end_paint {
+            end_paint();
+        }
     }
 
-    pub fn show_keyboard(&self, show: bool) {
-        (self.unity.show_keyboard)(
-            if show { 1 } else { 0 },
-            self.text.as_ptr(),
-            self.text.len() as u32,
-        );
+    /// Replace the text of the focused text edit changed by the app, see
+    /// `Frame::set_keyboard_text`, the ime position is sent again if the text changed.
+    pub fn set_keyboard_text(&mut self, text: String) {
+        if self.text == text {
+            return;
+        }
+        self.text = text;
+        let pos = self.ime_position.take();
+        self.set_ime_position(pos);
+    }
+
+    /// Wrapper function for `show_keyboard` from unity, only called when the keyboard is shown or
+    /// hidden or the text changes, the text is null when hiding.
+    pub fn show_keyboard(&mut self, show: bool) {
+        if !show {
+            self.text.clear();
+        }
+        if matches!(&self.keyboard, Some((shown, text)) if *shown == show && *text == self.text) {
+            return;
+        }
+        self.keyboard = Some((show, self.text.clone()));
+        if let Some(show_keyboard) = self.unity.show_keyboard_utf16 {
+            if show {
+                let utf16_len = self.text.encode_utf16().count();
+                show_keyboard(
+                    1,
+                    self.text.as_ptr(),
+                    self.text.len() as u32,
+                    utf16_len as u32,
+                );
+            } else {
+                show_keyboard(0, std::ptr::null(), 0, 0);
+            }
+        } else if let Some(show_keyboard) = self.unity.show_keyboard {
+            if show {
+                show_keyboard(1, self.text.as_ptr(), self.text.len() as u32);
+            } else {
+                show_keyboard(0, std::ptr::null(), 0);
+            }
+        }
+    }
+
+    /// Set the remap table applied to pointer buttons from unity.
+    pub fn set_button_map(&mut self, map: ButtonMap) {
+        self.input_options.button_map = map;
+    }
+
+    /// Set how to handle a character sent both as a key and a text event.
+    pub fn set_key_text_convention(&mut self, convention: KeyTextConvention) {
+        self.input_options.key_text = convention;
+    }
+
+    /// Set points scrolled per wheel line and the multiplier of all scroll deltas, see
+    /// `InputOptions::points_per_scroll_line` and `InputOptions::scroll_multiplier`.
+    pub fn set_scroll_speed(&mut self, points_per_line: f32, multiplier: f32) {
+        self.input_options.points_per_scroll_line = points_per_line;
+        self.input_options.scroll_multiplier = multiplier;
+    }
+
+    /// Invert scroll and wheel deltas from unity, see `InputOptions::invert_scroll`.
+    pub fn set_invert_scroll(&mut self, invert: bool) {
+        self.input_options.invert_scroll = invert;
+    }
+
+    /// Set the number of events converted from one input, see `InputOptions::max_events_per_frame`.
+    pub fn set_max_events_per_frame(&mut self, max: usize) {
+        self.input_options.max_events_per_frame = max;
+    }
+
+    /// Set the largest texture side used when input doesn't provide one, see
+    /// `InputOptions::max_texture_side`.
+    pub fn set_max_texture_side(&mut self, side: Option<usize>) {
+        self.input_options.max_texture_side = side;
+    }
+
+    /// Set the vertex color format sent in `paint_mesh`, unity shaders must read the same format.
+    pub fn set_vertex_color_format(&mut self, format: VertexColorFormat) {
+        self.mesh_options.color_format = format;
+    }
+
+    /// Set the pixel format of textures sent to unity, the texture format in unity must match.
+    pub fn set_texture_format(&mut self, format: TextureFormat) {
+        self.texture_format = format;
+    }
+
+    /// Add a font loaded by unity at runtime as the first choice of `family`, a font with the same
+    /// name is replaced. The font is installed after the next frame and used from the one after,
+    /// fonts set by the app are kept.
+    pub fn add_font(
+        &mut self,
+        name: String,
+        bytes: Vec<u8>,
+        family: FontFamily,
+    ) -> Result<(), InvalidFont> {
+        FontRef::try_from_slice(&bytes)?;
+        self.fonts.retain(|(added, ..)| *added != name);
+        self.fonts.push((name, FontData::from_owned(bytes), family));
+        self.repaint_after = Duration::ZERO;
+        Ok(())
+    }
+
+    /// Set the fonts of `family` by name in fallback order, e.g. latin, then CJK, then emoji,
+    /// replacing the current order after the next frame like `add_font`. Names of fonts not
+    /// added are logged and skipped, the order is kept as is if none is left, which would render
+    /// only boxes.
+    pub fn set_font_fallbacks(&mut self, family: FontFamily, names: Vec<String>) {
+        self.font_fallbacks.retain(|(set, _)| *set != family);
+        self.font_fallbacks.push((family, names));
+        self.repaint_after = Duration::ZERO;
+    }
+
+    /// Install fonts added by `add_font` on top of the current font definitions, then apply
+    /// the fallback order of `set_font_fallbacks`.
+    fn install_fonts(&mut self) {
+        if self.fonts.is_empty() && self.font_fallbacks.is_empty() {
+            return;
+        }
+        let mut definitions = self
+            .context
+            .fonts(|fonts| fonts.lock().fonts.definitions().clone());
+        for (name, data, family) in self.fonts.drain(..) {
+            for names in definitions.families.values_mut() {
+                names.retain(|font| *font != name);
+            }
+            definitions
+                .families
+                .entry(family)
+                .or_default()
+                .insert(0, name.clone());
+            definitions.font_data.insert(name, data);
+        }
+        for (family, mut names) in self.font_fallbacks.drain(..) {
+            names.retain(|name| {
+                let added = definitions.font_data.contains_key(name);
+                if !added {
+                    log::error!("font `{}` not added, skipped in {:?}", name, family);
+                }
+                added
+            });
+            if names.is_empty() {
+                log::error!("no font added for {:?}, fallbacks not changed", family);
+            } else {
+                definitions.families.insert(family, names);
+            }
+        }
+        self.context.set_fonts(definitions);
+        self.repaint_after = Duration::ZERO;
     }
 
+    /// Paint to the RenderTexture identified by `target` on the unity side, 0(default) for the
+    /// target bound by unity. The target is passed to `set_render_target` before every
+    /// `begin_paint`, so each context paints to its own target.
+    pub fn set_render_target(&mut self, target: u64) {
+        self.render_target = target;
+        self.repaint_after = Duration::ZERO;
+    }
+
+    /// Paste text in the next frame, large clipboard contents go through here instead of the
+    /// input, the paste is sent to egui only once.
+    pub fn paste_text(&mut self, text: String) {
+        self.queued_events.push(Event::Paste(text));
+        self.repaint_after = Duration::ZERO;
+    }
+
+    /// Set pixels per point used from the next frame instead of the one in input, e.g. when the
+    /// window moves to a monitor with another dpi, fonts are rasterized again for the new scale.
+    /// Returns false and keeps the current value if `pixels_per_point` is not finite and positive.
+    pub fn set_pixels_per_point(&mut self, pixels_per_point: f32) -> bool {
+        if !pixels_per_point.is_finite() || pixels_per_point <= 0.0 {
+            return false;
+        }
+        self.pixels_per_point = Some(pixels_per_point);
+        self.repaint_after = Duration::ZERO;
+        true
+    }
+
+    /// Set the transform from input coordinates of unity to egui points, see `InputTransform`,
+    /// clip rects passed to `paint_mesh`, `paint_frame` and `paint_callback` are transformed back
+    /// to input coordinates, vertices stay in points. Returns false and keeps the current
+    /// transform if a component of `scale` is 0 or any component is not finite.
+    pub fn set_input_transform(&mut self, transform: InputTransform) -> bool {
+        let components = [
+            transform.offset.x,
+            transform.offset.y,
+            transform.scale.x,
+            transform.scale.y,
+        ];
+        if components.iter().any(|c| !c.is_finite())
+            || transform.scale.x == 0.0
+            || transform.scale.y == 0.0
+        {
+            return false;
+        }
+        self.input_options.transform = transform;
+        true
+    }
+
+    /// Clip rect of a primitive as passed to unity, see `set_input_transform`.
+    fn unity_clip_rect(&self, clip_rect: egui::Rect) -> egui::Rect {
+        self.input_options.transform.inverse_rect(clip_rect)
+    }
+
+    /// Set gamma applied to font coverage of font textures, `Some(1.0)`(default) keeps coverage
+    /// as is and suits the Gamma color space of unity, `None` uses the egui default(0.55) which
+    /// suits the Linear color space, where thin glyphs would look too light otherwise.
+    pub fn set_font_gamma(&mut self, gamma: Option<f32>) {
+        self.font_gamma = gamma;
+    }
+
+    /// Set options used to tessellate shapes into meshes, e.g. feathering(anti-aliasing) and its
+    /// width, same as `Context::tessellation_options_mut`.
+    pub fn set_tessellation_options(&self, options: TessellationOptions) {
+        self.context.tessellation_options_mut(|o| *o = options);
+    }
+
+    /// Options used to tessellate shapes into meshes.
+    pub fn tessellation_options(&self) -> TessellationOptions {
+        self.context.tessellation_options(|o| *o)
+    }
+
+    /// Reverse triangle winding of meshes sent in `paint_mesh`, to match culling of unity material.
+    pub fn set_reverse_winding(&mut self, reverse: bool) {
+        self.mesh_options.reverse_winding = reverse;
+    }
+
+    /// Set max level logged to unity, the level is shared by all contexts.
     pub fn set_log_level(&mut self, level: LevelFilter) {
-        self.logger.log_level = level;
+        LOGGER.log_level.store(level as usize, Ordering::Relaxed);
+        set_max_level(level);
     }
 
+    /// Send logs to `show_log` of this context, see `UnityInitializer::init_log`.
     pub fn init_log(&self) {
-        let logger: &'static UnityLogger = unsafe { std::mem::transmute(&self.logger) };
-        set_logger(logger)
-            .map(|_| set_max_level(LevelFilter::Trace))
-            .unwrap();
+        self.unity.init_log();
+    }
+}
+
+impl<T: App> Drop for UnityContext<T> {
+    fn drop(&mut self) {
+        self.app.on_exit();
+        self.release_textures();
+        if LOGGER.contexts.fetch_sub(1, Ordering::AcqRel) == 1 {
+            LOGGER
+                .show_log
+                .store(std::ptr::null_mut(), Ordering::Release);
+        }
+    }
+}
+
+impl UnityLogger {
+    fn log_level(&self) -> LevelFilter {
+        u32_to_level_filter(self.log_level.load(Ordering::Relaxed) as u32)
+    }
+}
+
+/// Convert 0..=5 to `LevelFilter::Off..=Trace`, larger values are treated as `Trace`.
+pub fn u32_to_level_filter(level: u32) -> LevelFilter {
+    match level {
+        0 => LevelFilter::Off,
+        1 => LevelFilter::Error,
+        2 => LevelFilter::Warn,
+        3 => LevelFilter::Info,
+        4 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
     }
 }
 
 impl log::Log for UnityLogger {
     fn enabled(&self, metadata: &Metadata) -> bool {
-        self.log_level >= metadata.level()
+        self.log_level() >= metadata.level()
     }
 
     fn log(&self, record: &Record) {
         if !self.enabled(record.metadata()) {
             return;
         }
+        let Some(_guard) = LogGuard::enter() else {
+            return;
+        };
+        let scoped = SCOPED_SHOW_LOG.try_with(Cell::get).ok().flatten();
+        let Some(show_log) = scoped.or_else(|| {
+            let show_log = self.show_log.load(Ordering::Acquire);
+            (!show_log.is_null()).then(|| unsafe { std::mem::transmute::<_, ShowLog>(show_log) })
+        }) else {
+            return;
+        };
         let message = format!(
             "{}[{}:{}][{}]{}",
             chrono::Local::now().format("[%Y-%m-%d %H:%M:%S%.6f]"),
@@ -245,7 +2134,7 @@ impl log::Log for UnityLogger {
             record.level(),
             record.args(),
         );
-        (self.show_log)(
+        show_log(
             log_level_to_unity(record.level()),
             message.as_ptr(),
             message.len() as i32,
@@ -255,12 +2144,89 @@ impl log::Log for UnityLogger {
     fn flush(&self) {}
 }
 
-fn log_level_to_unity(level: Level) -> i32 {
+/// A frame begun in egui, ended when dropped by a panic between `begin_frame` and `end_frame`,
+/// e.g. of an app whose panics are not caught, so the next frame doesn't begin inside it.
+/// Textures of an ended frame are kept for the next one, the rest of its output is dropped.
+struct FrameGuard {
+    context: Context,
+    aborted_textures: Rc<RefCell<TexturesDelta>>,
+    ended: bool,
+}
+
+impl FrameGuard {
+    fn begin(
+        context: &Context,
+        input: RawInput,
+        aborted_textures: &Rc<RefCell<TexturesDelta>>,
+    ) -> Self {
+        context.begin_frame(input);
+        Self {
+            context: context.clone(),
+            aborted_textures: aborted_textures.clone(),
+            ended: false,
+        }
+    }
+
+    /// End the frame with textures of aborted frames first.
+    fn end(mut self) -> FullOutput {
+        self.ended = true;
+        let mut output = self.context.end_frame();
+        let mut textures_delta = self.aborted_textures.take();
+        textures_delta.append(output.textures_delta);
+        output.textures_delta = textures_delta;
+        output
+    }
+}
+
+impl Drop for FrameGuard {
+    fn drop(&mut self) {
+        if !self.ended {
+            let output = self.context.end_frame();
+            self.aborted_textures
+                .borrow_mut()
+                .append(output.textures_delta);
+        }
+    }
+}
+
+/// Clip rect of a mesh narrowed to the bounds of its vertices in one pass, `None` if the mesh is
+/// entirely clipped, e.g. scrolled out of view.
+fn mesh_clip_rect(mesh: &Mesh, clip_rect: egui::Rect) -> Option<egui::Rect> {
+    let clip_rect = clip_rect.intersect(mesh.calc_bounds());
+    clip_rect.is_positive().then_some(clip_rect)
+}
+
+/// Best effort message of a panic payload, `panic!` gives a `&str` or a `String`.
+pub fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown")
+}
+
+fn pressed_keys(events: &[Event]) -> Vec<Key> {
+    let mut keys = Vec::new();
+    for e in events {
+        if let Event::Key {
+            key, pressed: true, ..
+        } = e
+        {
+            if !keys.contains(key) {
+                keys.push(*key);
+            }
+        }
+    }
+    keys
+}
+
+/// Severity passed to `show_log`, 1 for error, 2 warn, 3 info, 4 debug and 5 trace.
+pub fn log_level_to_unity(level: Level) -> i32 {
     match level {
         Level::Error => 1,
+        Level::Warn => 2,
+        Level::Info => 3,
         Level::Debug => 4,
-        Level::Trace => 4,
-        Level::Info => 4,
-        Level::Warn => 3,
+        Level::Trace => 5,
     }
 }
@@ -0,0 +1,37 @@
+//! Errors reported by the bridge.
+use std::fmt;
+
+/// Error of `UnityContext::update` and input parsing.
+///
+/// A null or empty buffer is valid input, a frame without input, so there is no error for it. A
+/// layout mismatch between unity and the native library can't be detected from rust either,
+/// unity checks the exported `uegui_abi_version` against `ABI_VERSION` instead. The enum is non
+/// exhaustive so failures found later can be added.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum UeguiError {
+    /// The input from unity is not a valid protobuf `Input`.
+    Parse(protobuf::Error),
+}
+
+impl fmt::Display for UeguiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UeguiError::Parse(err) => write!(f, "invalid input:{}", err),
+        }
+    }
+}
+
+impl std::error::Error for UeguiError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            UeguiError::Parse(err) => Some(err),
+        }
+    }
+}
+
+impl From<protobuf::Error> for UeguiError {
+    fn from(err: protobuf::Error) -> Self {
+        UeguiError::Parse(err)
+    }
+}
@@ -1,18 +1,23 @@
 //! Both Egui input and Unity input are complicated, so a simple but efficient way to exchange
 //! information interchangably is to use a binary protocol like protobuf. And this is the way
 //! this project is using.
-use std::ptr::slice_from_raw_parts;
+//!
+//! Conversion functions are public, so the mapping from protobuf messages in [`crate::proto`] to
+//! egui can be reused to build or test input outside of unity.
+use std::path::PathBuf;
 
 use egui::Event::PointerButton;
 use egui::{Key, RawInput};
-use protobuf::Message;
+use protobuf::{Enum, Message};
 
 use crate::proto::input::{
-    ButtonType, Event, EventType, Input, KeyType, Modifiers, Pos2, Rect, Touch, TouchPhase,
+    ButtonType, DroppedFile, Event, EventType, HoveredFile, Input, KeyType, Modifiers, MouseWheel,
+    Pos2, Rect, ScrollUnit, Touch, TouchPhase, Vec2,
 };
-use crate::Buffer;
+use crate::UeguiError;
 
-fn key_type_from_pb_to_native(t: KeyType) -> Option<Key> {
+/// Convert protobuf key type to egui key, `None` for `KT_NONE`.
+pub fn key_type_from_pb_to_native(t: KeyType) -> Option<Key> {
     match t {
         KeyType::KT_NONE => None,
         KeyType::ArrowDown => Some(Key::ArrowDown),
@@ -86,21 +91,359 @@ fn key_type_from_pb_to_native(t: KeyType) -> Option<Key> {
         KeyType::F18 => Some(Key::F18),
         KeyType::F19 => Some(Key::F19),
         KeyType::F20 => Some(Key::F20),
+        KeyType::Minus => Some(Key::Minus),
+        KeyType::PlusEquals => Some(Key::PlusEquals),
     }
 }
 
-fn button_type_from_pb_to_native(bt: ButtonType) -> Option<egui::PointerButton> {
-    match bt {
+/// Convert a `KeyType` value received from unity to egui key.
+pub fn key_from_pb_code(code: u32) -> Option<Key> {
+    KeyType::from_i32(code as i32)
+        .map(key_type_from_pb_to_native)
+        .unwrap_or_default()
+}
+
+/// Remap table for pointer buttons sent from unity, e.g. swap primary and secondary buttons for
+/// left-handed mode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ButtonMap([egui::PointerButton; egui::NUM_POINTER_BUTTONS]);
+
+impl Default for ButtonMap {
+    fn default() -> Self {
+        Self([
+            egui::PointerButton::Primary,
+            egui::PointerButton::Secondary,
+            egui::PointerButton::Middle,
+            egui::PointerButton::Extra1,
+            egui::PointerButton::Extra2,
+        ])
+    }
+}
+
+impl ButtonMap {
+    /// Mapping with primary and secondary buttons swapped.
+    pub fn left_handed() -> Self {
+        let mut map = Self::default();
+        map.set(egui::PointerButton::Primary, egui::PointerButton::Secondary);
+        map.set(egui::PointerButton::Secondary, egui::PointerButton::Primary);
+        map
+    }
+
+    /// Button `from` sent by unity will be received as `to` in egui.
+    pub fn set(&mut self, from: egui::PointerButton, to: egui::PointerButton) {
+        self.0[from as usize] = to;
+    }
+
+    /// Get the remapped button of `button`.
+    pub fn get(&self, button: egui::PointerButton) -> egui::PointerButton {
+        self.0[button as usize]
+    }
+}
+
+/// How to handle a character sent both as a key event and a text event in the same frame, some
+/// platforms send both for keys like tab, which are then inserted twice by egui.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum KeyTextConvention {
+    /// Forward all events, for platforms which never send both.
+    #[default]
+    Both,
+    /// Drop the text event and let egui handle the key.
+    PreferKey,
+    /// Drop the key press and keep the text event, except for keys egui only handles as key
+    /// events(enter, tab, backspace and delete), their text event is dropped instead.
+    PreferText,
+}
+
+/// Tracks IME composition across frames, so the preedit text shown by egui is replaced by the
+/// committed text.
+///
+/// egui ignores `CompositionUpdate` before `CompositionStart`, and only replaces the preedit on
+/// `CompositionEnd`, while unity may report preedit changes without a start event and commit by
+/// sending text.
+#[derive(Clone, Debug, Default)]
+pub struct ImeComposition {
+    composing: bool,
+}
+
+impl ImeComposition {
+    /// Returns true if a composition is in progress.
+    pub fn is_composing(&self) -> bool {
+        self.composing
+    }
+
+    /// Insert missing `CompositionStart` and turn text committed during a composition into
+    /// `CompositionEnd`.
+    pub fn process(&mut self, events: &mut Vec<egui::Event>) {
+        let mut i = 0;
+        while i < events.len() {
+            match &events[i] {
+                egui::Event::CompositionStart => self.composing = true,
+                egui::Event::CompositionUpdate(_) if !self.composing => {
+                    events.insert(i, egui::Event::CompositionStart);
+                    self.composing = true;
+                    i += 1;
+                }
+                egui::Event::Text(text) if self.composing => {
+                    events[i] = egui::Event::CompositionEnd(text.clone());
+                    self.composing = false;
+                }
+                egui::Event::CompositionEnd(_) => self.composing = false,
+                _ => (),
+            }
+            i += 1;
+        }
+    }
+}
+
+/// Multi clicks counted by unity, egui counts clicks from the frame time, which is distorted if
+/// unity coalesces or delays pointer events.
+///
+/// A release with `click_count` of 2 or more gets the fewest extra release and press pairs before
+/// it in the same frame, so egui counts at least as many clicks, at most 3 as egui does. The times
+/// egui saw the last two clicks are kept to not add clicks egui counts by itself.
+#[derive(Clone, Debug)]
+pub struct MultiClick {
+    clicks: [f64; 2],
+}
+
+impl Default for MultiClick {
+    fn default() -> Self {
+        Self {
+            clicks: [f64::NEG_INFINITY; 2],
+        }
+    }
+}
+
+impl MultiClick {
+    /// Same as `MAX_DOUBLE_CLICK_DELAY` of egui.
+    const DOUBLE_CLICK_DELAY: f64 = 0.3;
+
+    /// Count of a click at `time` as egui does.
+    fn count(&self, time: f64) -> u32 {
+        if time - self.clicks[1] < Self::DOUBLE_CLICK_DELAY * 2.0 {
+            3
+        } else if time - self.clicks[0] < Self::DOUBLE_CLICK_DELAY {
+            2
+        } else {
+            1
+        }
+    }
+
+    fn click(&mut self, time: f64) {
+        self.clicks = [time, self.clicks[0]];
+    }
+
+    /// Add pointer clicks before releases with a `click_count` egui would not count, `time` is
+    /// the time egui uses for this frame.
+    pub fn process(&mut self, events: &mut Vec<Event>, time: f64) {
+        let mut i = 0;
+        while i < events.len() {
+            let event = &events[i];
+            let is_release = event.et.enum_value() == Ok(EventType::POINTER_BUTTON)
+                && !event.pointer_button.pressed;
+            if !is_release {
+                i += 1;
+                continue;
+            }
+            let target = event.pointer_button.click_count.min(3);
+            let mut extra = Vec::new();
+            while self.count(time) < target {
+                // release the press of this click and press again
+                for pressed in [false, true] {
+                    let mut click = event.clone();
+                    let button = click.pointer_button.mut_or_insert_default();
+                    button.pressed = pressed;
+                    button.click_count = 0;
+                    extra.push(click);
+                }
+                self.click(time);
+            }
+            self.click(time);
+            let added = extra.len();
+            events.splice(i..i, extra);
+            i += added + 1;
+        }
+    }
+}
+
+/// Affine transform from input coordinates of unity to egui points, `pos * scale + offset`, for
+/// surfaces where unity raycasts give other coordinates, e.g. the uv of an in-world quad.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InputTransform {
+    pub offset: egui::Vec2,
+    pub scale: egui::Vec2,
+}
+
+impl Default for InputTransform {
+    fn default() -> Self {
+        Self {
+            offset: egui::Vec2::ZERO,
+            scale: egui::Vec2::splat(1.0),
+        }
+    }
+}
+
+impl InputTransform {
+    /// Transform a position from unity to egui points.
+    pub fn apply(&self, pos: egui::Pos2) -> egui::Pos2 {
+        egui::pos2(
+            pos.x * self.scale.x + self.offset.x,
+            pos.y * self.scale.y + self.offset.y,
+        )
+    }
+
+    /// Transform a rect in egui points back to input coordinates of unity.
+    pub fn inverse_rect(&self, rect: egui::Rect) -> egui::Rect {
+        let inverse = |pos: egui::Pos2| {
+            egui::pos2(
+                (pos.x - self.offset.x) / self.scale.x,
+                (pos.y - self.offset.y) / self.scale.y,
+            )
+        };
+        egui::Rect::from_two_pos(inverse(rect.min), inverse(rect.max))
+    }
+}
+
+/// Options used when converting input from unity to egui.
+#[derive(Clone, Debug)]
+pub struct InputOptions {
+    /// Remap table applied to pointer buttons.
+    pub button_map: ButtonMap,
+    /// How to handle a character sent both as key and text.
+    pub key_text: KeyTextConvention,
+    /// Points scrolled by a `SU_LINE` wheel delta of 1, 50 like egui-winit.
+    pub points_per_scroll_line: f32,
+    /// Multiplier of scroll and wheel deltas to tune scroll speed, 1 by default.
+    pub scroll_multiplier: f32,
+    /// Invert scroll and wheel deltas, for hosts reporting the opposite direction, e.g. to follow
+    /// the natural scrolling setting of the os.
+    pub invert_scroll: bool,
+    /// Events converted from one input, the rest are dropped with a warning so a flood from unity
+    /// can't stall the frame, 1024 by default.
+    pub max_events_per_frame: usize,
+    /// Largest texture side used when input doesn't provide one, `None` leaves the egui default
+    /// of 2048 at first and the last side set afterwards. The font atlas is as wide as this, at
+    /// most 8192, and grows in height up to it, fonts are rasterized again and the atlas uploaded
+    /// in full once it is 80% full, so a small side caps texture memory on constrained gpus at
+    /// the cost of more rebuilds with many glyphs.
+    pub max_texture_side: Option<usize>,
+    /// Transform applied to pointer and touch positions, identity by default.
+    pub transform: InputTransform,
+}
+
+impl Default for InputOptions {
+    fn default() -> Self {
+        Self {
+            button_map: Default::default(),
+            key_text: Default::default(),
+            points_per_scroll_line: 50.0,
+            scroll_multiplier: 1.0,
+            invert_scroll: false,
+            max_events_per_frame: 1024,
+            max_texture_side: None,
+            transform: Default::default(),
+        }
+    }
+}
+
+/// Text of the keys which produce a character.
+fn key_text(key: Key) -> Option<&'static str> {
+    match key {
+        Key::Tab => Some("\t"),
+        Key::Enter => Some("\n"),
+        Key::Space => Some(" "),
+        Key::Backspace => Some("\u{8}"),
+        Key::Delete => Some("\u{7f}"),
+        _ => None,
+    }
+}
+
+/// Keys `TextEdit` acts on only as key events, it ignores their text or inserts it as a control
+/// character, and tab moves the focus.
+fn key_only(key: Key) -> bool {
+    matches!(key, Key::Enter | Key::Tab | Key::Backspace | Key::Delete)
+}
+
+/// Remove duplicated characters sent as both key and text events, see `KeyTextConvention`.
+pub fn remove_duplicate_text(events: &mut Vec<egui::Event>, convention: KeyTextConvention) {
+    let pressed_text = |e: &egui::Event, filter: fn(Key) -> bool| match e {
+        egui::Event::Key {
+            key, pressed: true, ..
+        } if filter(*key) => key_text(*key),
+        _ => None,
+    };
+    let text = |e: &egui::Event| match e {
+        egui::Event::Text(text) => Some(text.clone()),
+        _ => None,
+    };
+    let remove_texts = |events: &mut Vec<egui::Event>, filter: fn(Key) -> bool| {
+        let mut keys: Vec<_> = events
+            .iter()
+            .filter_map(|e| pressed_text(e, filter))
+            .collect();
+        events.retain(|e| match text(e) {
+            Some(text) => match keys.iter().position(|k| *k == text) {
+                Some(i) => {
+                    keys.remove(i);
+                    false
+                }
+                None => true,
+            },
+            None => true,
+        });
+    };
+    match convention {
+        KeyTextConvention::Both => {}
+        KeyTextConvention::PreferKey => remove_texts(events, |_| true),
+        KeyTextConvention::PreferText => {
+            remove_texts(events, key_only);
+            let mut texts: Vec<_> = events.iter().filter_map(text).collect();
+            events.retain(|e| match pressed_text(e, |key| !key_only(key)) {
+                Some(key) => match texts.iter().position(|t| t == key) {
+                    Some(i) => {
+                        texts.remove(i);
+                        false
+                    }
+                    None => true,
+                },
+                None => true,
+            });
+        }
+    }
+}
+
+/// Convert protobuf button type to egui pointer button, remapped by `map`.
+pub fn button_type_from_pb_to_native(
+    bt: ButtonType,
+    map: &ButtonMap,
+) -> Option<egui::PointerButton> {
+    let button = match bt {
         ButtonType::BT_NONE => None,
         ButtonType::PRIMARY => Some(egui::PointerButton::Primary),
         ButtonType::SECONDARY => Some(egui::PointerButton::Secondary),
         ButtonType::MIDDLE => Some(egui::PointerButton::Middle),
         ButtonType::EXTRA1 => Some(egui::PointerButton::Extra1),
         ButtonType::EXTRA2 => Some(egui::PointerButton::Extra2),
-    }
+    };
+    button.map(|button| map.get(button))
+}
+
+/// Convert protobuf event to egui event, `None` if the event is unknown or incomplete.
+///
+/// A pointer button or enter without position is at `pointer_pos`, the last known pointer
+/// position, or the top left corner if it is `None`.
+pub fn event_from_pb_to_native(
+    e: Event,
+    options: &InputOptions,
+    mut pointer_pos: Option<egui::Pos2>,
+) -> Option<egui::Event> {
+    let mut event = event_with_unset_positions(e, options)?;
+    fill_pointer_positions(std::slice::from_mut(&mut event), &mut pointer_pos);
+    Some(event)
 }
 
-fn event_from_pb_to_native(e: Event) -> Option<egui::Event> {
+/// `event_from_pb_to_native` with pointer positions not set by unity at NaN.
+fn event_with_unset_positions(e: Event, options: &InputOptions) -> Option<egui::Event> {
     if e.et.enum_value().is_err() {
         return None;
     }
@@ -122,7 +465,7 @@ fn event_from_pb_to_native(e: Event) -> Option<egui::Event> {
             })
             .unwrap_or_default()
             .map(|kt| egui::Event::Key {
-                repeat: false,
+                repeat: e.key.repeat,
                 key: kt,
                 pressed: e.key.pressed,
                 modifiers: modifier_from_pb_to_native(&e.key.modifiers),
@@ -139,34 +482,103 @@ fn event_from_pb_to_native(e: Event) -> Option<egui::Event> {
                 b.button
                     .enum_value()
                     .ok()
-                    .map(button_type_from_pb_to_native)
+                    .map(|bt| button_type_from_pb_to_native(bt, &options.button_map))
                     .unwrap_or_default()
             })
             .unwrap_or_default()
             .map(|bt| PointerButton {
-                pos: pos2_from_pb_to_native(&e.pointer_button.pos),
+                pos: e
+                    .pointer_button
+                    .pos
+                    .as_ref()
+                    .map_or(egui::pos2(f32::NAN, f32::NAN), pos2_from_pb_to_native),
                 button: bt,
                 pressed: e.pointer_button.pressed,
                 modifiers: modifier_from_pb_to_native(&e.pointer_button.modifiers),
             }),
         EventType::POINTER_GONE => Some(egui::Event::PointerGone),
+        EventType::POINTER_ENTERED => Some(egui::Event::PointerMoved(
+            e.pointer_entered
+                .as_ref()
+                .map_or(egui::pos2(f32::NAN, f32::NAN), pos2_from_pb_to_native),
+        )),
         EventType::SCROLL => e
             .scroll
             .as_ref()
-            .map(pos2_from_pb_to_native)
-            .map(|pos| egui::Event::Scroll(egui::Vec2 { x: pos.x, y: pos.y })),
+            .map(|delta| egui::Event::Scroll(scroll_delta(delta, options))),
         EventType::ZOOM => Some(egui::Event::Zoom(e.zoom)),
         EventType::COMPOSITION_START => Some(egui::Event::CompositionStart),
         EventType::COMPOSITION_UPDATE => Some(egui::Event::CompositionUpdate(e.composition_update)),
+        EventType::COMPOSITION_END => Some(egui::Event::CompositionEnd(e.composition_end)),
         EventType::TOUCH => e
             .touch
             .as_ref()
             .map(touch_from_pb_to_native)
             .unwrap_or_default(),
+        EventType::MOUSE_WHEEL => e
+            .mouse_wheel
+            .as_ref()
+            .and_then(|wheel| mouse_wheel_from_pb_to_native(wheel, options, None)),
+        EventType::BACK => Some(back_key(true)),
+        // egui 0.21 has no focus event, `input_from_pb_to_native` sets `has_focus` instead
+        EventType::FOCUS => None,
     }
 }
 
-fn touch_from_pb_to_native(touch: &Touch) -> Option<egui::Event> {
+/// The escape key event which the android back button maps to, `input_from_pb_to_native` sends
+/// both the press and the release so the back button acts as one escape tap.
+fn back_key(pressed: bool) -> egui::Event {
+    egui::Event::Key {
+        key: egui::Key::Escape,
+        pressed,
+        repeat: false,
+        modifiers: Default::default(),
+    }
+}
+
+/// Convert a protobuf scroll delta to egui, scaled by `InputOptions::scroll_multiplier` and
+/// inverted if `InputOptions::invert_scroll` is set.
+fn scroll_delta(delta: &Vec2, options: &InputOptions) -> egui::Vec2 {
+    let delta = vec2_from_pb_to_native(delta) * options.scroll_multiplier;
+    if options.invert_scroll {
+        -delta
+    } else {
+        delta
+    }
+}
+
+/// Convert protobuf mouse wheel to `egui::Event::Zoom` if ctrl or command is held, otherwise to
+/// `egui::Event::Scroll` in points, horizontal if shift is held, the same as egui-winit does.
+///
+/// A `SU_PAGE` delta scrolls by `page`, usually the screen size, or by a line if not known.
+pub fn mouse_wheel_from_pb_to_native(
+    wheel: &MouseWheel,
+    options: &InputOptions,
+    page: Option<egui::Vec2>,
+) -> Option<egui::Event> {
+    let delta = scroll_delta(wheel.delta.as_ref()?, options);
+    let line = egui::Vec2::splat(options.points_per_scroll_line);
+    let delta = match wheel.unit.enum_value().ok()? {
+        ScrollUnit::SU_POINT => delta,
+        ScrollUnit::SU_LINE => delta * line,
+        ScrollUnit::SU_PAGE => delta * page.unwrap_or(line),
+    };
+    let modifiers = wheel
+        .modifiers
+        .as_ref()
+        .map(modifier_from_pb_to_native)
+        .unwrap_or_default();
+    if modifiers.ctrl || modifiers.command {
+        Some(egui::Event::Zoom((delta.y / 200.0).exp()))
+    } else if modifiers.shift {
+        Some(egui::Event::Scroll(egui::vec2(delta.x + delta.y, 0.0)))
+    } else {
+        Some(egui::Event::Scroll(delta))
+    }
+}
+
+/// Convert protobuf touch to `egui::Event::Touch`, force is clamped to `0.0..=1.0`.
+pub fn touch_from_pb_to_native(touch: &Touch) -> Option<egui::Event> {
     touch
         .phase
         .enum_value()
@@ -178,11 +590,13 @@ fn touch_from_pb_to_native(touch: &Touch) -> Option<egui::Event> {
             id: egui::TouchId(touch.id),
             phase,
             pos: pos2_from_pb_to_native(touch.pos.get_or_default()),
-            force: touch.force,
+            // unity reports a negative force if pressure is not available
+            force: touch.force.clamp(0.0, 1.0),
         })
 }
 
-fn touch_phase_from_pb_to_native(typ: TouchPhase) -> Option<egui::TouchPhase> {
+/// Convert protobuf touch phase to egui touch phase.
+pub fn touch_phase_from_pb_to_native(typ: TouchPhase) -> Option<egui::TouchPhase> {
     match typ {
         TouchPhase::TP_NONE => None,
         TouchPhase::START => Some(egui::TouchPhase::Start),
@@ -192,7 +606,8 @@ fn touch_phase_from_pb_to_native(typ: TouchPhase) -> Option<egui::TouchPhase> {
     }
 }
 
-fn modifier_from_pb_to_native(m: &Modifiers) -> egui::Modifiers {
+/// Convert protobuf modifiers to egui modifiers.
+pub fn modifier_from_pb_to_native(m: &Modifiers) -> egui::Modifiers {
     egui::Modifiers {
         alt: m.alt,
         ctrl: m.ctrl,
@@ -202,7 +617,20 @@ fn modifier_from_pb_to_native(m: &Modifiers) -> egui::Modifiers {
     }
 }
 
-fn rect_from_pb_to_native(rect: &Rect) -> egui::Rect {
+/// Convert egui modifiers to protobuf modifiers.
+pub fn modifier_from_native_to_pb(m: &egui::Modifiers) -> Modifiers {
+    Modifiers {
+        alt: m.alt,
+        ctrl: m.ctrl,
+        shift: m.shift,
+        mac_cmd: m.mac_cmd,
+        command: m.command,
+        ..Default::default()
+    }
+}
+
+/// Convert protobuf rect to egui rect.
+pub fn rect_from_pb_to_native(rect: &Rect) -> egui::Rect {
     egui::Rect {
         min: rect
             .min
@@ -217,36 +645,183 @@ fn rect_from_pb_to_native(rect: &Rect) -> egui::Rect {
     }
 }
 
-fn pos2_from_pb_to_native(pos: &Pos2) -> egui::Pos2 {
+/// Convert protobuf position to egui position.
+pub fn pos2_from_pb_to_native(pos: &Pos2) -> egui::Pos2 {
     egui::Pos2 { x: pos.x, y: pos.y }
 }
 
-pub fn parse_input(buffer: Buffer) -> Result<RawInput, protobuf::Error> {
-    let buffer = unsafe { &*slice_from_raw_parts(buffer.data, buffer.len) };
-    let mut pb_input = Input::default();
-    pb_input.merge_from_bytes(buffer)?;
-    let mut input = RawInput::default();
-    input.screen_rect = pb_input.screen_rect.as_ref().map(rect_from_pb_to_native);
-    input.has_focus = pb_input.has_focus;
-    if pb_input.time > 0.0 {
-        input.time = Some(pb_input.time);
+/// Convert protobuf vec2 to egui vec2.
+pub fn vec2_from_pb_to_native(vec: &Vec2) -> egui::Vec2 {
+    egui::Vec2 { x: vec.x, y: vec.y }
+}
+
+/// Combine the display scale of the os and the content scale of the app into pixels per point
+/// of egui, `dpi_scale * pixels_per_point`, a scale not provided(not positive) counts as 1.
+/// Returns `None` if neither is provided, so egui keeps its current value.
+pub fn effective_pixels_per_point(dpi_scale: f32, pixels_per_point: f32) -> Option<f32> {
+    match (dpi_scale > 0.0, pixels_per_point > 0.0) {
+        (false, false) => None,
+        (true, false) => Some(dpi_scale),
+        (false, true) => Some(pixels_per_point),
+        (true, true) => Some(dpi_scale * pixels_per_point),
     }
-    if pb_input.pixels_per_point > 0.0 {
-        input.pixels_per_point = Some(pb_input.pixels_per_point);
+}
+
+/// Convert protobuf hovered file to egui hovered file.
+pub fn hovered_file_from_pb_to_native(file: HoveredFile) -> egui::HoveredFile {
+    egui::HoveredFile {
+        path: (!file.path.is_empty()).then(|| file.path.into()),
+        mime: file.mime,
     }
-    if pb_input.max_texture_side > 0 {
-        input.max_texture_side = Some(pb_input.max_texture_side as usize);
+}
+
+/// Convert protobuf dropped file to egui dropped file, the name is taken from the path if unity
+/// only provides a path.
+pub fn dropped_file_from_pb_to_native(file: DroppedFile) -> egui::DroppedFile {
+    let path: Option<PathBuf> = (!file.path.is_empty()).then(|| file.path.into());
+    let name = if file.name.is_empty() {
+        path.as_ref()
+            .and_then(|path| path.file_name())
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    } else {
+        file.name
+    };
+    egui::DroppedFile {
+        path,
+        name,
+        last_modified: None,
+        bytes: (!file.bytes.is_empty()).then(|| file.bytes.into()),
     }
+}
+
+/// Move pointer buttons and enters at NaN to the position of the pointer before them, starting at
+/// `pointer_pos`, which is left at the last position of `events`.
+pub(crate) fn fill_pointer_positions(
+    events: &mut [egui::Event],
+    pointer_pos: &mut Option<egui::Pos2>,
+) {
+    for event in events {
+        match event {
+            egui::Event::PointerMoved(pos) | egui::Event::PointerButton { pos, .. } => {
+                if pos.any_nan() {
+                    *pos = pointer_pos.unwrap_or_default();
+                } else {
+                    *pointer_pos = Some(*pos);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Convert protobuf input to egui input, pointer buttons and enters without position are at the
+/// pointer position before them in the input, or at `pointer_pos`(see `event_from_pb_to_native`)
+/// without one.
+pub fn input_from_pb_to_native(
+    pb_input: Input,
+    options: &InputOptions,
+    mut pointer_pos: Option<egui::Pos2>,
+) -> RawInput {
+    let mut input = RawInput {
+        screen_rect: pb_input.screen_rect.as_ref().map(rect_from_pb_to_native),
+        has_focus: pb_input.has_focus,
+        ..Default::default()
+    };
+    input.time = pb_input.time;
+    input.pixels_per_point =
+        effective_pixels_per_point(pb_input.dpi_scale, pb_input.pixels_per_point);
+    input.max_texture_side = if pb_input.max_texture_side > 0 {
+        Some(pb_input.max_texture_side as usize)
+    } else {
+        options.max_texture_side
+    };
     if let Some(modifier) = pb_input.modifier.0 {
         input.modifiers = modifier_from_pb_to_native(modifier.as_ref());
     }
     if pb_input.predicted_dt > 0.0 {
         input.predicted_dt = pb_input.predicted_dt;
     }
-    for event in pb_input.events {
-        if let Some(event) = event_from_pb_to_native(event) {
-            input.events.push(event);
+    let page = input.screen_rect.map(|rect| rect.size());
+    let mut events = pb_input.events;
+    if events.len() > options.max_events_per_frame {
+        log::warn!(
+            "{} input events exceed the limit {}, the rest are dropped",
+            events.len(),
+            options.max_events_per_frame
+        );
+        events.truncate(options.max_events_per_frame);
+    }
+    input.events = Vec::with_capacity(events.len());
+    for event in events {
+        let event = match event.et.enum_value() {
+            Ok(EventType::MOUSE_WHEEL) => event
+                .mouse_wheel
+                .as_ref()
+                .and_then(|wheel| mouse_wheel_from_pb_to_native(wheel, options, page)),
+            Ok(EventType::BACK) => {
+                input.events.extend([back_key(true), back_key(false)]);
+                continue;
+            }
+            Ok(EventType::FOCUS) => {
+                input.has_focus = event.focused;
+                continue;
+            }
+            _ => event_with_unset_positions(event, options),
+        };
+        input.events.extend(event);
+    }
+    input.hovered_files = pb_input
+        .hovered_files
+        .into_iter()
+        .map(hovered_file_from_pb_to_native)
+        .collect();
+    input.dropped_files = pb_input
+        .dropped_files
+        .into_iter()
+        .map(dropped_file_from_pb_to_native)
+        .collect();
+    if options.transform != InputTransform::default() {
+        transform_positions(&mut input.events, &options.transform);
+    }
+    fill_pointer_positions(&mut input.events, &mut pointer_pos);
+    remove_duplicate_text(&mut input.events, options.key_text);
+    input
+}
+
+/// Apply `transform` to positions of pointer and touch events.
+fn transform_positions(events: &mut [egui::Event], transform: &InputTransform) {
+    for event in events {
+        match event {
+            egui::Event::PointerMoved(pos)
+            | egui::Event::PointerButton { pos, .. }
+            | egui::Event::Touch { pos, .. } => *pos = transform.apply(*pos),
+            _ => {}
         }
     }
-    Ok(input)
+}
+
+/// Parse input from protobuf encoded bytes, pointer positions not set are filled in as by
+/// `input_from_pb_to_native` without a known position.
+pub fn parse_input_bytes(bytes: &[u8], options: &InputOptions) -> Result<RawInput, UeguiError> {
+    let mut pb_input = Input::default();
+    pb_input.merge_from_bytes(bytes)?;
+    Ok(input_from_pb_to_native(pb_input, options, None))
+}
+
+/// Parse protobuf input from bytes without converting it, `None` for empty bytes(e.g. the first
+/// frame or a resize frame without payload).
+pub fn parse_pb_input_bytes(bytes: &[u8]) -> Result<Option<Input>, UeguiError> {
+    let mut pb_input = Input::default();
+    Ok(merge_pb_input_bytes(&mut pb_input, bytes)?.then_some(pb_input))
+}
+
+/// Merge protobuf input from bytes into `pb_input` as protobuf does, events are appended and
+/// other fields set in the bytes replaced, returns false for empty bytes.
+pub fn merge_pb_input_bytes(pb_input: &mut Input, bytes: &[u8]) -> Result<bool, UeguiError> {
+    if bytes.is_empty() {
+        return Ok(false);
+    }
+    pb_input.merge_from_bytes(bytes)?;
+    Ok(true)
 }
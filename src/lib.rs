@@ -2,7 +2,7 @@
 //! When writing your own application you should create a new cargo library project with crate-type
 //! has cdylib. Then use init macro as the following code
 //! ```
-//! uegui::init!(MyDemoApp, |cc| {
+//! uegui::init!(MyApp, |_cc| {
 //!    MyApp::default()
 //! });
 //!
@@ -40,11 +40,30 @@
 //!
 use std::ffi::c_void;
 
-pub use bridge::{UnityContext, UnityInitializer};
+pub use bridge::{
+    cursor_icon_to_u32, log_level_to_unity, output_event_to_u32, panic_message, panicked_in_log,
+    texture_id_to_u64, u32_to_level_filter, u64_to_texture_id, widget_type_to_u32, DebugWindow,
+    FrameStats, PointerState, TextureFormat, UnityCallback, UnityContext, UnityContextBuilder,
+    UnityInitializer, SAFE_MODE_FAILURES, WANTS_IME, WANTS_KEYBOARD_INPUT, WANTS_POINTER_INPUT,
+};
+pub use error::UeguiError;
+pub use input::{ButtonMap, ImeComposition, InputOptions, InputTransform, KeyTextConvention};
+pub use mesh::{MeshOptions, UnityVertex, VertexColorFormat};
 
 mod bridge;
-mod input;
-mod proto;
+mod error;
+pub mod input;
+pub mod mesh;
+/// Protobuf messages exchanged with unity, generated from `proto/input.proto`.
+pub mod proto;
+
+/// Version of the interface shared with unity, returned by the exported `uegui_abi_version`.
+///
+/// Bumped whenever the layout of `UnityInitializer`, `EGuiInitializer` or `Buffer`, the signature
+/// of a callback or an exported function, or `proto/input.proto` changes incompatibly, appending
+/// a callback to `UnityInitializer` or a field or event to a message included, the unity plugin
+/// should refuse to run against a native library with another version.
+pub const ABI_VERSION: u32 = 4;
 
 /// Wrapper struct used to interchange binary data from c# to rust.
 #[repr(C)]
@@ -53,52 +72,597 @@ pub struct Buffer {
     pub len: usize,
 }
 
+impl Buffer {
+    /// A buffer without data, e.g. for `UnityContext::update` of a frame without input.
+    pub const fn null() -> Self {
+        Self {
+            data: std::ptr::null(),
+            len: 0,
+        }
+    }
+
+    /// Leak bytes into a buffer returned to unity, it must be handed back with `free`.
+    pub fn from_vec(bytes: Vec<u8>) -> Self {
+        let len = bytes.len();
+        let data = Box::into_raw(bytes.into_boxed_slice()) as *const u8;
+        Self { data, len }
+    }
+
+    /// Free a buffer created by `from_vec`, an empty buffer is ignored.
+    ///
+    /// # Safety
+    /// The buffer must come from `from_vec` and be freed only once.
+    pub unsafe fn free(self) {
+        if !self.data.is_null() {
+            let bytes = std::ptr::slice_from_raw_parts_mut(self.data as *mut u8, self.len);
+            drop(Box::from_raw(bytes));
+        }
+    }
+}
+
+/// Bytes unity passed as a pointer and a length, empty for a null pointer or a length of 0, used
+/// by the functions `init` exports.
+///
+/// # Safety
+/// A pointer not null must point to `len` readable bytes valid for `'a`.
+#[doc(hidden)]
+pub unsafe fn bytes<'a>(data: *const u8, len: usize) -> &'a [u8] {
+    if data.is_null() || len == 0 {
+        &[]
+    } else {
+        std::slice::from_raw_parts(data, len)
+    }
+}
+
 /// Wrapper struct for rust exported functions and data
 #[repr(C)]
 pub struct EGuiInitializer {
     /// update function pointer
     pub update: *const c_void,
-    /// app data pointer
+    /// app data pointer, dropped with the exported `destroy`
     pub app: *mut c_void,
 }
 
+/// Requests from the app to unity during a frame, like `eframe::Frame`.
+#[derive(Debug, Default)]
+pub struct Frame {
+    close: bool,
+    events: Vec<egui::Event>,
+    keyboard_text: Option<String>,
+}
+
+impl Frame {
+    /// Ask unity to close the app, unity gets `request_close` after this frame and should
+    /// destroy the context.
+    pub fn close(&mut self) {
+        self.close = true;
+    }
+
+    /// Returns true if `close` was called in this frame.
+    pub fn close_requested(&self) -> bool {
+        self.close
+    }
+
+    /// Add an event to the start of the next frame's input, before the events from unity, e.g. a
+    /// gamepad button mapped to `Key::Enter`. Events are sent once in the order pushed.
+    pub fn push_event(&mut self, event: egui::Event) {
+        self.events.push(event);
+    }
+
+    /// Tell unity the focused text edit now holds `text` after the app changed it directly, e.g.
+    /// cleared it on submit. egui only reports text changed by input, so without this the
+    /// keyboard keeps the old text. On android the soft keyboard owns its own copy of the text and
+    /// edits it in place, it would bring the old text back with the next key, the text is sent
+    /// again with `show_keyboard` and the ime position is sent again to restart the composition.
+    pub fn set_keyboard_text(&mut self, text: impl Into<String>) {
+        self.keyboard_text = Some(text.into());
+    }
+
+    /// Events pushed by `push_event` and the text from `set_keyboard_text` in this frame.
+    pub(crate) fn into_parts(self) -> (Vec<egui::Event>, Option<String>) {
+        (self.events, self.keyboard_text)
+    }
+}
+
 /// Application trait like eframe.
 pub trait App {
+    /// Called once by `UnityContext::new` right after the app is created, before the first
+    /// frame, to configure style, fonts or tessellation of the context.
+    fn setup(&mut self, _context: &egui::Context) {}
+
     fn update(&mut self, context: &egui::Context);
+
+    /// Same as `update` with a `Frame` to talk to unity, called every frame instead of `update`
+    /// and forwards to it by default, apps using `Frame` implement this and leave `update` empty.
+    fn update_frame(&mut self, context: &egui::Context, _frame: &mut Frame) {
+        self.update(context);
+    }
+
+    /// Called every frame right before `egui::Context::begin_frame` with the final input, e.g. to
+    /// inject or filter events.
+    fn before_frame(&mut self, _input: &mut egui::RawInput) {}
+
+    /// Called every frame right after `egui::Context::end_frame` with the output before it is
+    /// handled and painted.
+    fn after_frame(&mut self, _output: &egui::FullOutput) {}
+
+    /// Called exactly once when unity destroys the context, before the app and the egui context
+    /// are dropped, no `update` follows.
+    fn on_exit(&mut self) {}
 }
 
 /// Generate exported function used for unity.
+/// ```ignore
+/// uegui::init!(MyApp, |_cc|{MyApp::default()});
 /// ```
-/// init!(MyApp, |_cc|{MyApp::default()});
+/// A prefix can be given to run several apps in one unity process, each exported function is
+/// prefixed, e.g. `myapp_init` and `myapp_update`, and generated inside module `myapp`.
+/// ```ignore
+/// uegui::init!(prefix = myapp, MyApp, |_cc|{MyApp::default()});
+/// ```
+/// C# binds the prefixed symbols with `EntryPoint`, e.g.
+/// `[DllImport("mylib", EntryPoint = "myapp_init")] static extern EGuiInitializer Init(...)`.
+///
+/// Unity frees the context returned by `init` with the exported `destroy`, exactly once.
+///
+/// The closure returns the app or a `UnityContextBuilder` to configure the context too.
+///
+/// With `try` the closure returns a `Result` of either, an error is logged through `show_log` and
+/// `init` returns an `EGuiInitializer` with null `update` and `app`, which unity must check to
+/// report the failure instead of calling `update`.
+/// ```ignore
+/// uegui::init!(try MyApp, |_cc| MyApp::load("config.json"));
+/// uegui::init!(prefix = myapp, try MyApp, |_cc| MyApp::load("config.json"));
 /// ```
 #[macro_export]
 macro_rules! init {
     ($name:ident, $app:expr) => {
-        #[no_mangle]
+        $crate::init!(@exports "", $name, |initializer| {
+            let context = $crate::UnityContext::<$name>::from_builder(initializer, $app);
+            Ok::<_, std::convert::Infallible>(context)
+        });
+    };
+    (try $name:ident, $app:expr) => {
+        $crate::init!(@exports "", $name, |initializer| {
+            $crate::UnityContext::<$name>::try_new(initializer, $app)
+        });
+    };
+    (prefix = $prefix:ident, $name:ident, $app:expr) => {
+        #[allow(non_snake_case)]
+        mod $prefix {
+            use super::*;
+
+            $crate::init!(@exports concat!(stringify!($prefix), "_"), $name, |initializer| {
+                let context = $crate::UnityContext::<$name>::from_builder(initializer, $app);
+                Ok::<_, std::convert::Infallible>(context)
+            });
+        }
+    };
+    (prefix = $prefix:ident, try $name:ident, $app:expr) => {
+        #[allow(non_snake_case)]
+        mod $prefix {
+            use super::*;
+
+            $crate::init!(@exports concat!(stringify!($prefix), "_"), $name, |initializer| {
+                $crate::UnityContext::<$name>::try_new(initializer, $app)
+            });
+        }
+    };
+    (@exports $prefix:expr, $name:ident, $create:expr) => {
+        #[export_name = concat!($prefix, "init")]
         pub extern "C" fn init(initializer: $crate::UnityInitializer) -> $crate::EGuiInitializer {
-            let context = Box::new($crate::UnityContext::new(initializer, $app));
-            context.init_log();
-            $crate::EGuiInitializer {
-                update: update as _,
-                app: Box::leak(context) as *mut $crate::UnityContext<$name> as _,
+            initializer.init_log();
+            match ($create)(initializer) {
+                Ok(context) => $crate::EGuiInitializer {
+                    update: update as _,
+                    app: Box::leak(Box::new(context)) as *mut $crate::UnityContext<$name> as _,
+                },
+                Err(err) => {
+                    log::error!("app init failed:{:?}", err);
+                    $crate::EGuiInitializer {
+                        update: std::ptr::null(),
+                        app: std::ptr::null_mut(),
+                    }
+                }
             }
         }
 
-        #[no_mangle]
-        extern "C" fn update(input: $crate::Buffer, data: *mut std::ffi::c_void, destroy: u32) {
+        /// Run a frame, `destroy_context` not 0 drops the context instead, deprecated in favor of
+        /// the exported `destroy` and kept for unity code written before it.
+        #[export_name = concat!($prefix, "update")]
+        extern "C" fn update(
+            input: $crate::Buffer,
+            data: *mut std::ffi::c_void,
+            destroy_context: u32,
+        ) {
+            if destroy_context != 0 {
+                destroy(data);
+                return;
+            }
+            let app = unsafe { &mut *(data as *mut $crate::UnityContext<$name>) };
+            if !app.catch_panics() {
+                if let Err(err) = unsafe { app.update(input) } {
+                    log::error!("unexpected error:{:?}", err);
+                }
+                return;
+            }
             if let Err(err) = std::panic::catch_unwind(|| unsafe {
-                let app = data as *mut $crate::UnityContext<$name>;
-                if destroy != 0 {
-                    let _ = Box::from_raw(app);
-                } else {
-                    let app: &mut $crate::UnityContext<$name> = &mut *app;
-                    if let Err(err) = app.update(input) {
-                        log::error!("unexpected error:{:?}", err);
-                    }
+                let app: &mut $crate::UnityContext<$name> =
+                    &mut *(data as *mut $crate::UnityContext<$name>);
+                if let Err(err) = app.update(input) {
+                    log::error!("unexpected error:{:?}", err);
                 }
             }) {
-                log::error!("unwind error:{:?}", err);
+                let app = unsafe { &*(data as *mut $crate::UnityContext<$name>) };
+                app.report_panic(&*err);
+            }
+        }
+
+        /// Drop the context returned by `init`, `App::on_exit` is called first. Call it exactly
+        /// once per context and no other exported function with it afterwards, null from a
+        /// failed `init` is ignored.
+        #[export_name = concat!($prefix, "destroy")]
+        pub extern "C" fn destroy(data: *mut std::ffi::c_void) {
+            if data.is_null() {
+                return;
+            }
+            if let Err(err) = std::panic::catch_unwind(|| unsafe {
+                let _ = Box::from_raw(data as *mut $crate::UnityContext<$name>);
+            }) {
+                if !$crate::panicked_in_log() {
+                    log::error!("unwind error:{}", $crate::panic_message(&*err));
+                }
             }
         }
+
+        /// Returns `ABI_VERSION` of the native library, unity should check it at load time.
+        #[export_name = concat!($prefix, "uegui_abi_version")]
+        extern "C" fn uegui_abi_version() -> u32 {
+            $crate::ABI_VERSION
+        }
+
+        /// Input egui wanted in the last frame without running a frame, 1 for keyboard, 2 for
+        /// pointer and 4 for the IME.
+        #[export_name = concat!($prefix, "peek_input_state")]
+        extern "C" fn peek_input_state(data: *mut std::ffi::c_void) -> u32 {
+            let app = unsafe { &*(data as *mut $crate::UnityContext<$name>) };
+            app.peek_input_state()
+        }
+
+        /// Returns 1 if the key(`KeyType` value) was pressed in the last frame and consumed by egui,
+        /// in which case unity should not handle it again.
+        #[export_name = concat!($prefix, "key_consumed")]
+        extern "C" fn key_consumed(data: *mut std::ffi::c_void, key: u32) -> u32 {
+            let app = unsafe { &*(data as *mut $crate::UnityContext<$name>) };
+            app.key_code_consumed(key) as u32
+        }
+
+        /// Let panics abort with the full backtrace if `catch` is 0, only in debug builds.
+        #[export_name = concat!($prefix, "set_catch_panics")]
+        extern "C" fn set_catch_panics(data: *mut std::ffi::c_void, catch: u32) {
+            let app = unsafe { &mut *(data as *mut $crate::UnityContext<$name>) };
+            app.set_catch_panics(catch != 0);
+        }
+
+        /// Log frames after which egui repaints immediately if `debug` is not 0.
+        #[export_name = concat!($prefix, "set_debug_repaint")]
+        extern "C" fn set_debug_repaint(data: *mut std::ffi::c_void, debug: u32) {
+            let app = unsafe { &mut *(data as *mut $crate::UnityContext<$name>) };
+            app.set_debug_repaint(debug != 0);
+        }
+
+        /// Show(1) or hide(0) a built-in egui window, 0 for settings, 1 for inspection and
+        /// 2 for memory.
+        #[export_name = concat!($prefix, "show_debug_window")]
+        extern "C" fn show_debug_window(data: *mut std::ffi::c_void, window: u32, show: u32) {
+            let app = unsafe { &mut *(data as *mut $crate::UnityContext<$name>) };
+            let window = match window {
+                0 => $crate::DebugWindow::Settings,
+                1 => $crate::DebugWindow::Inspection,
+                2 => $crate::DebugWindow::Memory,
+                _ => return,
+            };
+            app.show_debug_window(window, show != 0);
+        }
+
+        /// Set safe area insets in pixels, egui lays out inside the shrunk screen rect.
+        #[export_name = concat!($prefix, "set_safe_area")]
+        extern "C" fn set_safe_area(
+            data: *mut std::ffi::c_void,
+            left: f32,
+            top: f32,
+            right: f32,
+            bottom: f32,
+        ) {
+            let app = unsafe { &mut *(data as *mut $crate::UnityContext<$name>) };
+            app.set_safe_area(left, top, right, bottom);
+        }
+
+        /// Returns milliseconds egui is content to wait before the next `update`, 0 means
+        /// repaint immediately, `u64::MAX` means no repaint needed until new input arrives.
+        #[export_name = concat!($prefix, "repaint_after")]
+        extern "C" fn repaint_after(data: *mut std::ffi::c_void) -> u64 {
+            let app = unsafe { &*(data as *mut $crate::UnityContext<$name>) };
+            app.repaint_after_millis()
+        }
+
+        /// Returns total bytes of all live textures in unity, including the font atlas.
+        #[export_name = concat!($prefix, "texture_memory")]
+        extern "C" fn texture_memory(data: *mut std::ffi::c_void) -> u64 {
+            let app = unsafe { &*(data as *mut $crate::UnityContext<$name>) };
+            app.texture_memory() as u64
+        }
+
+        /// Set max log level, 0 for off, 1 error, 2 warn, 3 info, 4 debug and 5 trace.
+        #[export_name = concat!($prefix, "set_log_level")]
+        extern "C" fn set_log_level(data: *mut std::ffi::c_void, level: u32) {
+            let app = unsafe { &mut *(data as *mut $crate::UnityContext<$name>) };
+            app.set_log_level($crate::u32_to_level_filter(level));
+        }
+
+        /// Enable(1) or disable(0) feathering, the anti-aliasing of egui, with the feathering
+        /// width in pixels, a width of 0 or below keeps the current one.
+        #[export_name = concat!($prefix, "set_feathering")]
+        extern "C" fn set_feathering(data: *mut std::ffi::c_void, feathering: u32, size: f32) {
+            let app = unsafe { &*(data as *mut $crate::UnityContext<$name>) };
+            let mut options = app.tessellation_options();
+            options.feathering = feathering != 0;
+            if size > 0.0 {
+                options.feathering_size_in_pixels = size;
+            }
+            app.set_tessellation_options(options);
+        }
+
+        /// Switch to the dark(1) or light(0) theme of egui, with the `persistence` feature a full
+        /// style can be applied with `set_style`.
+        #[export_name = concat!($prefix, "set_visuals")]
+        extern "C" fn set_visuals(data: *mut std::ffi::c_void, is_dark: u32) {
+            let app = unsafe { &*(data as *mut $crate::UnityContext<$name>) };
+            app.set_dark_mode(is_dark != 0);
+        }
+
+        /// Paint this context to the RenderTexture `target` identifies in unity, 0 for the target
+        /// bound by unity.
+        #[export_name = concat!($prefix, "set_render_target")]
+        extern "C" fn set_render_target(data: *mut std::ffi::c_void, target: u64) {
+            let app = unsafe { &mut *(data as *mut $crate::UnityContext<$name>) };
+            app.set_render_target(target);
+        }
+
+        /// Paste text(utf8) in the next frame, for clipboard contents too large for the input.
+        #[export_name = concat!($prefix, "paste_text")]
+        extern "C" fn paste_text(data: *mut std::ffi::c_void, text: *const u8, len: u32) {
+            let app = unsafe { &mut *(data as *mut $crate::UnityContext<$name>) };
+            let text = unsafe { $crate::bytes(text, len as usize) };
+            app.paste_text(String::from_utf8_lossy(text).into_owned());
+        }
+
+        /// Push a chunk of input too large for one buffer, assembled until `is_last` is not 0 and
+        /// merged into the input of the next update.
+        #[export_name = concat!($prefix, "push_input_chunk")]
+        extern "C" fn push_input_chunk(data: *mut std::ffi::c_void, chunk: $crate::Buffer, is_last: u32) {
+            let app = unsafe { &mut *(data as *mut $crate::UnityContext<$name>) };
+            let bytes = unsafe { $crate::bytes(chunk.data, chunk.len) };
+            app.push_input_chunk(bytes, is_last != 0);
+        }
+
+        /// Set pixels per point used instead of the one in input, returns 1 if applied, 0 if it is
+        /// not finite and positive.
+        #[export_name = concat!($prefix, "set_pixels_per_point")]
+        extern "C" fn set_pixels_per_point(data: *mut std::ffi::c_void, pixels_per_point: f32) -> u32 {
+            let app = unsafe { &mut *(data as *mut $crate::UnityContext<$name>) };
+            app.set_pixels_per_point(pixels_per_point) as u32
+        }
+
+        /// Set the transform from input coordinates to points, `pos * scale + offset`, returns 1
+        /// if applied, 0 if a scale is 0 or a value is not finite.
+        #[export_name = concat!($prefix, "set_input_transform")]
+        extern "C" fn set_input_transform(
+            data: *mut std::ffi::c_void,
+            offset_x: f32,
+            offset_y: f32,
+            scale_x: f32,
+            scale_y: f32,
+        ) -> u32 {
+            let app = unsafe { &mut *(data as *mut $crate::UnityContext<$name>) };
+            app.set_input_transform($crate::InputTransform {
+                offset: [offset_x, offset_y].into(),
+                scale: [scale_x, scale_y].into(),
+            }) as u32
+        }
+
+        /// Set points scrolled per wheel line(50 by default) and the multiplier of all scroll
+        /// deltas(1 by default).
+        #[export_name = concat!($prefix, "set_scroll_speed")]
+        extern "C" fn set_scroll_speed(data: *mut std::ffi::c_void, line_height: f32, multiplier: f32) {
+            let app = unsafe { &mut *(data as *mut $crate::UnityContext<$name>) };
+            app.set_scroll_speed(line_height, multiplier);
+        }
+
+        /// Invert(1) scroll and wheel deltas or keep them(0), e.g. to follow natural scrolling.
+        #[export_name = concat!($prefix, "set_invert_scroll")]
+        extern "C" fn set_invert_scroll(data: *mut std::ffi::c_void, invert: u32) {
+            let app = unsafe { &mut *(data as *mut $crate::UnityContext<$name>) };
+            app.set_invert_scroll(invert != 0);
+        }
+
+        /// Set the number of input events handled per frame(1024 by default), the rest are
+        /// dropped.
+        #[export_name = concat!($prefix, "set_max_events_per_frame")]
+        extern "C" fn set_max_events_per_frame(data: *mut std::ffi::c_void, max: u32) {
+            let app = unsafe { &mut *(data as *mut $crate::UnityContext<$name>) };
+            app.set_max_events_per_frame(max as usize);
+        }
+
+        /// Set the largest texture side used when input doesn't provide one, 0 for the egui
+        /// default.
+        #[export_name = concat!($prefix, "set_max_texture_side")]
+        extern "C" fn set_max_texture_side(data: *mut std::ffi::c_void, side: u32) {
+            let app = unsafe { &mut *(data as *mut $crate::UnityContext<$name>) };
+            app.set_max_texture_side((side > 0).then_some(side as usize));
+        }
+
+        /// Set the vertex color format of meshes, see `VertexColorFormat::from_u32` for values,
+        /// unknown ones are ignored.
+        #[export_name = concat!($prefix, "set_vertex_color_format")]
+        extern "C" fn set_vertex_color_format(data: *mut std::ffi::c_void, format: u32) {
+            let app = unsafe { &mut *(data as *mut $crate::UnityContext<$name>) };
+            match $crate::VertexColorFormat::from_u32(format) {
+                Some(format) => app.set_vertex_color_format(format),
+                None => log::warn!("unknown vertex color format:{}", format),
+            }
+        }
+
+        /// Set the pixel format of textures, see `TextureFormat` for values, unknown ones are
+        /// ignored.
+        #[export_name = concat!($prefix, "set_texture_format")]
+        extern "C" fn set_texture_format(data: *mut std::ffi::c_void, format: u32) {
+            let app = unsafe { &mut *(data as *mut $crate::UnityContext<$name>) };
+            match $crate::TextureFormat::from_u32(format) {
+                Some(format) => app.set_texture_format(format),
+                None => log::warn!("unknown texture format:{}", format),
+            }
+        }
+
+        /// Capture the next frame, the result is sent to the `capture_ready` callback.
+        #[export_name = concat!($prefix, "request_capture")]
+        extern "C" fn request_capture(data: *mut std::ffi::c_void) {
+            let app = unsafe { &mut *(data as *mut $crate::UnityContext<$name>) };
+            app.request_capture();
+        }
+
+        /// Add a ttf/otf font loaded at runtime, 0 for proportional family and 1 for monospace, a
+        /// font added with the same name is replaced.
+        #[export_name = concat!($prefix, "add_font")]
+        extern "C" fn add_font(
+            data: *mut std::ffi::c_void,
+            name: *const u8,
+            name_len: u32,
+            bytes: *const u8,
+            bytes_len: u32,
+            family: u32,
+        ) {
+            let app = unsafe { &mut *(data as *mut $crate::UnityContext<$name>) };
+            let family = match family {
+                0 => egui::FontFamily::Proportional,
+                1 => egui::FontFamily::Monospace,
+                _ => return,
+            };
+            let name = unsafe { $crate::bytes(name, name_len as usize) };
+            let name = String::from_utf8_lossy(name).into_owned();
+            let bytes = unsafe { $crate::bytes(bytes, bytes_len as usize) };
+            if let Err(err) = app.add_font(name, bytes.to_vec(), family) {
+                log::error!("invalid font:{:?}", err);
+            }
+        }
+
+        /// Set fonts of a family(0 for proportional and 1 for monospace) in fallback order, as
+        /// names separated by '\n', fonts not added are skipped.
+        #[export_name = concat!($prefix, "set_font_fallbacks")]
+        extern "C" fn set_font_fallbacks(
+            data: *mut std::ffi::c_void,
+            family: u32,
+            names: *const u8,
+            names_len: u32,
+        ) {
+            let app = unsafe { &mut *(data as *mut $crate::UnityContext<$name>) };
+            let family = match family {
+                0 => egui::FontFamily::Proportional,
+                1 => egui::FontFamily::Monospace,
+                _ => return,
+            };
+            let names = unsafe { $crate::bytes(names, names_len as usize) };
+            let names = String::from_utf8_lossy(names)
+                .split('\n')
+                .filter(|name| !name.is_empty())
+                .map(str::to_owned)
+                .collect();
+            app.set_font_fallbacks(family, names);
+        }
+
+        $crate::persistence_exports!($prefix, $name);
     };
 }
+
+/// Generate exported functions that need the `persistence` feature, used by `init`.
+#[doc(hidden)]
+#[cfg(feature = "persistence")]
+#[macro_export]
+macro_rules! persistence_exports {
+    ($prefix:expr, $name:ident) => {
+        /// Serialize current style as json into `ptr`, returns the length of json, nothing is
+        /// written if `len` is not enough or style can't be serialized(returns 0).
+        #[export_name = concat!($prefix, "get_style")]
+        extern "C" fn get_style(data: *mut std::ffi::c_void, ptr: *mut u8, len: usize) -> usize {
+            let app = unsafe { &*(data as *mut $crate::UnityContext<$name>) };
+            match app.get_style() {
+                Ok(style) => {
+                    if style.len() <= len {
+                        unsafe { std::ptr::copy_nonoverlapping(style.as_ptr(), ptr, style.len()) };
+                    }
+                    style.len()
+                }
+                Err(err) => {
+                    log::error!("serialize style failed:{:?}", err);
+                    0
+                }
+            }
+        }
+
+        /// Apply a style serialized as json, returns 1 if applied, 0 if the style is malformed.
+        #[export_name = concat!($prefix, "set_style")]
+        extern "C" fn set_style(data: *mut std::ffi::c_void, style: $crate::Buffer) -> u32 {
+            let app = unsafe { &*(data as *mut $crate::UnityContext<$name>) };
+            let style = unsafe { $crate::bytes(style.data, style.len) };
+            match app.set_style(style) {
+                Ok(()) => 1,
+                Err(err) => {
+                    log::error!("invalid style:{:?}", err);
+                    0
+                }
+            }
+        }
+
+        /// Serialize egui memory as json into a buffer owned by rust, unity must pass it back to
+        /// `free_buffer` after copying, an empty buffer is returned if serializing fails.
+        #[export_name = concat!($prefix, "save_memory")]
+        extern "C" fn save_memory(data: *mut std::ffi::c_void) -> $crate::Buffer {
+            let app = unsafe { &*(data as *mut $crate::UnityContext<$name>) };
+            match app.save_memory() {
+                Ok(memory) => $crate::Buffer::from_vec(memory),
+                Err(err) => {
+                    log::error!("serialize memory failed:{:?}", err);
+                    $crate::Buffer::from_vec(Vec::new())
+                }
+            }
+        }
+
+        /// Restore egui memory saved by `save_memory`, returns 1 if restored, 0 if malformed.
+        /// The buffer is owned by unity.
+        #[export_name = concat!($prefix, "load_memory")]
+        extern "C" fn load_memory(data: *mut std::ffi::c_void, memory: $crate::Buffer) -> u32 {
+            let app = unsafe { &*(data as *mut $crate::UnityContext<$name>) };
+            let memory = unsafe { $crate::bytes(memory.data, memory.len) };
+            match app.load_memory(memory) {
+                Ok(()) => 1,
+                Err(err) => {
+                    log::error!("invalid memory:{:?}", err);
+                    0
+                }
+            }
+        }
+
+        /// Free a buffer returned by `save_memory`.
+        #[export_name = concat!($prefix, "free_buffer")]
+        extern "C" fn free_buffer(buffer: $crate::Buffer) {
+            unsafe { buffer.free() };
+        }
+    };
+}
+
+#[doc(hidden)]
+#[cfg(not(feature = "persistence"))]
+#[macro_export]
+macro_rules! persistence_exports {
+    ($prefix:expr, $name:ident) => {};
+}
@@ -0,0 +1,126 @@
+//! Meshes are sent to unity as egui produces them, vertices built with the `unity` feature of egui
+//! are laid out as pos, color and uv. Some unity shaders or materials expect a different layout,
+//! so optional conversions applied before `paint_mesh` are collected here.
+
+use egui::ecolor::linear_f32_from_gamma_u8;
+use egui::epaint::{Mesh, Vertex};
+
+/// Vertex layout sent to unity in `paint_mesh` with `VertexColorFormat::Color32`, egui vertices
+/// are sent as is, the assertions below fail the build if `Vertex` of egui ever differs.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct UnityVertex {
+    /// Position in points.
+    pub pos: [f32; 2],
+    /// sRGBA with premultiplied alpha.
+    pub color: u32,
+    /// Normalized texture coordinates.
+    pub uv: [f32; 2],
+}
+
+const _: () = {
+    use std::mem::{align_of, offset_of, size_of};
+    assert!(size_of::<Vertex>() == size_of::<UnityVertex>());
+    assert!(align_of::<Vertex>() == align_of::<UnityVertex>());
+    assert!(offset_of!(Vertex, pos) == offset_of!(UnityVertex, pos));
+    assert!(offset_of!(Vertex, color) == offset_of!(UnityVertex, color));
+    assert!(offset_of!(Vertex, uv) == offset_of!(UnityVertex, uv));
+};
+
+/// Vertex color format sent to unity in `paint_mesh`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum VertexColorFormat {
+    /// Packed `Color32`, sRGBA with premultiplied alpha, 20 bytes per vertex laid out as
+    /// `UnityVertex`.
+    #[default]
+    Color32,
+    /// Normalized float4 color, 32 bytes per vertex, costs a repack of every vertex.
+    Float4,
+    /// Float4 color converted from sRGB to linear the same way as `egui::Rgba`, for unity projects
+    /// in linear color space, 32 bytes per vertex. The conversion goes through a table of the 256
+    /// byte values, so it costs about as much as `Float4`.
+    LinearFloat4,
+}
+
+impl VertexColorFormat {
+    /// Format of a value sent by unity, 0 for `Color32`, 1 for `Float4` and 2 for `LinearFloat4`,
+    /// `None` if unknown.
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Color32),
+            1 => Some(Self::Float4),
+            2 => Some(Self::LinearFloat4),
+            _ => None,
+        }
+    }
+}
+
+/// Options used when converting meshes for unity.
+#[derive(Clone, Debug, Default)]
+pub struct MeshOptions {
+    /// Vertex color format.
+    pub color_format: VertexColorFormat,
+    /// Reverse winding order of every triangle, for materials culling the other face.
+    pub reverse_winding: bool,
+}
+
+/// Reverse winding order of triangles by swapping the last two indices of every triplet.
+pub fn reverse_winding(indices: &mut [u32]) {
+    for triangle in indices.chunks_exact_mut(3) {
+        triangle.swap(1, 2);
+    }
+}
+
+/// Get vertex bytes in `format`, `buffer` is used to hold the repacked vertices if needed.
+pub fn repack_vertices<'a>(
+    vertices: &'a [Vertex],
+    format: VertexColorFormat,
+    buffer: &'a mut Vec<u8>,
+) -> &'a [u8] {
+    match format {
+        VertexColorFormat::Color32 => bytemuck::cast_slice(vertices),
+        VertexColorFormat::Float4 | VertexColorFormat::LinearFloat4 => {
+            let mut table = [0.0; 256];
+            for (i, f) in table.iter_mut().enumerate() {
+                *f = match format {
+                    VertexColorFormat::LinearFloat4 => linear_f32_from_gamma_u8(i as u8),
+                    _ => i as f32 / 255.0,
+                };
+            }
+            buffer.clear();
+            buffer.reserve(vertices.len() * 32);
+            for v in vertices {
+                let [r, g, b, a] = v.color.to_array();
+                for f in [
+                    v.pos.x,
+                    v.pos.y,
+                    table[r as usize],
+                    table[g as usize],
+                    table[b as usize],
+                    a as f32 / 255.0,
+                    v.uv.x,
+                    v.uv.y,
+                ] {
+                    buffer.extend_from_slice(&f.to_ne_bytes());
+                }
+            }
+            buffer
+        }
+    }
+}
+
+/// Split a mesh with more than `u16::MAX` vertices, so every sub-mesh fits a unity mesh with
+/// 16 bit index format, indices are remapped to each sub-mesh but still sent as `u32`.
+pub fn split_mesh(mesh: Mesh) -> Vec<Mesh> {
+    if mesh.vertices.len() <= u16::MAX as usize {
+        return vec![mesh];
+    }
+    mesh.split_to_u16()
+        .into_iter()
+        .map(|mesh| Mesh {
+            indices: mesh.indices.into_iter().map(u32::from).collect(),
+            vertices: mesh.vertices,
+            texture_id: mesh.texture_id,
+        })
+        .collect()
+}
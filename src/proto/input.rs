@@ -165,6 +165,147 @@ impl ::protobuf::reflect::ProtobufValue for Pos2 {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
+///  a delta, e.g. of scrolling, in points.
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:proto.Vec2)
+pub struct Vec2 {
+    // message fields
+    // @@protoc_insertion_point(field:proto.Vec2.x)
+    pub x: f32,
+    // @@protoc_insertion_point(field:proto.Vec2.y)
+    pub y: f32,
+    // special fields
+    // @@protoc_insertion_point(special_field:proto.Vec2.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Vec2 {
+    fn default() -> &'a Vec2 {
+        <Vec2 as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Vec2 {
+    pub fn new() -> Vec2 {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "x",
+            |m: &Vec2| { &m.x },
+            |m: &mut Vec2| { &mut m.x },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "y",
+            |m: &Vec2| { &m.y },
+            |m: &mut Vec2| { &mut m.y },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Vec2>(
+            "Vec2",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Vec2 {
+    const NAME: &'static str = "Vec2";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                13 => {
+                    self.x = is.read_float()?;
+                },
+                21 => {
+                    self.y = is.read_float()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.x != 0. {
+            my_size += 1 + 4;
+        }
+        if self.y != 0. {
+            my_size += 1 + 4;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.x != 0. {
+            os.write_float(1, self.x)?;
+        }
+        if self.y != 0. {
+            os.write_float(2, self.y)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Vec2 {
+        Vec2::new()
+    }
+
+    fn clear(&mut self) {
+        self.x = 0.;
+        self.y = 0.;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Vec2 {
+        static instance: Vec2 = Vec2 {
+            x: 0.,
+            y: 0.,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Vec2 {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Vec2").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Vec2 {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Vec2 {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
 #[derive(PartialEq,Clone,Default,Debug)]
 // @@protoc_insertion_point(message:proto.Rect)
 pub struct Rect {
@@ -511,6 +652,9 @@ pub struct Key {
     pub pressed: bool,
     // @@protoc_insertion_point(field:proto.Key.modifiers)
     pub modifiers: ::protobuf::MessageField<Modifiers>,
+    ///  a pressed event generated by os key repeat while the key is held.
+    // @@protoc_insertion_point(field:proto.Key.repeat)
+    pub repeat: bool,
     // special fields
     // @@protoc_insertion_point(special_field:proto.Key.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -528,7 +672,7 @@ impl Key {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut fields = ::std::vec::Vec::with_capacity(4);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "key",
@@ -545,6 +689,11 @@ impl Key {
             |m: &Key| { &m.modifiers },
             |m: &mut Key| { &mut m.modifiers },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "repeat",
+            |m: &Key| { &m.repeat },
+            |m: &mut Key| { &mut m.repeat },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Key>(
             "Key",
             fields,
@@ -572,6 +721,9 @@ impl ::protobuf::Message for Key {
                 26 => {
                     ::protobuf::rt::read_singular_message_into_field(is, &mut self.modifiers)?;
                 },
+                32 => {
+                    self.repeat = is.read_bool()?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -594,6 +746,9 @@ impl ::protobuf::Message for Key {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
+        if self.repeat != false {
+            my_size += 1 + 1;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -609,6 +764,9 @@ impl ::protobuf::Message for Key {
         if let Some(v) = self.modifiers.as_ref() {
             ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
         }
+        if self.repeat != false {
+            os.write_bool(4, self.repeat)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -629,6 +787,7 @@ impl ::protobuf::Message for Key {
         self.key = ::protobuf::EnumOrUnknown::new(KeyType::KT_NONE);
         self.pressed = false;
         self.modifiers.clear();
+        self.repeat = false;
         self.special_fields.clear();
     }
 
@@ -637,6 +796,7 @@ impl ::protobuf::Message for Key {
             key: ::protobuf::EnumOrUnknown::from_i32(0),
             pressed: false,
             modifiers: ::protobuf::MessageField::none(),
+            repeat: false,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -664,6 +824,7 @@ impl ::protobuf::reflect::ProtobufValue for Key {
 // @@protoc_insertion_point(message:proto.PointerButton)
 pub struct PointerButton {
     // message fields
+    ///  unset to press or release at the last pointer position.
     // @@protoc_insertion_point(field:proto.PointerButton.pos)
     pub pos: ::protobuf::MessageField<Pos2>,
     // @@protoc_insertion_point(field:proto.PointerButton.button)
@@ -672,6 +833,10 @@ pub struct PointerButton {
     pub pressed: bool,
     // @@protoc_insertion_point(field:proto.PointerButton.modifiers)
     pub modifiers: ::protobuf::MessageField<Modifiers>,
+    ///  on release, the count of this click in a multi click as detected by unity, e.g. 2 for a double
+    ///  click, 0 to let egui count clicks from the frame time.
+    // @@protoc_insertion_point(field:proto.PointerButton.click_count)
+    pub click_count: u32,
     // special fields
     // @@protoc_insertion_point(special_field:proto.PointerButton.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -689,7 +854,7 @@ impl PointerButton {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut fields = ::std::vec::Vec::with_capacity(5);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Pos2>(
             "pos",
@@ -711,6 +876,11 @@ impl PointerButton {
             |m: &PointerButton| { &m.modifiers },
             |m: &mut PointerButton| { &mut m.modifiers },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "click_count",
+            |m: &PointerButton| { &m.click_count },
+            |m: &mut PointerButton| { &mut m.click_count },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PointerButton>(
             "PointerButton",
             fields,
@@ -741,6 +911,9 @@ impl ::protobuf::Message for PointerButton {
                 34 => {
                     ::protobuf::rt::read_singular_message_into_field(is, &mut self.modifiers)?;
                 },
+                40 => {
+                    self.click_count = is.read_uint32()?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -767,6 +940,9 @@ impl ::protobuf::Message for PointerButton {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
+        if self.click_count != 0 {
+            my_size += ::protobuf::rt::uint32_size(5, self.click_count);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -785,6 +961,9 @@ impl ::protobuf::Message for PointerButton {
         if let Some(v) = self.modifiers.as_ref() {
             ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
         }
+        if self.click_count != 0 {
+            os.write_uint32(5, self.click_count)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -806,6 +985,7 @@ impl ::protobuf::Message for PointerButton {
         self.button = ::protobuf::EnumOrUnknown::new(ButtonType::BT_NONE);
         self.pressed = false;
         self.modifiers.clear();
+        self.click_count = 0;
         self.special_fields.clear();
     }
 
@@ -815,6 +995,7 @@ impl ::protobuf::Message for PointerButton {
             button: ::protobuf::EnumOrUnknown::from_i32(0),
             pressed: false,
             modifiers: ::protobuf::MessageField::none(),
+            click_count: 0,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -850,6 +1031,7 @@ pub struct Touch {
     pub phase: ::protobuf::EnumOrUnknown<TouchPhase>,
     // @@protoc_insertion_point(field:proto.Touch.pos)
     pub pos: ::protobuf::MessageField<Pos2>,
+    ///  Pressure in 0..=1, negative if not available.
     // @@protoc_insertion_point(field:proto.Touch.force)
     pub force: f32,
     // special fields
@@ -1033,6 +1215,169 @@ impl ::protobuf::reflect::ProtobufValue for Touch {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
+///  a wheel or trackpad scroll with the unit of delta as reported by the os, converted to zoom if
+///  ctrl or command is held and to horizontal scroll if shift is held.
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:proto.MouseWheel)
+pub struct MouseWheel {
+    // message fields
+    // @@protoc_insertion_point(field:proto.MouseWheel.unit)
+    pub unit: ::protobuf::EnumOrUnknown<ScrollUnit>,
+    ///  same sign convention as Event.scroll.
+    // @@protoc_insertion_point(field:proto.MouseWheel.delta)
+    pub delta: ::protobuf::MessageField<Vec2>,
+    // @@protoc_insertion_point(field:proto.MouseWheel.modifiers)
+    pub modifiers: ::protobuf::MessageField<Modifiers>,
+    // special fields
+    // @@protoc_insertion_point(special_field:proto.MouseWheel.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MouseWheel {
+    fn default() -> &'a MouseWheel {
+        <MouseWheel as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MouseWheel {
+    pub fn new() -> MouseWheel {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "unit",
+            |m: &MouseWheel| { &m.unit },
+            |m: &mut MouseWheel| { &mut m.unit },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Vec2>(
+            "delta",
+            |m: &MouseWheel| { &m.delta },
+            |m: &mut MouseWheel| { &mut m.delta },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Modifiers>(
+            "modifiers",
+            |m: &MouseWheel| { &m.modifiers },
+            |m: &mut MouseWheel| { &mut m.modifiers },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MouseWheel>(
+            "MouseWheel",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for MouseWheel {
+    const NAME: &'static str = "MouseWheel";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.unit = is.read_enum_or_unknown()?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.delta)?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.modifiers)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.unit != ::protobuf::EnumOrUnknown::new(ScrollUnit::SU_POINT) {
+            my_size += ::protobuf::rt::int32_size(1, self.unit.value());
+        }
+        if let Some(v) = self.delta.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.modifiers.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.unit != ::protobuf::EnumOrUnknown::new(ScrollUnit::SU_POINT) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.unit))?;
+        }
+        if let Some(v) = self.delta.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if let Some(v) = self.modifiers.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> MouseWheel {
+        MouseWheel::new()
+    }
+
+    fn clear(&mut self) {
+        self.unit = ::protobuf::EnumOrUnknown::new(ScrollUnit::SU_POINT);
+        self.delta.clear();
+        self.modifiers.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static MouseWheel {
+        static instance: MouseWheel = MouseWheel {
+            unit: ::protobuf::EnumOrUnknown::from_i32(0),
+            delta: ::protobuf::MessageField::none(),
+            modifiers: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for MouseWheel {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MouseWheel").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for MouseWheel {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MouseWheel {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
 #[derive(PartialEq,Clone,Default,Debug)]
 // @@protoc_insertion_point(message:proto.Event)
 pub struct Event {
@@ -1055,8 +1400,10 @@ pub struct Event {
     pub pointer_button: ::protobuf::MessageField<PointerButton>,
     // @@protoc_insertion_point(field:proto.Event.pointer_gone)
     pub pointer_gone: bool,
+    ///  how to move the content, positive x and y move it right and down to reveal what is left and
+    ///  above, e.g. rolling the wheel up gives a positive y, like egui.
     // @@protoc_insertion_point(field:proto.Event.scroll)
-    pub scroll: ::protobuf::MessageField<Pos2>,
+    pub scroll: ::protobuf::MessageField<Vec2>,
     // @@protoc_insertion_point(field:proto.Event.zoom)
     pub zoom: f32,
     // @@protoc_insertion_point(field:proto.Event.composition_start)
@@ -1065,6 +1412,15 @@ pub struct Event {
     pub composition_update: ::std::string::String,
     // @@protoc_insertion_point(field:proto.Event.touch)
     pub touch: ::protobuf::MessageField<Touch>,
+    // @@protoc_insertion_point(field:proto.Event.mouse_wheel)
+    pub mouse_wheel: ::protobuf::MessageField<MouseWheel>,
+    ///  the committed text which replaces the preedit.
+    // @@protoc_insertion_point(field:proto.Event.composition_end)
+    pub composition_end: ::std::string::String,
+    // @@protoc_insertion_point(field:proto.Event.focused)
+    pub focused: bool,
+    // @@protoc_insertion_point(field:proto.Event.pointer_entered)
+    pub pointer_entered: ::protobuf::MessageField<Pos2>,
     // special fields
     // @@protoc_insertion_point(special_field:proto.Event.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -1082,7 +1438,7 @@ impl Event {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(14);
+        let mut fields = ::std::vec::Vec::with_capacity(18);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "et",
@@ -1129,7 +1485,7 @@ impl Event {
             |m: &Event| { &m.pointer_gone },
             |m: &mut Event| { &mut m.pointer_gone },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Pos2>(
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Vec2>(
             "scroll",
             |m: &Event| { &m.scroll },
             |m: &mut Event| { &mut m.scroll },
@@ -1154,6 +1510,26 @@ impl Event {
             |m: &Event| { &m.touch },
             |m: &mut Event| { &mut m.touch },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, MouseWheel>(
+            "mouse_wheel",
+            |m: &Event| { &m.mouse_wheel },
+            |m: &mut Event| { &mut m.mouse_wheel },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "composition_end",
+            |m: &Event| { &m.composition_end },
+            |m: &mut Event| { &mut m.composition_end },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "focused",
+            |m: &Event| { &m.focused },
+            |m: &mut Event| { &mut m.focused },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Pos2>(
+            "pointer_entered",
+            |m: &Event| { &m.pointer_entered },
+            |m: &mut Event| { &mut m.pointer_entered },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Event>(
             "Event",
             fields,
@@ -1214,10 +1590,22 @@ impl ::protobuf::Message for Event {
                 114 => {
                     ::protobuf::rt::read_singular_message_into_field(is, &mut self.touch)?;
                 },
-                tag => {
-                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                122 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.mouse_wheel)?;
                 },
-            };
+                130 => {
+                    self.composition_end = is.read_string()?;
+                },
+                136 => {
+                    self.focused = is.read_bool()?;
+                },
+                146 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.pointer_entered)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
         }
         ::std::result::Result::Ok(())
     }
@@ -1273,6 +1661,20 @@ impl ::protobuf::Message for Event {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
+        if let Some(v) = self.mouse_wheel.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if !self.composition_end.is_empty() {
+            my_size += ::protobuf::rt::string_size(16, &self.composition_end);
+        }
+        if self.focused != false {
+            my_size += 2 + 1;
+        }
+        if let Some(v) = self.pointer_entered.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -1321,6 +1723,18 @@ impl ::protobuf::Message for Event {
         if let Some(v) = self.touch.as_ref() {
             ::protobuf::rt::write_message_field_with_cached_size(14, v, os)?;
         }
+        if let Some(v) = self.mouse_wheel.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(15, v, os)?;
+        }
+        if !self.composition_end.is_empty() {
+            os.write_string(16, &self.composition_end)?;
+        }
+        if self.focused != false {
+            os.write_bool(17, self.focused)?;
+        }
+        if let Some(v) = self.pointer_entered.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(18, v, os)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1352,6 +1766,10 @@ impl ::protobuf::Message for Event {
         self.composition_start = false;
         self.composition_update.clear();
         self.touch.clear();
+        self.mouse_wheel.clear();
+        self.composition_end.clear();
+        self.focused = false;
+        self.pointer_entered.clear();
         self.special_fields.clear();
     }
 
@@ -1371,6 +1789,10 @@ impl ::protobuf::Message for Event {
             composition_start: false,
             composition_update: ::std::string::String::new(),
             touch: ::protobuf::MessageField::none(),
+            mouse_wheel: ::protobuf::MessageField::none(),
+            composition_end: ::std::string::String::new(),
+            focused: false,
+            pointer_entered: ::protobuf::MessageField::none(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -1394,6 +1816,306 @@ impl ::protobuf::reflect::ProtobufValue for Event {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
+///  a file dragged over the window, path on desktop, mime on webgl, empty if not provided.
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:proto.HoveredFile)
+pub struct HoveredFile {
+    // message fields
+    // @@protoc_insertion_point(field:proto.HoveredFile.path)
+    pub path: ::std::string::String,
+    // @@protoc_insertion_point(field:proto.HoveredFile.mime)
+    pub mime: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:proto.HoveredFile.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a HoveredFile {
+    fn default() -> &'a HoveredFile {
+        <HoveredFile as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl HoveredFile {
+    pub fn new() -> HoveredFile {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "path",
+            |m: &HoveredFile| { &m.path },
+            |m: &mut HoveredFile| { &mut m.path },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "mime",
+            |m: &HoveredFile| { &m.mime },
+            |m: &mut HoveredFile| { &mut m.mime },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<HoveredFile>(
+            "HoveredFile",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for HoveredFile {
+    const NAME: &'static str = "HoveredFile";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.path = is.read_string()?;
+                },
+                18 => {
+                    self.mime = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.path);
+        }
+        if !self.mime.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.mime);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.path.is_empty() {
+            os.write_string(1, &self.path)?;
+        }
+        if !self.mime.is_empty() {
+            os.write_string(2, &self.mime)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> HoveredFile {
+        HoveredFile::new()
+    }
+
+    fn clear(&mut self) {
+        self.path.clear();
+        self.mime.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static HoveredFile {
+        static instance: HoveredFile = HoveredFile {
+            path: ::std::string::String::new(),
+            mime: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for HoveredFile {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("HoveredFile").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for HoveredFile {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HoveredFile {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  a file dropped on the window, path on desktop, name and bytes on webgl, empty if not provided.
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:proto.DroppedFile)
+pub struct DroppedFile {
+    // message fields
+    // @@protoc_insertion_point(field:proto.DroppedFile.path)
+    pub path: ::std::string::String,
+    // @@protoc_insertion_point(field:proto.DroppedFile.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:proto.DroppedFile.bytes)
+    pub bytes: ::std::vec::Vec<u8>,
+    // special fields
+    // @@protoc_insertion_point(special_field:proto.DroppedFile.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a DroppedFile {
+    fn default() -> &'a DroppedFile {
+        <DroppedFile as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DroppedFile {
+    pub fn new() -> DroppedFile {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "path",
+            |m: &DroppedFile| { &m.path },
+            |m: &mut DroppedFile| { &mut m.path },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &DroppedFile| { &m.name },
+            |m: &mut DroppedFile| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "bytes",
+            |m: &DroppedFile| { &m.bytes },
+            |m: &mut DroppedFile| { &mut m.bytes },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DroppedFile>(
+            "DroppedFile",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for DroppedFile {
+    const NAME: &'static str = "DroppedFile";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.path = is.read_string()?;
+                },
+                18 => {
+                    self.name = is.read_string()?;
+                },
+                26 => {
+                    self.bytes = is.read_bytes()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.path);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name);
+        }
+        if !self.bytes.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.bytes);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.path.is_empty() {
+            os.write_string(1, &self.path)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
+        }
+        if !self.bytes.is_empty() {
+            os.write_bytes(3, &self.bytes)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> DroppedFile {
+        DroppedFile::new()
+    }
+
+    fn clear(&mut self) {
+        self.path.clear();
+        self.name.clear();
+        self.bytes.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static DroppedFile {
+        static instance: DroppedFile = DroppedFile {
+            path: ::std::string::String::new(),
+            name: ::std::string::String::new(),
+            bytes: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for DroppedFile {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DroppedFile").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for DroppedFile {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DroppedFile {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
 #[derive(PartialEq,Clone,Default,Debug)]
 // @@protoc_insertion_point(message:proto.Input)
 pub struct Input {
@@ -1404,8 +2126,11 @@ pub struct Input {
     pub pixels_per_point: f32,
     // @@protoc_insertion_point(field:proto.Input.max_texture_side)
     pub max_texture_side: u32,
+    ///  seconds since the app started, 0 included, egui advances the last time by predicted_dt if not
+    ///  set. If it goes backwards, e.g. after a scene reload, the frame comes predicted_dt after the
+    ///  last one and later times are shifted by the same offset.
     // @@protoc_insertion_point(field:proto.Input.time)
-    pub time: f64,
+    pub time: ::std::option::Option<f64>,
     // @@protoc_insertion_point(field:proto.Input.predicted_dt)
     pub predicted_dt: f32,
     // @@protoc_insertion_point(field:proto.Input.events)
@@ -1414,6 +2139,14 @@ pub struct Input {
     pub has_focus: bool,
     // @@protoc_insertion_point(field:proto.Input.modifier)
     pub modifier: ::protobuf::MessageField<Modifiers>,
+    ///  display scale of the os, multiplied with pixels_per_point which is the content scale(zoom)
+    ///  of the app, 0 means not provided.
+    // @@protoc_insertion_point(field:proto.Input.dpi_scale)
+    pub dpi_scale: f32,
+    // @@protoc_insertion_point(field:proto.Input.hovered_files)
+    pub hovered_files: ::std::vec::Vec<HoveredFile>,
+    // @@protoc_insertion_point(field:proto.Input.dropped_files)
+    pub dropped_files: ::std::vec::Vec<DroppedFile>,
     // special fields
     // @@protoc_insertion_point(special_field:proto.Input.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -1431,7 +2164,7 @@ impl Input {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(8);
+        let mut fields = ::std::vec::Vec::with_capacity(11);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Rect>(
             "screen_rect",
@@ -1448,7 +2181,7 @@ impl Input {
             |m: &Input| { &m.max_texture_side },
             |m: &mut Input| { &mut m.max_texture_side },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
             "time",
             |m: &Input| { &m.time },
             |m: &mut Input| { &mut m.time },
@@ -1473,6 +2206,21 @@ impl Input {
             |m: &Input| { &m.modifier },
             |m: &mut Input| { &mut m.modifier },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "dpi_scale",
+            |m: &Input| { &m.dpi_scale },
+            |m: &mut Input| { &mut m.dpi_scale },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "hovered_files",
+            |m: &Input| { &m.hovered_files },
+            |m: &mut Input| { &mut m.hovered_files },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "dropped_files",
+            |m: &Input| { &m.dropped_files },
+            |m: &mut Input| { &mut m.dropped_files },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Input>(
             "Input",
             fields,
@@ -1501,7 +2249,7 @@ impl ::protobuf::Message for Input {
                     self.max_texture_side = is.read_uint32()?;
                 },
                 33 => {
-                    self.time = is.read_double()?;
+                    self.time = ::std::option::Option::Some(is.read_double()?);
                 },
                 45 => {
                     self.predicted_dt = is.read_float()?;
@@ -1515,6 +2263,15 @@ impl ::protobuf::Message for Input {
                 66 => {
                     ::protobuf::rt::read_singular_message_into_field(is, &mut self.modifier)?;
                 },
+                77 => {
+                    self.dpi_scale = is.read_float()?;
+                },
+                82 => {
+                    self.hovered_files.push(is.read_message()?);
+                },
+                90 => {
+                    self.dropped_files.push(is.read_message()?);
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -1537,7 +2294,7 @@ impl ::protobuf::Message for Input {
         if self.max_texture_side != 0 {
             my_size += ::protobuf::rt::uint32_size(3, self.max_texture_side);
         }
-        if self.time != 0. {
+        if let Some(v) = self.time {
             my_size += 1 + 8;
         }
         if self.predicted_dt != 0. {
@@ -1554,6 +2311,17 @@ impl ::protobuf::Message for Input {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
+        if self.dpi_scale != 0. {
+            my_size += 1 + 4;
+        }
+        for value in &self.hovered_files {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.dropped_files {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -1569,8 +2337,8 @@ impl ::protobuf::Message for Input {
         if self.max_texture_side != 0 {
             os.write_uint32(3, self.max_texture_side)?;
         }
-        if self.time != 0. {
-            os.write_double(4, self.time)?;
+        if let Some(v) = self.time {
+            os.write_double(4, v)?;
         }
         if self.predicted_dt != 0. {
             os.write_float(5, self.predicted_dt)?;
@@ -1584,6 +2352,15 @@ impl ::protobuf::Message for Input {
         if let Some(v) = self.modifier.as_ref() {
             ::protobuf::rt::write_message_field_with_cached_size(8, v, os)?;
         }
+        if self.dpi_scale != 0. {
+            os.write_float(9, self.dpi_scale)?;
+        }
+        for v in &self.hovered_files {
+            ::protobuf::rt::write_message_field_with_cached_size(10, v, os)?;
+        };
+        for v in &self.dropped_files {
+            ::protobuf::rt::write_message_field_with_cached_size(11, v, os)?;
+        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1604,11 +2381,14 @@ impl ::protobuf::Message for Input {
         self.screen_rect.clear();
         self.pixels_per_point = 0.;
         self.max_texture_side = 0;
-        self.time = 0.;
+        self.time = ::std::option::Option::None;
         self.predicted_dt = 0.;
         self.events.clear();
         self.has_focus = false;
         self.modifier.clear();
+        self.dpi_scale = 0.;
+        self.hovered_files.clear();
+        self.dropped_files.clear();
         self.special_fields.clear();
     }
 
@@ -1617,11 +2397,14 @@ impl ::protobuf::Message for Input {
             screen_rect: ::protobuf::MessageField::none(),
             pixels_per_point: 0.,
             max_texture_side: 0,
-            time: 0.,
+            time: ::std::option::Option::None,
             predicted_dt: 0.,
             events: ::std::vec::Vec::new(),
             has_focus: false,
             modifier: ::protobuf::MessageField::none(),
+            dpi_scale: 0.,
+            hovered_files: ::std::vec::Vec::new(),
+            dropped_files: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -1792,6 +2575,10 @@ pub enum KeyType {
     F19 = 71,
     // @@protoc_insertion_point(enum_value:proto.KeyType.F20)
     F20 = 72,
+    // @@protoc_insertion_point(enum_value:proto.KeyType.Minus)
+    Minus = 73,
+    // @@protoc_insertion_point(enum_value:proto.KeyType.PlusEquals)
+    PlusEquals = 74,
 }
 
 impl ::protobuf::Enum for KeyType {
@@ -1875,6 +2662,8 @@ impl ::protobuf::Enum for KeyType {
             70 => ::std::option::Option::Some(KeyType::F18),
             71 => ::std::option::Option::Some(KeyType::F19),
             72 => ::std::option::Option::Some(KeyType::F20),
+            73 => ::std::option::Option::Some(KeyType::Minus),
+            74 => ::std::option::Option::Some(KeyType::PlusEquals),
             _ => ::std::option::Option::None
         }
     }
@@ -1952,6 +2741,8 @@ impl ::protobuf::Enum for KeyType {
         KeyType::F18,
         KeyType::F19,
         KeyType::F20,
+        KeyType::Minus,
+        KeyType::PlusEquals,
     ];
 }
 
@@ -2035,6 +2826,8 @@ impl ::protobuf::EnumFull for KeyType {
             KeyType::F18 => 69,
             KeyType::F19 => 70,
             KeyType::F20 => 71,
+            KeyType::Minus => 72,
+            KeyType::PlusEquals => 73,
         };
         Self::enum_descriptor().value_by_index(index)
     }
@@ -2219,6 +3012,16 @@ pub enum EventType {
     COMPOSITION_UPDATE = 13,
     // @@protoc_insertion_point(enum_value:proto.EventType.TOUCH)
     TOUCH = 14,
+    // @@protoc_insertion_point(enum_value:proto.EventType.MOUSE_WHEEL)
+    MOUSE_WHEEL = 15,
+    // @@protoc_insertion_point(enum_value:proto.EventType.COMPOSITION_END)
+    COMPOSITION_END = 16,
+    // @@protoc_insertion_point(enum_value:proto.EventType.BACK)
+    BACK = 17,
+    // @@protoc_insertion_point(enum_value:proto.EventType.FOCUS)
+    FOCUS = 18,
+    // @@protoc_insertion_point(enum_value:proto.EventType.POINTER_ENTERED)
+    POINTER_ENTERED = 19,
 }
 
 impl ::protobuf::Enum for EventType {
@@ -2244,6 +3047,11 @@ impl ::protobuf::Enum for EventType {
             12 => ::std::option::Option::Some(EventType::COMPOSITION_START),
             13 => ::std::option::Option::Some(EventType::COMPOSITION_UPDATE),
             14 => ::std::option::Option::Some(EventType::TOUCH),
+            15 => ::std::option::Option::Some(EventType::MOUSE_WHEEL),
+            16 => ::std::option::Option::Some(EventType::COMPOSITION_END),
+            17 => ::std::option::Option::Some(EventType::BACK),
+            18 => ::std::option::Option::Some(EventType::FOCUS),
+            19 => ::std::option::Option::Some(EventType::POINTER_ENTERED),
             _ => ::std::option::Option::None
         }
     }
@@ -2263,6 +3071,11 @@ impl ::protobuf::Enum for EventType {
         EventType::COMPOSITION_START,
         EventType::COMPOSITION_UPDATE,
         EventType::TOUCH,
+        EventType::MOUSE_WHEEL,
+        EventType::COMPOSITION_END,
+        EventType::BACK,
+        EventType::FOCUS,
+        EventType::POINTER_ENTERED,
     ];
 }
 
@@ -2288,6 +3101,11 @@ impl ::protobuf::EnumFull for EventType {
             EventType::COMPOSITION_START => 11,
             EventType::COMPOSITION_UPDATE => 12,
             EventType::TOUCH => 13,
+            EventType::MOUSE_WHEEL => 14,
+            EventType::COMPOSITION_END => 15,
+            EventType::BACK => 16,
+            EventType::FOCUS => 17,
+            EventType::POINTER_ENTERED => 18,
         };
         Self::enum_descriptor().value_by_index(index)
     }
@@ -2305,430 +3123,641 @@ impl EventType {
     }
 }
 
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:proto.ScrollUnit)
+pub enum ScrollUnit {
+    // @@protoc_insertion_point(enum_value:proto.ScrollUnit.SU_POINT)
+    SU_POINT = 0,
+    // @@protoc_insertion_point(enum_value:proto.ScrollUnit.SU_LINE)
+    SU_LINE = 1,
+    // @@protoc_insertion_point(enum_value:proto.ScrollUnit.SU_PAGE)
+    SU_PAGE = 2,
+}
+
+impl ::protobuf::Enum for ScrollUnit {
+    const NAME: &'static str = "ScrollUnit";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<ScrollUnit> {
+        match value {
+            0 => ::std::option::Option::Some(ScrollUnit::SU_POINT),
+            1 => ::std::option::Option::Some(ScrollUnit::SU_LINE),
+            2 => ::std::option::Option::Some(ScrollUnit::SU_PAGE),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [ScrollUnit] = &[
+        ScrollUnit::SU_POINT,
+        ScrollUnit::SU_LINE,
+        ScrollUnit::SU_PAGE,
+    ];
+}
+
+impl ::protobuf::EnumFull for ScrollUnit {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("ScrollUnit").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for ScrollUnit {
+    fn default() -> Self {
+        ScrollUnit::SU_POINT
+    }
+}
+
+impl ScrollUnit {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<ScrollUnit>("ScrollUnit")
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x0binput.proto\x12\x05proto\"\"\n\x04Pos2\x12\x0c\n\x01x\x18\x01\x20\
-    \x01(\x02R\x01x\x12\x0c\n\x01y\x18\x02\x20\x01(\x02R\x01y\"D\n\x04Rect\
-    \x12\x1d\n\x03min\x18\x01\x20\x01(\x0b2\x0b.proto.Pos2R\x03min\x12\x1d\n\
-    \x03max\x18\x02\x20\x01(\x0b2\x0b.proto.Pos2R\x03max\"z\n\tModifiers\x12\
-    \x10\n\x03alt\x18\x01\x20\x01(\x08R\x03alt\x12\x12\n\x04ctrl\x18\x02\x20\
-    \x01(\x08R\x04ctrl\x12\x14\n\x05shift\x18\x03\x20\x01(\x08R\x05shift\x12\
-    \x17\n\x07mac_cmd\x18\x04\x20\x01(\x08R\x06macCmd\x12\x18\n\x07command\
-    \x18\x05\x20\x01(\x08R\x07command\"q\n\x03Key\x12\x20\n\x03key\x18\x01\
-    \x20\x01(\x0e2\x0e.proto.KeyTypeR\x03key\x12\x18\n\x07pressed\x18\x02\
-    \x20\x01(\x08R\x07pressed\x12.\n\tmodifiers\x18\x03\x20\x01(\x0b2\x10.pr\
-    oto.ModifiersR\tmodifiers\"\xa3\x01\n\rPointerButton\x12\x1d\n\x03pos\
-    \x18\x01\x20\x01(\x0b2\x0b.proto.Pos2R\x03pos\x12)\n\x06button\x18\x02\
-    \x20\x01(\x0e2\x11.proto.ButtonTypeR\x06button\x12\x18\n\x07pressed\x18\
-    \x03\x20\x01(\x08R\x07pressed\x12.\n\tmodifiers\x18\x04\x20\x01(\x0b2\
-    \x10.proto.ModifiersR\tmodifiers\"\x92\x01\n\x05Touch\x12\x1b\n\tdevice_\
+    \x01(\x02R\x01x\x12\x0c\n\x01y\x18\x02\x20\x01(\x02R\x01y\"\"\n\x04Vec2\
+    \x12\x0c\n\x01x\x18\x01\x20\x01(\x02R\x01x\x12\x0c\n\x01y\x18\x02\x20\
+    \x01(\x02R\x01y\"D\n\x04Rect\x12\x1d\n\x03min\x18\x01\x20\x01(\x0b2\x0b.\
+    proto.Pos2R\x03min\x12\x1d\n\x03max\x18\x02\x20\x01(\x0b2\x0b.proto.Pos2\
+    R\x03max\"z\n\tModifiers\x12\x10\n\x03alt\x18\x01\x20\x01(\x08R\x03alt\
+    \x12\x12\n\x04ctrl\x18\x02\x20\x01(\x08R\x04ctrl\x12\x14\n\x05shift\x18\
+    \x03\x20\x01(\x08R\x05shift\x12\x17\n\x07mac_cmd\x18\x04\x20\x01(\x08R\
+    \x06macCmd\x12\x18\n\x07command\x18\x05\x20\x01(\x08R\x07command\"\x89\
+    \x01\n\x03Key\x12\x20\n\x03key\x18\x01\x20\x01(\x0e2\x0e.proto.KeyTypeR\
+    \x03key\x12\x18\n\x07pressed\x18\x02\x20\x01(\x08R\x07pressed\x12.\n\tmo\
+    difiers\x18\x03\x20\x01(\x0b2\x10.proto.ModifiersR\tmodifiers\x12\x16\n\
+    \x06repeat\x18\x04\x20\x01(\x08R\x06repeat\"\xc4\x01\n\rPointerButton\
+    \x12\x1d\n\x03pos\x18\x01\x20\x01(\x0b2\x0b.proto.Pos2R\x03pos\x12)\n\
+    \x06button\x18\x02\x20\x01(\x0e2\x11.proto.ButtonTypeR\x06button\x12\x18\
+    \n\x07pressed\x18\x03\x20\x01(\x08R\x07pressed\x12.\n\tmodifiers\x18\x04\
+    \x20\x01(\x0b2\x10.proto.ModifiersR\tmodifiers\x12\x1f\n\x0bclick_count\
+    \x18\x05\x20\x01(\rR\nclickCount\"\x92\x01\n\x05Touch\x12\x1b\n\tdevice_\
     id\x18\x01\x20\x01(\x04R\x08deviceId\x12\x0e\n\x02id\x18\x02\x20\x01(\
     \x04R\x02id\x12'\n\x05phase\x18\x03\x20\x01(\x0e2\x11.proto.TouchPhaseR\
     \x05phase\x12\x1d\n\x03pos\x18\x04\x20\x01(\x0b2\x0b.proto.Pos2R\x03pos\
-    \x12\x14\n\x05force\x18\x05\x20\x01(\x02R\x05force\"\xe2\x03\n\x05Event\
-    \x12\x20\n\x02et\x18\x01\x20\x01(\x0e2\x10.proto.EventTypeR\x02et\x12\
-    \x12\n\x04copy\x18\x02\x20\x01(\x08R\x04copy\x12\x10\n\x03cut\x18\x03\
-    \x20\x01(\x08R\x03cut\x12\x14\n\x05paste\x18\x04\x20\x01(\tR\x05paste\
-    \x12\x12\n\x04text\x18\x05\x20\x01(\tR\x04text\x12\x1c\n\x03key\x18\x06\
-    \x20\x01(\x0b2\n.proto.KeyR\x03key\x120\n\rpointer_moved\x18\x07\x20\x01\
-    (\x0b2\x0b.proto.Pos2R\x0cpointerMoved\x12;\n\x0epointer_button\x18\x08\
-    \x20\x01(\x0b2\x14.proto.PointerButtonR\rpointerButton\x12!\n\x0cpointer\
-    _gone\x18\t\x20\x01(\x08R\x0bpointerGone\x12#\n\x06scroll\x18\n\x20\x01(\
-    \x0b2\x0b.proto.Pos2R\x06scroll\x12\x12\n\x04zoom\x18\x0b\x20\x01(\x02R\
-    \x04zoom\x12+\n\x11composition_start\x18\x0c\x20\x01(\x08R\x10compositio\
-    nStart\x12-\n\x12composition_update\x18\r\x20\x01(\tR\x11compositionUpda\
-    te\x12\"\n\x05touch\x18\x0e\x20\x01(\x0b2\x0c.proto.TouchR\x05touch\"\
-    \xb1\x02\n\x05Input\x12,\n\x0bscreen_rect\x18\x01\x20\x01(\x0b2\x0b.prot\
-    o.RectR\nscreenRect\x12(\n\x10pixels_per_point\x18\x02\x20\x01(\x02R\x0e\
-    pixelsPerPoint\x12(\n\x10max_texture_side\x18\x03\x20\x01(\rR\x0emaxText\
-    ureSide\x12\x12\n\x04time\x18\x04\x20\x01(\x01R\x04time\x12!\n\x0cpredic\
-    ted_dt\x18\x05\x20\x01(\x02R\x0bpredictedDt\x12$\n\x06events\x18\x06\x20\
-    \x03(\x0b2\x0c.proto.EventR\x06events\x12\x1b\n\thas_focus\x18\x07\x20\
-    \x01(\x08R\x08hasFocus\x12,\n\x08modifier\x18\x08\x20\x01(\x0b2\x10.prot\
-    o.ModifiersR\x08modifier*\x95\x05\n\x07KeyType\x12\x0b\n\x07KT_NONE\x10\
-    \0\x12\r\n\tArrowDown\x10\x01\x12\r\n\tArrowLeft\x10\x02\x12\x0e\n\nArro\
-    wRight\x10\x03\x12\x0b\n\x07ArrowUp\x10\x04\x12\n\n\x06Escape\x10\x05\
-    \x12\x07\n\x03Tab\x10\x06\x12\r\n\tBackspace\x10\x07\x12\t\n\x05Enter\
-    \x10\x08\x12\t\n\x05Space\x10\t\x12\n\n\x06Insert\x10\n\x12\n\n\x06Delet\
-    e\x10\x0b\x12\x08\n\x04Home\x10\x0c\x12\x07\n\x03End\x10\r\x12\n\n\x06Pa\
-    geUp\x10\x0e\x12\x0c\n\x08PageDown\x10\x0f\x12\x08\n\x04Num0\x10\x10\x12\
-    \x08\n\x04Num1\x10\x11\x12\x08\n\x04Num2\x10\x12\x12\x08\n\x04Num3\x10\
-    \x13\x12\x08\n\x04Num4\x10\x14\x12\x08\n\x04Num5\x10\x15\x12\x08\n\x04Nu\
-    m6\x10\x16\x12\x08\n\x04Num7\x10\x17\x12\x08\n\x04Num8\x10\x18\x12\x08\n\
-    \x04Num9\x10\x19\x12\x05\n\x01A\x10\x1a\x12\x05\n\x01B\x10\x1b\x12\x05\n\
-    \x01C\x10\x1c\x12\x05\n\x01D\x10\x1d\x12\x05\n\x01E\x10\x1e\x12\x05\n\
-    \x01F\x10\x1f\x12\x05\n\x01G\x10\x20\x12\x05\n\x01H\x10!\x12\x05\n\x01I\
-    \x10\"\x12\x05\n\x01J\x10#\x12\x05\n\x01K\x10%\x12\x05\n\x01L\x10&\x12\
-    \x05\n\x01M\x10'\x12\x05\n\x01N\x10(\x12\x05\n\x01O\x10)\x12\x05\n\x01P\
-    \x10*\x12\x05\n\x01Q\x10+\x12\x05\n\x01R\x10,\x12\x05\n\x01S\x10-\x12\
-    \x05\n\x01T\x10.\x12\x05\n\x01U\x10/\x12\x05\n\x01V\x100\x12\x05\n\x01W\
-    \x101\x12\x05\n\x01X\x102\x12\x05\n\x01Y\x103\x12\x05\n\x01Z\x104\x12\
-    \x06\n\x02F1\x105\x12\x06\n\x02F2\x106\x12\x06\n\x02F3\x107\x12\x06\n\
-    \x02F4\x108\x12\x06\n\x02F5\x109\x12\x06\n\x02F6\x10:\x12\x06\n\x02F7\
-    \x10;\x12\x06\n\x02F8\x10<\x12\x06\n\x02F9\x10=\x12\x07\n\x03F10\x10>\
-    \x12\x07\n\x03F11\x10?\x12\x07\n\x03F12\x10@\x12\x07\n\x03F13\x10A\x12\
-    \x07\n\x03F14\x10B\x12\x07\n\x03F15\x10C\x12\x07\n\x03F16\x10D\x12\x07\n\
-    \x03F17\x10E\x12\x07\n\x03F18\x10F\x12\x07\n\x03F19\x10G\x12\x07\n\x03F2\
-    0\x10H*Y\n\nButtonType\x12\x0b\n\x07BT_NONE\x10\0\x12\x0b\n\x07PRIMARY\
-    \x10\x01\x12\r\n\tSECONDARY\x10\x02\x12\n\n\x06MIDDLE\x10\x03\x12\n\n\
+    \x12\x14\n\x05force\x18\x05\x20\x01(\x02R\x05force\"\x86\x01\n\nMouseWhe\
+    el\x12%\n\x04unit\x18\x01\x20\x01(\x0e2\x11.proto.ScrollUnitR\x04unit\
+    \x12!\n\x05delta\x18\x02\x20\x01(\x0b2\x0b.proto.Vec2R\x05delta\x12.\n\t\
+    modifiers\x18\x03\x20\x01(\x0b2\x10.proto.ModifiersR\tmodifiers\"\x8f\
+    \x05\n\x05Event\x12\x20\n\x02et\x18\x01\x20\x01(\x0e2\x10.proto.EventTyp\
+    eR\x02et\x12\x12\n\x04copy\x18\x02\x20\x01(\x08R\x04copy\x12\x10\n\x03cu\
+    t\x18\x03\x20\x01(\x08R\x03cut\x12\x14\n\x05paste\x18\x04\x20\x01(\tR\
+    \x05paste\x12\x12\n\x04text\x18\x05\x20\x01(\tR\x04text\x12\x1c\n\x03key\
+    \x18\x06\x20\x01(\x0b2\n.proto.KeyR\x03key\x120\n\rpointer_moved\x18\x07\
+    \x20\x01(\x0b2\x0b.proto.Pos2R\x0cpointerMoved\x12;\n\x0epointer_button\
+    \x18\x08\x20\x01(\x0b2\x14.proto.PointerButtonR\rpointerButton\x12!\n\
+    \x0cpointer_gone\x18\t\x20\x01(\x08R\x0bpointerGone\x12#\n\x06scroll\x18\
+    \n\x20\x01(\x0b2\x0b.proto.Vec2R\x06scroll\x12\x12\n\x04zoom\x18\x0b\x20\
+    \x01(\x02R\x04zoom\x12+\n\x11composition_start\x18\x0c\x20\x01(\x08R\x10\
+    compositionStart\x12-\n\x12composition_update\x18\r\x20\x01(\tR\x11compo\
+    sitionUpdate\x12\"\n\x05touch\x18\x0e\x20\x01(\x0b2\x0c.proto.TouchR\x05\
+    touch\x122\n\x0bmouse_wheel\x18\x0f\x20\x01(\x0b2\x11.proto.MouseWheelR\
+    \nmouseWheel\x12'\n\x0fcomposition_end\x18\x10\x20\x01(\tR\x0ecompositio\
+    nEnd\x12\x18\n\x07focused\x18\x11\x20\x01(\x08R\x07focused\x124\n\x0fpoi\
+    nter_entered\x18\x12\x20\x01(\x0b2\x0b.proto.Pos2R\x0epointerEntered\"5\
+    \n\x0bHoveredFile\x12\x12\n\x04path\x18\x01\x20\x01(\tR\x04path\x12\x12\
+    \n\x04mime\x18\x02\x20\x01(\tR\x04mime\"K\n\x0bDroppedFile\x12\x12\n\x04\
+    path\x18\x01\x20\x01(\tR\x04path\x12\x12\n\x04name\x18\x02\x20\x01(\tR\
+    \x04name\x12\x14\n\x05bytes\x18\x03\x20\x01(\x0cR\x05bytes\"\xce\x03\n\
+    \x05Input\x12,\n\x0bscreen_rect\x18\x01\x20\x01(\x0b2\x0b.proto.RectR\ns\
+    creenRect\x12(\n\x10pixels_per_point\x18\x02\x20\x01(\x02R\x0epixelsPerP\
+    oint\x12(\n\x10max_texture_side\x18\x03\x20\x01(\rR\x0emaxTextureSide\
+    \x12\x17\n\x04time\x18\x04\x20\x01(\x01H\0R\x04time\x88\x01\x01\x12!\n\
+    \x0cpredicted_dt\x18\x05\x20\x01(\x02R\x0bpredictedDt\x12$\n\x06events\
+    \x18\x06\x20\x03(\x0b2\x0c.proto.EventR\x06events\x12\x1b\n\thas_focus\
+    \x18\x07\x20\x01(\x08R\x08hasFocus\x12,\n\x08modifier\x18\x08\x20\x01(\
+    \x0b2\x10.proto.ModifiersR\x08modifier\x12\x1b\n\tdpi_scale\x18\t\x20\
+    \x01(\x02R\x08dpiScale\x127\n\rhovered_files\x18\n\x20\x03(\x0b2\x12.pro\
+    to.HoveredFileR\x0choveredFiles\x127\n\rdropped_files\x18\x0b\x20\x03(\
+    \x0b2\x12.proto.DroppedFileR\x0cdroppedFilesB\x07\n\x05_time*\xb0\x05\n\
+    \x07KeyType\x12\x0b\n\x07KT_NONE\x10\0\x12\r\n\tArrowDown\x10\x01\x12\r\
+    \n\tArrowLeft\x10\x02\x12\x0e\n\nArrowRight\x10\x03\x12\x0b\n\x07ArrowUp\
+    \x10\x04\x12\n\n\x06Escape\x10\x05\x12\x07\n\x03Tab\x10\x06\x12\r\n\tBac\
+    kspace\x10\x07\x12\t\n\x05Enter\x10\x08\x12\t\n\x05Space\x10\t\x12\n\n\
+    \x06Insert\x10\n\x12\n\n\x06Delete\x10\x0b\x12\x08\n\x04Home\x10\x0c\x12\
+    \x07\n\x03End\x10\r\x12\n\n\x06PageUp\x10\x0e\x12\x0c\n\x08PageDown\x10\
+    \x0f\x12\x08\n\x04Num0\x10\x10\x12\x08\n\x04Num1\x10\x11\x12\x08\n\x04Nu\
+    m2\x10\x12\x12\x08\n\x04Num3\x10\x13\x12\x08\n\x04Num4\x10\x14\x12\x08\n\
+    \x04Num5\x10\x15\x12\x08\n\x04Num6\x10\x16\x12\x08\n\x04Num7\x10\x17\x12\
+    \x08\n\x04Num8\x10\x18\x12\x08\n\x04Num9\x10\x19\x12\x05\n\x01A\x10\x1a\
+    \x12\x05\n\x01B\x10\x1b\x12\x05\n\x01C\x10\x1c\x12\x05\n\x01D\x10\x1d\
+    \x12\x05\n\x01E\x10\x1e\x12\x05\n\x01F\x10\x1f\x12\x05\n\x01G\x10\x20\
+    \x12\x05\n\x01H\x10!\x12\x05\n\x01I\x10\"\x12\x05\n\x01J\x10#\x12\x05\n\
+    \x01K\x10%\x12\x05\n\x01L\x10&\x12\x05\n\x01M\x10'\x12\x05\n\x01N\x10(\
+    \x12\x05\n\x01O\x10)\x12\x05\n\x01P\x10*\x12\x05\n\x01Q\x10+\x12\x05\n\
+    \x01R\x10,\x12\x05\n\x01S\x10-\x12\x05\n\x01T\x10.\x12\x05\n\x01U\x10/\
+    \x12\x05\n\x01V\x100\x12\x05\n\x01W\x101\x12\x05\n\x01X\x102\x12\x05\n\
+    \x01Y\x103\x12\x05\n\x01Z\x104\x12\x06\n\x02F1\x105\x12\x06\n\x02F2\x106\
+    \x12\x06\n\x02F3\x107\x12\x06\n\x02F4\x108\x12\x06\n\x02F5\x109\x12\x06\
+    \n\x02F6\x10:\x12\x06\n\x02F7\x10;\x12\x06\n\x02F8\x10<\x12\x06\n\x02F9\
+    \x10=\x12\x07\n\x03F10\x10>\x12\x07\n\x03F11\x10?\x12\x07\n\x03F12\x10@\
+    \x12\x07\n\x03F13\x10A\x12\x07\n\x03F14\x10B\x12\x07\n\x03F15\x10C\x12\
+    \x07\n\x03F16\x10D\x12\x07\n\x03F17\x10E\x12\x07\n\x03F18\x10F\x12\x07\n\
+    \x03F19\x10G\x12\x07\n\x03F20\x10H\x12\t\n\x05Minus\x10I\x12\x0e\n\nPlus\
+    Equals\x10J*Y\n\nButtonType\x12\x0b\n\x07BT_NONE\x10\0\x12\x0b\n\x07PRIM\
+    ARY\x10\x01\x12\r\n\tSECONDARY\x10\x02\x12\n\n\x06MIDDLE\x10\x03\x12\n\n\
     \x06EXTRA1\x10\x04\x12\n\n\x06EXTRA2\x10\x05*C\n\nTouchPhase\x12\x0b\n\
     \x07TP_NONE\x10\0\x12\t\n\x05START\x10\x01\x12\x08\n\x04MOVE\x10\x02\x12\
-    \x07\n\x03END\x10\x03\x12\n\n\x06CANCEL\x10\x04*\xd2\x01\n\tEventType\
+    \x07\n\x03END\x10\x03\x12\n\n\x06CANCEL\x10\x04*\xa2\x02\n\tEventType\
     \x12\x0b\n\x07ET_NONE\x10\0\x12\x08\n\x04COPY\x10\x02\x12\x07\n\x03CUT\
     \x10\x03\x12\t\n\x05PASTE\x10\x04\x12\x08\n\x04TEXT\x10\x05\x12\x07\n\
     \x03KEY\x10\x06\x12\x11\n\rPOINTER_MOVED\x10\x07\x12\x12\n\x0ePOINTER_BU\
     TTON\x10\x08\x12\x10\n\x0cPOINTER_GONE\x10\t\x12\n\n\x06SCROLL\x10\n\x12\
     \x08\n\x04ZOOM\x10\x0b\x12\x15\n\x11COMPOSITION_START\x10\x0c\x12\x16\n\
-    \x12COMPOSITION_UPDATE\x10\r\x12\t\n\x05TOUCH\x10\x0eJ\xab=\n\x07\x12\
-    \x05\0\0\xc2\x01\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\x02\x12\
-    \x03\x02\0\x0e\n\n\n\x02\x04\0\x12\x04\x04\0\x07\x01\n\n\n\x03\x04\0\x01\
-    \x12\x03\x04\x08\x0c\n\x0b\n\x04\x04\0\x02\0\x12\x03\x05\x02\x0e\n\x0c\n\
-    \x05\x04\0\x02\0\x05\x12\x03\x05\x02\x07\n\x0c\n\x05\x04\0\x02\0\x01\x12\
-    \x03\x05\x08\t\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x05\x0c\r\n\x0b\n\x04\
-    \x04\0\x02\x01\x12\x03\x06\x02\x0e\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\
-    \x06\x02\x07\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x06\x08\t\n\x0c\n\x05\
-    \x04\0\x02\x01\x03\x12\x03\x06\x0c\r\n\n\n\x02\x04\x01\x12\x04\t\0\x0c\
-    \x01\n\n\n\x03\x04\x01\x01\x12\x03\t\x08\x0c\n\x0b\n\x04\x04\x01\x02\0\
-    \x12\x03\n\x02\x0f\n\x0c\n\x05\x04\x01\x02\0\x06\x12\x03\n\x02\x06\n\x0c\
-    \n\x05\x04\x01\x02\0\x01\x12\x03\n\x07\n\n\x0c\n\x05\x04\x01\x02\0\x03\
-    \x12\x03\n\r\x0e\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x0b\x02\x0f\n\x0c\n\
-    \x05\x04\x01\x02\x01\x06\x12\x03\x0b\x02\x06\n\x0c\n\x05\x04\x01\x02\x01\
-    \x01\x12\x03\x0b\x07\n\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x0b\r\x0e\
-    \n\n\n\x02\x04\x02\x12\x04\x0e\0\x14\x01\n\n\n\x03\x04\x02\x01\x12\x03\
-    \x0e\x08\x11\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x0f\x02\x0f\n\x0c\n\x05\
-    \x04\x02\x02\0\x05\x12\x03\x0f\x02\x06\n\x0c\n\x05\x04\x02\x02\0\x01\x12\
-    \x03\x0f\x07\n\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x0f\r\x0e\n\x0b\n\
-    \x04\x04\x02\x02\x01\x12\x03\x10\x02\x10\n\x0c\n\x05\x04\x02\x02\x01\x05\
-    \x12\x03\x10\x02\x06\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\x10\x07\x0b\
-    \n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x10\x0e\x0f\n\x0b\n\x04\x04\x02\
-    \x02\x02\x12\x03\x11\x02\x11\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\x03\x11\
-    \x02\x06\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x11\x07\x0c\n\x0c\n\x05\
-    \x04\x02\x02\x02\x03\x12\x03\x11\x0f\x10\n\x0b\n\x04\x04\x02\x02\x03\x12\
-    \x03\x12\x02\x13\n\x0c\n\x05\x04\x02\x02\x03\x05\x12\x03\x12\x02\x06\n\
-    \x0c\n\x05\x04\x02\x02\x03\x01\x12\x03\x12\x07\x0e\n\x0c\n\x05\x04\x02\
-    \x02\x03\x03\x12\x03\x12\x11\x12\n\x0b\n\x04\x04\x02\x02\x04\x12\x03\x13\
-    \x02\x13\n\x0c\n\x05\x04\x02\x02\x04\x05\x12\x03\x13\x02\x06\n\x0c\n\x05\
-    \x04\x02\x02\x04\x01\x12\x03\x13\x07\x0e\n\x0c\n\x05\x04\x02\x02\x04\x03\
-    \x12\x03\x13\x11\x12\n\n\n\x02\x05\0\x12\x04\x16\0o\x01\n\n\n\x03\x05\0\
-    \x01\x12\x03\x16\x05\x0c\n\x0b\n\x04\x05\0\x02\0\x12\x03\x17\x02\x0e\n\
-    \x0c\n\x05\x05\0\x02\0\x01\x12\x03\x17\x02\t\n\x0c\n\x05\x05\0\x02\0\x02\
-    \x12\x03\x17\x0c\r\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x18\x02\x10\n\x0c\n\
-    \x05\x05\0\x02\x01\x01\x12\x03\x18\x02\x0b\n\x0c\n\x05\x05\0\x02\x01\x02\
-    \x12\x03\x18\x0e\x0f\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x19\x02\x10\n\x0c\
-    \n\x05\x05\0\x02\x02\x01\x12\x03\x19\x02\x0b\n\x0c\n\x05\x05\0\x02\x02\
-    \x02\x12\x03\x19\x0e\x0f\n\x0b\n\x04\x05\0\x02\x03\x12\x03\x1a\x02\x11\n\
-    \x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x1a\x02\x0c\n\x0c\n\x05\x05\0\x02\
-    \x03\x02\x12\x03\x1a\x0f\x10\n\x0b\n\x04\x05\0\x02\x04\x12\x03\x1b\x02\
-    \x0e\n\x0c\n\x05\x05\0\x02\x04\x01\x12\x03\x1b\x02\t\n\x0c\n\x05\x05\0\
-    \x02\x04\x02\x12\x03\x1b\x0c\r\n\x0b\n\x04\x05\0\x02\x05\x12\x03\x1d\x02\
-    \r\n\x0c\n\x05\x05\0\x02\x05\x01\x12\x03\x1d\x02\x08\n\x0c\n\x05\x05\0\
-    \x02\x05\x02\x12\x03\x1d\x0b\x0c\n\x0b\n\x04\x05\0\x02\x06\x12\x03\x1e\
-    \x02\n\n\x0c\n\x05\x05\0\x02\x06\x01\x12\x03\x1e\x02\x05\n\x0c\n\x05\x05\
-    \0\x02\x06\x02\x12\x03\x1e\x08\t\n\x0b\n\x04\x05\0\x02\x07\x12\x03\x1f\
-    \x02\x10\n\x0c\n\x05\x05\0\x02\x07\x01\x12\x03\x1f\x02\x0b\n\x0c\n\x05\
-    \x05\0\x02\x07\x02\x12\x03\x1f\x0e\x0f\n\x0b\n\x04\x05\0\x02\x08\x12\x03\
-    \x20\x02\x0c\n\x0c\n\x05\x05\0\x02\x08\x01\x12\x03\x20\x02\x07\n\x0c\n\
-    \x05\x05\0\x02\x08\x02\x12\x03\x20\n\x0b\n\x0b\n\x04\x05\0\x02\t\x12\x03\
-    !\x02\x0c\n\x0c\n\x05\x05\0\x02\t\x01\x12\x03!\x02\x07\n\x0c\n\x05\x05\0\
-    \x02\t\x02\x12\x03!\n\x0b\n\x0b\n\x04\x05\0\x02\n\x12\x03#\x02\x0e\n\x0c\
-    \n\x05\x05\0\x02\n\x01\x12\x03#\x02\x08\n\x0c\n\x05\x05\0\x02\n\x02\x12\
-    \x03#\x0b\r\n\x0b\n\x04\x05\0\x02\x0b\x12\x03$\x02\x0e\n\x0c\n\x05\x05\0\
-    \x02\x0b\x01\x12\x03$\x02\x08\n\x0c\n\x05\x05\0\x02\x0b\x02\x12\x03$\x0b\
-    \r\n\x0b\n\x04\x05\0\x02\x0c\x12\x03%\x02\x0c\n\x0c\n\x05\x05\0\x02\x0c\
-    \x01\x12\x03%\x02\x06\n\x0c\n\x05\x05\0\x02\x0c\x02\x12\x03%\t\x0b\n\x0b\
-    \n\x04\x05\0\x02\r\x12\x03&\x02\x0b\n\x0c\n\x05\x05\0\x02\r\x01\x12\x03&\
-    \x02\x05\n\x0c\n\x05\x05\0\x02\r\x02\x12\x03&\x08\n\n\x0b\n\x04\x05\0\
-    \x02\x0e\x12\x03'\x02\x0e\n\x0c\n\x05\x05\0\x02\x0e\x01\x12\x03'\x02\x08\
-    \n\x0c\n\x05\x05\0\x02\x0e\x02\x12\x03'\x0b\r\n\x0b\n\x04\x05\0\x02\x0f\
-    \x12\x03(\x02\x10\n\x0c\n\x05\x05\0\x02\x0f\x01\x12\x03(\x02\n\n\x0c\n\
-    \x05\x05\0\x02\x0f\x02\x12\x03(\r\x0f\n<\n\x04\x05\0\x02\x10\x12\x03+\
+    \x12COMPOSITION_UPDATE\x10\r\x12\t\n\x05TOUCH\x10\x0e\x12\x0f\n\x0bMOUSE\
+    _WHEEL\x10\x0f\x12\x13\n\x0fCOMPOSITION_END\x10\x10\x12\x08\n\x04BACK\
+    \x10\x11\x12\t\n\x05FOCUS\x10\x12\x12\x13\n\x0fPOINTER_ENTERED\x10\x13*4\
+    \n\nScrollUnit\x12\x0c\n\x08SU_POINT\x10\0\x12\x0b\n\x07SU_LINE\x10\x01\
+    \x12\x0b\n\x07SU_PAGE\x10\x02J\xd3Z\n\x07\x12\x05\0\0\x89\x02\x01\n\x08\
+    \n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\x02\x12\x03\x02\0\x0e\n\n\n\x02\
+    \x04\0\x12\x04\x04\0\x07\x01\n\n\n\x03\x04\0\x01\x12\x03\x04\x08\x0c\n\
+    \x0b\n\x04\x04\0\x02\0\x12\x03\x05\x02\x0e\n\x0c\n\x05\x04\0\x02\0\x05\
+    \x12\x03\x05\x02\x07\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x05\x08\t\n\x0c\
+    \n\x05\x04\0\x02\0\x03\x12\x03\x05\x0c\r\n\x0b\n\x04\x04\0\x02\x01\x12\
+    \x03\x06\x02\x0e\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x06\x02\x07\n\x0c\
+    \n\x05\x04\0\x02\x01\x01\x12\x03\x06\x08\t\n\x0c\n\x05\x04\0\x02\x01\x03\
+    \x12\x03\x06\x0c\r\n4\n\x02\x04\x01\x12\x04\n\0\r\x01\x1a(\x20a\x20delta\
+    ,\x20e.g.\x20of\x20scrolling,\x20in\x20points.\n\n\n\n\x03\x04\x01\x01\
+    \x12\x03\n\x08\x0c\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x0b\x02\x0e\n\x0c\n\
+    \x05\x04\x01\x02\0\x05\x12\x03\x0b\x02\x07\n\x0c\n\x05\x04\x01\x02\0\x01\
+    \x12\x03\x0b\x08\t\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x0b\x0c\r\n\x0b\
+    \n\x04\x04\x01\x02\x01\x12\x03\x0c\x02\x0e\n\x0c\n\x05\x04\x01\x02\x01\
+    \x05\x12\x03\x0c\x02\x07\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x0c\x08\
+    \t\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x0c\x0c\r\n\n\n\x02\x04\x02\
+    \x12\x04\x0f\0\x12\x01\n\n\n\x03\x04\x02\x01\x12\x03\x0f\x08\x0c\n\x0b\n\
+    \x04\x04\x02\x02\0\x12\x03\x10\x02\x0f\n\x0c\n\x05\x04\x02\x02\0\x06\x12\
+    \x03\x10\x02\x06\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x10\x07\n\n\x0c\n\
+    \x05\x04\x02\x02\0\x03\x12\x03\x10\r\x0e\n\x0b\n\x04\x04\x02\x02\x01\x12\
+    \x03\x11\x02\x0f\n\x0c\n\x05\x04\x02\x02\x01\x06\x12\x03\x11\x02\x06\n\
+    \x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\x11\x07\n\n\x0c\n\x05\x04\x02\x02\
+    \x01\x03\x12\x03\x11\r\x0e\n\n\n\x02\x04\x03\x12\x04\x14\0\x1a\x01\n\n\n\
+    \x03\x04\x03\x01\x12\x03\x14\x08\x11\n\x0b\n\x04\x04\x03\x02\0\x12\x03\
+    \x15\x02\x0f\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x15\x02\x06\n\x0c\n\
+    \x05\x04\x03\x02\0\x01\x12\x03\x15\x07\n\n\x0c\n\x05\x04\x03\x02\0\x03\
+    \x12\x03\x15\r\x0e\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\x16\x02\x10\n\x0c\
+    \n\x05\x04\x03\x02\x01\x05\x12\x03\x16\x02\x06\n\x0c\n\x05\x04\x03\x02\
+    \x01\x01\x12\x03\x16\x07\x0b\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03\x16\
+    \x0e\x0f\n\x0b\n\x04\x04\x03\x02\x02\x12\x03\x17\x02\x11\n\x0c\n\x05\x04\
+    \x03\x02\x02\x05\x12\x03\x17\x02\x06\n\x0c\n\x05\x04\x03\x02\x02\x01\x12\
+    \x03\x17\x07\x0c\n\x0c\n\x05\x04\x03\x02\x02\x03\x12\x03\x17\x0f\x10\n\
+    \x0b\n\x04\x04\x03\x02\x03\x12\x03\x18\x02\x13\n\x0c\n\x05\x04\x03\x02\
+    \x03\x05\x12\x03\x18\x02\x06\n\x0c\n\x05\x04\x03\x02\x03\x01\x12\x03\x18\
+    \x07\x0e\n\x0c\n\x05\x04\x03\x02\x03\x03\x12\x03\x18\x11\x12\n\x0b\n\x04\
+    \x04\x03\x02\x04\x12\x03\x19\x02\x13\n\x0c\n\x05\x04\x03\x02\x04\x05\x12\
+    \x03\x19\x02\x06\n\x0c\n\x05\x04\x03\x02\x04\x01\x12\x03\x19\x07\x0e\n\
+    \x0c\n\x05\x04\x03\x02\x04\x03\x12\x03\x19\x11\x12\n\n\n\x02\x05\0\x12\
+    \x04\x1c\0x\x01\n\n\n\x03\x05\0\x01\x12\x03\x1c\x05\x0c\n\x0b\n\x04\x05\
+    \0\x02\0\x12\x03\x1d\x02\x0e\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x1d\x02\
+    \t\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x1d\x0c\r\n\x0b\n\x04\x05\0\x02\
+    \x01\x12\x03\x1e\x02\x10\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x1e\x02\
+    \x0b\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x1e\x0e\x0f\n\x0b\n\x04\x05\0\
+    \x02\x02\x12\x03\x1f\x02\x10\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x1f\
+    \x02\x0b\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x1f\x0e\x0f\n\x0b\n\x04\
+    \x05\0\x02\x03\x12\x03\x20\x02\x11\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\
+    \x20\x02\x0c\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\x20\x0f\x10\n\x0b\n\
+    \x04\x05\0\x02\x04\x12\x03!\x02\x0e\n\x0c\n\x05\x05\0\x02\x04\x01\x12\
+    \x03!\x02\t\n\x0c\n\x05\x05\0\x02\x04\x02\x12\x03!\x0c\r\n\x0b\n\x04\x05\
+    \0\x02\x05\x12\x03#\x02\r\n\x0c\n\x05\x05\0\x02\x05\x01\x12\x03#\x02\x08\
+    \n\x0c\n\x05\x05\0\x02\x05\x02\x12\x03#\x0b\x0c\n\x0b\n\x04\x05\0\x02\
+    \x06\x12\x03$\x02\n\n\x0c\n\x05\x05\0\x02\x06\x01\x12\x03$\x02\x05\n\x0c\
+    \n\x05\x05\0\x02\x06\x02\x12\x03$\x08\t\n\x0b\n\x04\x05\0\x02\x07\x12\
+    \x03%\x02\x10\n\x0c\n\x05\x05\0\x02\x07\x01\x12\x03%\x02\x0b\n\x0c\n\x05\
+    \x05\0\x02\x07\x02\x12\x03%\x0e\x0f\n\x0b\n\x04\x05\0\x02\x08\x12\x03&\
+    \x02\x0c\n\x0c\n\x05\x05\0\x02\x08\x01\x12\x03&\x02\x07\n\x0c\n\x05\x05\
+    \0\x02\x08\x02\x12\x03&\n\x0b\n\x0b\n\x04\x05\0\x02\t\x12\x03'\x02\x0c\n\
+    \x0c\n\x05\x05\0\x02\t\x01\x12\x03'\x02\x07\n\x0c\n\x05\x05\0\x02\t\x02\
+    \x12\x03'\n\x0b\n\x0b\n\x04\x05\0\x02\n\x12\x03)\x02\x0e\n\x0c\n\x05\x05\
+    \0\x02\n\x01\x12\x03)\x02\x08\n\x0c\n\x05\x05\0\x02\n\x02\x12\x03)\x0b\r\
+    \n\x0b\n\x04\x05\0\x02\x0b\x12\x03*\x02\x0e\n\x0c\n\x05\x05\0\x02\x0b\
+    \x01\x12\x03*\x02\x08\n\x0c\n\x05\x05\0\x02\x0b\x02\x12\x03*\x0b\r\n\x0b\
+    \n\x04\x05\0\x02\x0c\x12\x03+\x02\x0c\n\x0c\n\x05\x05\0\x02\x0c\x01\x12\
+    \x03+\x02\x06\n\x0c\n\x05\x05\0\x02\x0c\x02\x12\x03+\t\x0b\n\x0b\n\x04\
+    \x05\0\x02\r\x12\x03,\x02\x0b\n\x0c\n\x05\x05\0\x02\r\x01\x12\x03,\x02\
+    \x05\n\x0c\n\x05\x05\0\x02\r\x02\x12\x03,\x08\n\n\x0b\n\x04\x05\0\x02\
+    \x0e\x12\x03-\x02\x0e\n\x0c\n\x05\x05\0\x02\x0e\x01\x12\x03-\x02\x08\n\
+    \x0c\n\x05\x05\0\x02\x0e\x02\x12\x03-\x0b\r\n\x0b\n\x04\x05\0\x02\x0f\
+    \x12\x03.\x02\x10\n\x0c\n\x05\x05\0\x02\x0f\x01\x12\x03.\x02\n\n\x0c\n\
+    \x05\x05\0\x02\x0f\x02\x12\x03.\r\x0f\n<\n\x04\x05\0\x02\x10\x12\x031\
     \x02\x0c\x1a//\x20Either\x20from\x20the\x20main\x20row\x20or\x20from\x20\
-    the\x20numpad.\n\n\x0c\n\x05\x05\0\x02\x10\x01\x12\x03+\x02\x06\n\x0c\n\
-    \x05\x05\0\x02\x10\x02\x12\x03+\t\x0b\n<\n\x04\x05\0\x02\x11\x12\x03-\
+    the\x20numpad.\n\n\x0c\n\x05\x05\0\x02\x10\x01\x12\x031\x02\x06\n\x0c\n\
+    \x05\x05\0\x02\x10\x02\x12\x031\t\x0b\n<\n\x04\x05\0\x02\x11\x12\x033\
     \x02\x0c\x1a//\x20Either\x20from\x20the\x20main\x20row\x20or\x20from\x20\
-    the\x20numpad.\n\n\x0c\n\x05\x05\0\x02\x11\x01\x12\x03-\x02\x06\n\x0c\n\
-    \x05\x05\0\x02\x11\x02\x12\x03-\t\x0b\n<\n\x04\x05\0\x02\x12\x12\x03/\
+    the\x20numpad.\n\n\x0c\n\x05\x05\0\x02\x11\x01\x12\x033\x02\x06\n\x0c\n\
+    \x05\x05\0\x02\x11\x02\x12\x033\t\x0b\n<\n\x04\x05\0\x02\x12\x12\x035\
     \x02\x0c\x1a//\x20Either\x20from\x20the\x20main\x20row\x20or\x20from\x20\
-    the\x20numpad.\n\n\x0c\n\x05\x05\0\x02\x12\x01\x12\x03/\x02\x06\n\x0c\n\
-    \x05\x05\0\x02\x12\x02\x12\x03/\t\x0b\n<\n\x04\x05\0\x02\x13\x12\x031\
+    the\x20numpad.\n\n\x0c\n\x05\x05\0\x02\x12\x01\x12\x035\x02\x06\n\x0c\n\
+    \x05\x05\0\x02\x12\x02\x12\x035\t\x0b\n<\n\x04\x05\0\x02\x13\x12\x037\
     \x02\x0c\x1a//\x20Either\x20from\x20the\x20main\x20row\x20or\x20from\x20\
-    the\x20numpad.\n\n\x0c\n\x05\x05\0\x02\x13\x01\x12\x031\x02\x06\n\x0c\n\
-    \x05\x05\0\x02\x13\x02\x12\x031\t\x0b\n<\n\x04\x05\0\x02\x14\x12\x033\
+    the\x20numpad.\n\n\x0c\n\x05\x05\0\x02\x13\x01\x12\x037\x02\x06\n\x0c\n\
+    \x05\x05\0\x02\x13\x02\x12\x037\t\x0b\n<\n\x04\x05\0\x02\x14\x12\x039\
     \x02\x0c\x1a//\x20Either\x20from\x20the\x20main\x20row\x20or\x20from\x20\
-    the\x20numpad.\n\n\x0c\n\x05\x05\0\x02\x14\x01\x12\x033\x02\x06\n\x0c\n\
-    \x05\x05\0\x02\x14\x02\x12\x033\t\x0b\n<\n\x04\x05\0\x02\x15\x12\x035\
+    the\x20numpad.\n\n\x0c\n\x05\x05\0\x02\x14\x01\x12\x039\x02\x06\n\x0c\n\
+    \x05\x05\0\x02\x14\x02\x12\x039\t\x0b\n<\n\x04\x05\0\x02\x15\x12\x03;\
     \x02\x0c\x1a//\x20Either\x20from\x20the\x20main\x20row\x20or\x20from\x20\
-    the\x20numpad.\n\n\x0c\n\x05\x05\0\x02\x15\x01\x12\x035\x02\x06\n\x0c\n\
-    \x05\x05\0\x02\x15\x02\x12\x035\t\x0b\n<\n\x04\x05\0\x02\x16\x12\x037\
+    the\x20numpad.\n\n\x0c\n\x05\x05\0\x02\x15\x01\x12\x03;\x02\x06\n\x0c\n\
+    \x05\x05\0\x02\x15\x02\x12\x03;\t\x0b\n<\n\x04\x05\0\x02\x16\x12\x03=\
     \x02\x0c\x1a//\x20Either\x20from\x20the\x20main\x20row\x20or\x20from\x20\
-    the\x20numpad.\n\n\x0c\n\x05\x05\0\x02\x16\x01\x12\x037\x02\x06\n\x0c\n\
-    \x05\x05\0\x02\x16\x02\x12\x037\t\x0b\n<\n\x04\x05\0\x02\x17\x12\x039\
+    the\x20numpad.\n\n\x0c\n\x05\x05\0\x02\x16\x01\x12\x03=\x02\x06\n\x0c\n\
+    \x05\x05\0\x02\x16\x02\x12\x03=\t\x0b\n<\n\x04\x05\0\x02\x17\x12\x03?\
     \x02\x0c\x1a//\x20Either\x20from\x20the\x20main\x20row\x20or\x20from\x20\
-    the\x20numpad.\n\n\x0c\n\x05\x05\0\x02\x17\x01\x12\x039\x02\x06\n\x0c\n\
-    \x05\x05\0\x02\x17\x02\x12\x039\t\x0b\n<\n\x04\x05\0\x02\x18\x12\x03;\
+    the\x20numpad.\n\n\x0c\n\x05\x05\0\x02\x17\x01\x12\x03?\x02\x06\n\x0c\n\
+    \x05\x05\0\x02\x17\x02\x12\x03?\t\x0b\n<\n\x04\x05\0\x02\x18\x12\x03A\
     \x02\x0c\x1a//\x20Either\x20from\x20the\x20main\x20row\x20or\x20from\x20\
-    the\x20numpad.\n\n\x0c\n\x05\x05\0\x02\x18\x01\x12\x03;\x02\x06\n\x0c\n\
-    \x05\x05\0\x02\x18\x02\x12\x03;\t\x0b\n<\n\x04\x05\0\x02\x19\x12\x03=\
+    the\x20numpad.\n\n\x0c\n\x05\x05\0\x02\x18\x01\x12\x03A\x02\x06\n\x0c\n\
+    \x05\x05\0\x02\x18\x02\x12\x03A\t\x0b\n<\n\x04\x05\0\x02\x19\x12\x03C\
     \x02\x0c\x1a//\x20Either\x20from\x20the\x20main\x20row\x20or\x20from\x20\
-    the\x20numpad.\n\n\x0c\n\x05\x05\0\x02\x19\x01\x12\x03=\x02\x06\n\x0c\n\
-    \x05\x05\0\x02\x19\x02\x12\x03=\t\x0b\n*\n\x04\x05\0\x02\x1a\x12\x03?\
+    the\x20numpad.\n\n\x0c\n\x05\x05\0\x02\x19\x01\x12\x03C\x02\x06\n\x0c\n\
+    \x05\x05\0\x02\x19\x02\x12\x03C\t\x0b\n*\n\x04\x05\0\x02\x1a\x12\x03E\
     \x02\t\"\x1d\x20Used\x20for\x20cmd+A\x20(select\x20All)\n\n\x0c\n\x05\
-    \x05\0\x02\x1a\x01\x12\x03?\x02\x03\n\x0c\n\x05\x05\0\x02\x1a\x02\x12\
-    \x03?\x06\x08\n\x0b\n\x04\x05\0\x02\x1b\x12\x03@\x02\t\n\x0c\n\x05\x05\0\
-    \x02\x1b\x01\x12\x03@\x02\x03\n\x0c\n\x05\x05\0\x02\x1b\x02\x12\x03@\x06\
-    \x08\n\x19\n\x04\x05\0\x02\x1c\x12\x03A\x02\t\"\x0c\x20|CMD\x20COPY|\n\n\
-    \x0c\n\x05\x05\0\x02\x1c\x01\x12\x03A\x02\x03\n\x0c\n\x05\x05\0\x02\x1c\
-    \x02\x12\x03A\x06\x08\n\x1d\n\x04\x05\0\x02\x1d\x12\x03B\x02\t\"\x10\x20\
-    |CMD\x20BOOKMARK|\n\n\x0c\n\x05\x05\0\x02\x1d\x01\x12\x03B\x02\x03\n\x0c\
-    \n\x05\x05\0\x02\x1d\x02\x12\x03B\x06\x08\n\x1b\n\x04\x05\0\x02\x1e\x12\
-    \x03C\x02\t\"\x0e\x20|CMD\x20SEARCH|\n\n\x0c\n\x05\x05\0\x02\x1e\x01\x12\
-    \x03C\x02\x03\n\x0c\n\x05\x05\0\x02\x1e\x02\x12\x03C\x06\x08\n*\n\x04\
-    \x05\0\x02\x1f\x12\x03D\x02\t\"\x1d\x20|CMD\x20FIND\x20firefox\x20&\x20c\
-    hrome|\n\n\x0c\n\x05\x05\0\x02\x1f\x01\x12\x03D\x02\x03\n\x0c\n\x05\x05\
-    \0\x02\x1f\x02\x12\x03D\x06\x08\n\x20\n\x04\x05\0\x02\x20\x12\x03E\x02\t\
+    \x05\0\x02\x1a\x01\x12\x03E\x02\x03\n\x0c\n\x05\x05\0\x02\x1a\x02\x12\
+    \x03E\x06\x08\n\x0b\n\x04\x05\0\x02\x1b\x12\x03F\x02\t\n\x0c\n\x05\x05\0\
+    \x02\x1b\x01\x12\x03F\x02\x03\n\x0c\n\x05\x05\0\x02\x1b\x02\x12\x03F\x06\
+    \x08\n\x19\n\x04\x05\0\x02\x1c\x12\x03G\x02\t\"\x0c\x20|CMD\x20COPY|\n\n\
+    \x0c\n\x05\x05\0\x02\x1c\x01\x12\x03G\x02\x03\n\x0c\n\x05\x05\0\x02\x1c\
+    \x02\x12\x03G\x06\x08\n\x1d\n\x04\x05\0\x02\x1d\x12\x03H\x02\t\"\x10\x20\
+    |CMD\x20BOOKMARK|\n\n\x0c\n\x05\x05\0\x02\x1d\x01\x12\x03H\x02\x03\n\x0c\
+    \n\x05\x05\0\x02\x1d\x02\x12\x03H\x06\x08\n\x1b\n\x04\x05\0\x02\x1e\x12\
+    \x03I\x02\t\"\x0e\x20|CMD\x20SEARCH|\n\n\x0c\n\x05\x05\0\x02\x1e\x01\x12\
+    \x03I\x02\x03\n\x0c\n\x05\x05\0\x02\x1e\x02\x12\x03I\x06\x08\n*\n\x04\
+    \x05\0\x02\x1f\x12\x03J\x02\t\"\x1d\x20|CMD\x20FIND\x20firefox\x20&\x20c\
+    hrome|\n\n\x0c\n\x05\x05\0\x02\x1f\x01\x12\x03J\x02\x03\n\x0c\n\x05\x05\
+    \0\x02\x1f\x02\x12\x03J\x06\x08\n\x20\n\x04\x05\0\x02\x20\x12\x03K\x02\t\
     \"\x13\x20|CMD\x20FIND\x20chrome|\n\n\x0c\n\x05\x05\0\x02\x20\x01\x12\
-    \x03E\x02\x03\n\x0c\n\x05\x05\0\x02\x20\x02\x12\x03E\x06\x08\n\x1c\n\x04\
-    \x05\0\x02!\x12\x03F\x02\t\"\x0f\x20|CMD\x20History|\n\n\x0c\n\x05\x05\0\
-    \x02!\x01\x12\x03F\x02\x03\n\x0c\n\x05\x05\0\x02!\x02\x12\x03F\x06\x08\n\
-    \x16\n\x04\x05\0\x02\"\x12\x03G\x02\t\"\t\x20italics\n\n\x0c\n\x05\x05\0\
-    \x02\"\x01\x12\x03G\x02\x03\n\x0c\n\x05\x05\0\x02\"\x02\x12\x03G\x06\x08\
-    \n3\n\x04\x05\0\x02#\x12\x03H\x02\t\"&\x20|CMD\x20SEARCH\x20firefox/DOWN\
-    LOAD\x20chrome|\n\n\x0c\n\x05\x05\0\x02#\x01\x12\x03H\x02\x03\n\x0c\n\
-    \x05\x05\0\x02#\x02\x12\x03H\x06\x08\n9\n\x04\x05\0\x02$\x12\x03I\x02\t\
+    \x03K\x02\x03\n\x0c\n\x05\x05\0\x02\x20\x02\x12\x03K\x06\x08\n\x1c\n\x04\
+    \x05\0\x02!\x12\x03L\x02\t\"\x0f\x20|CMD\x20History|\n\n\x0c\n\x05\x05\0\
+    \x02!\x01\x12\x03L\x02\x03\n\x0c\n\x05\x05\0\x02!\x02\x12\x03L\x06\x08\n\
+    \x16\n\x04\x05\0\x02\"\x12\x03M\x02\t\"\t\x20italics\n\n\x0c\n\x05\x05\0\
+    \x02\"\x01\x12\x03M\x02\x03\n\x0c\n\x05\x05\0\x02\"\x02\x12\x03M\x06\x08\
+    \n3\n\x04\x05\0\x02#\x12\x03N\x02\t\"&\x20|CMD\x20SEARCH\x20firefox/DOWN\
+    LOAD\x20chrome|\n\n\x0c\n\x05\x05\0\x02#\x01\x12\x03N\x02\x03\n\x0c\n\
+    \x05\x05\0\x02#\x02\x12\x03N\x06\x08\n9\n\x04\x05\0\x02$\x12\x03O\x02\t\
     \",\x20Used\x20for\x20ctrl+K\x20(delete\x20text\x20after\x20cursor)\n\n\
-    \x0c\n\x05\x05\0\x02$\x01\x12\x03I\x02\x03\n\x0c\n\x05\x05\0\x02$\x02\
-    \x12\x03I\x06\x08\n\x0b\n\x04\x05\0\x02%\x12\x03J\x02\t\n\x0c\n\x05\x05\
-    \0\x02%\x01\x12\x03J\x02\x03\n\x0c\n\x05\x05\0\x02%\x02\x12\x03J\x06\x08\
-    \n\x0b\n\x04\x05\0\x02&\x12\x03K\x02\t\n\x0c\n\x05\x05\0\x02&\x01\x12\
-    \x03K\x02\x03\n\x0c\n\x05\x05\0\x02&\x02\x12\x03K\x06\x08\n\x0b\n\x04\
-    \x05\0\x02'\x12\x03L\x02\t\n\x0c\n\x05\x05\0\x02'\x01\x12\x03L\x02\x03\n\
-    \x0c\n\x05\x05\0\x02'\x02\x12\x03L\x06\x08\n\x19\n\x04\x05\0\x02(\x12\
-    \x03M\x02\t\"\x0c\x20|CMD\x20OPEN|\n\n\x0c\n\x05\x05\0\x02(\x01\x12\x03M\
-    \x02\x03\n\x0c\n\x05\x05\0\x02(\x02\x12\x03M\x06\x08\n\x1a\n\x04\x05\0\
-    \x02)\x12\x03N\x02\t\"\r\x20|CMD\x20PRINT|\n\n\x0c\n\x05\x05\0\x02)\x01\
-    \x12\x03N\x02\x03\n\x0c\n\x05\x05\0\x02)\x02\x12\x03N\x06\x08\n\x0b\n\
-    \x04\x05\0\x02*\x12\x03O\x02\t\n\x0c\n\x05\x05\0\x02*\x01\x12\x03O\x02\
-    \x03\n\x0c\n\x05\x05\0\x02*\x02\x12\x03O\x06\x08\n\x1c\n\x04\x05\0\x02+\
-    \x12\x03P\x02\t\"\x0f\x20|CMD\x20REFRESH|\n\n\x0c\n\x05\x05\0\x02+\x01\
-    \x12\x03P\x02\x03\n\x0c\n\x05\x05\0\x02+\x02\x12\x03P\x06\x08\n\x19\n\
-    \x04\x05\0\x02,\x12\x03Q\x02\t\"\x0c\x20|CMD\x20SAVE|\n\n\x0c\n\x05\x05\
-    \0\x02,\x01\x12\x03Q\x02\x03\n\x0c\n\x05\x05\0\x02,\x02\x12\x03Q\x06\x08\
-    \n\x18\n\x04\x05\0\x02-\x12\x03R\x02\t\"\x0b\x20|CMD\x20TAB|\n\n\x0c\n\
-    \x05\x05\0\x02-\x01\x12\x03R\x02\x03\n\x0c\n\x05\x05\0\x02-\x02\x12\x03R\
-    \x06\x08\n:\n\x04\x05\0\x02.\x12\x03S\x02\t\"-\x20Used\x20for\x20ctrl+U\
+    \x0c\n\x05\x05\0\x02$\x01\x12\x03O\x02\x03\n\x0c\n\x05\x05\0\x02$\x02\
+    \x12\x03O\x06\x08\n\x0b\n\x04\x05\0\x02%\x12\x03P\x02\t\n\x0c\n\x05\x05\
+    \0\x02%\x01\x12\x03P\x02\x03\n\x0c\n\x05\x05\0\x02%\x02\x12\x03P\x06\x08\
+    \n\x0b\n\x04\x05\0\x02&\x12\x03Q\x02\t\n\x0c\n\x05\x05\0\x02&\x01\x12\
+    \x03Q\x02\x03\n\x0c\n\x05\x05\0\x02&\x02\x12\x03Q\x06\x08\n\x0b\n\x04\
+    \x05\0\x02'\x12\x03R\x02\t\n\x0c\n\x05\x05\0\x02'\x01\x12\x03R\x02\x03\n\
+    \x0c\n\x05\x05\0\x02'\x02\x12\x03R\x06\x08\n\x19\n\x04\x05\0\x02(\x12\
+    \x03S\x02\t\"\x0c\x20|CMD\x20OPEN|\n\n\x0c\n\x05\x05\0\x02(\x01\x12\x03S\
+    \x02\x03\n\x0c\n\x05\x05\0\x02(\x02\x12\x03S\x06\x08\n\x1a\n\x04\x05\0\
+    \x02)\x12\x03T\x02\t\"\r\x20|CMD\x20PRINT|\n\n\x0c\n\x05\x05\0\x02)\x01\
+    \x12\x03T\x02\x03\n\x0c\n\x05\x05\0\x02)\x02\x12\x03T\x06\x08\n\x0b\n\
+    \x04\x05\0\x02*\x12\x03U\x02\t\n\x0c\n\x05\x05\0\x02*\x01\x12\x03U\x02\
+    \x03\n\x0c\n\x05\x05\0\x02*\x02\x12\x03U\x06\x08\n\x1c\n\x04\x05\0\x02+\
+    \x12\x03V\x02\t\"\x0f\x20|CMD\x20REFRESH|\n\n\x0c\n\x05\x05\0\x02+\x01\
+    \x12\x03V\x02\x03\n\x0c\n\x05\x05\0\x02+\x02\x12\x03V\x06\x08\n\x19\n\
+    \x04\x05\0\x02,\x12\x03W\x02\t\"\x0c\x20|CMD\x20SAVE|\n\n\x0c\n\x05\x05\
+    \0\x02,\x01\x12\x03W\x02\x03\n\x0c\n\x05\x05\0\x02,\x02\x12\x03W\x06\x08\
+    \n\x18\n\x04\x05\0\x02-\x12\x03X\x02\t\"\x0b\x20|CMD\x20TAB|\n\n\x0c\n\
+    \x05\x05\0\x02-\x01\x12\x03X\x02\x03\n\x0c\n\x05\x05\0\x02-\x02\x12\x03X\
+    \x06\x08\n:\n\x04\x05\0\x02.\x12\x03Y\x02\t\"-\x20Used\x20for\x20ctrl+U\
     \x20(delete\x20text\x20before\x20cursor)\n\n\x0c\n\x05\x05\0\x02.\x01\
-    \x12\x03S\x02\x03\n\x0c\n\x05\x05\0\x02.\x02\x12\x03S\x06\x08\n\x1a\n\
-    \x04\x05\0\x02/\x12\x03T\x02\t\"\r\x20|CMD\x20PASTE|\n\n\x0c\n\x05\x05\0\
-    \x02/\x01\x12\x03T\x02\x03\n\x0c\n\x05\x05\0\x02/\x02\x12\x03T\x06\x08\n\
-    5\n\x04\x05\0\x020\x12\x03U\x02\t\"(\x20Used\x20for\x20ctrl+W\x20(delete\
-    \x20previous\x20word)\n\n\x0c\n\x05\x05\0\x020\x01\x12\x03U\x02\x03\n\
-    \x0c\n\x05\x05\0\x020\x02\x12\x03U\x06\x08\n\x18\n\x04\x05\0\x021\x12\
-    \x03V\x02\t\"\x0b\x20|CMD\x20CUT|\n\n\x0c\n\x05\x05\0\x021\x01\x12\x03V\
-    \x02\x03\n\x0c\n\x05\x05\0\x021\x02\x12\x03V\x06\x08\n\x0b\n\x04\x05\0\
-    \x022\x12\x03W\x02\t\n\x0c\n\x05\x05\0\x022\x01\x12\x03W\x02\x03\n\x0c\n\
-    \x05\x05\0\x022\x02\x12\x03W\x06\x08\n\x19\n\x04\x05\0\x023\x12\x03X\x02\
-    \t\"\x0c\x20|CMD\x20UNDO|\n\n\x0c\n\x05\x05\0\x023\x01\x12\x03X\x02\x03\
-    \n\x0c\n\x05\x05\0\x023\x02\x12\x03X\x06\x08\n!\n\x04\x05\0\x024\x12\x03\
-    [\x02\n\x1a\x14\x20The\x20function\x20keys:\n\n\x0c\n\x05\x05\0\x024\x01\
-    \x12\x03[\x02\x04\n\x0c\n\x05\x05\0\x024\x02\x12\x03[\x07\t\n\x0b\n\x04\
-    \x05\0\x025\x12\x03\\\x02\n\n\x0c\n\x05\x05\0\x025\x01\x12\x03\\\x02\x04\
-    \n\x0c\n\x05\x05\0\x025\x02\x12\x03\\\x07\t\n\x0b\n\x04\x05\0\x026\x12\
-    \x03]\x02\n\n\x0c\n\x05\x05\0\x026\x01\x12\x03]\x02\x04\n\x0c\n\x05\x05\
-    \0\x026\x02\x12\x03]\x07\t\n\x0b\n\x04\x05\0\x027\x12\x03^\x02\n\n\x0c\n\
-    \x05\x05\0\x027\x01\x12\x03^\x02\x04\n\x0c\n\x05\x05\0\x027\x02\x12\x03^\
-    \x07\t\n\x1c\n\x04\x05\0\x028\x12\x03_\x02\n\"\x0f\x20|CMD\x20REFRESH|\n\
-    \n\x0c\n\x05\x05\0\x028\x01\x12\x03_\x02\x04\n\x0c\n\x05\x05\0\x028\x02\
-    \x12\x03_\x07\t\n\x0b\n\x04\x05\0\x029\x12\x03`\x02\n\n\x0c\n\x05\x05\0\
-    \x029\x01\x12\x03`\x02\x04\n\x0c\n\x05\x05\0\x029\x02\x12\x03`\x07\t\n\
-    \x0b\n\x04\x05\0\x02:\x12\x03a\x02\n\n\x0c\n\x05\x05\0\x02:\x01\x12\x03a\
-    \x02\x04\n\x0c\n\x05\x05\0\x02:\x02\x12\x03a\x07\t\n\x0b\n\x04\x05\0\x02\
-    ;\x12\x03b\x02\n\n\x0c\n\x05\x05\0\x02;\x01\x12\x03b\x02\x04\n\x0c\n\x05\
-    \x05\0\x02;\x02\x12\x03b\x07\t\n\x0b\n\x04\x05\0\x02<\x12\x03c\x02\n\n\
-    \x0c\n\x05\x05\0\x02<\x01\x12\x03c\x02\x04\n\x0c\n\x05\x05\0\x02<\x02\
-    \x12\x03c\x07\t\n\x0b\n\x04\x05\0\x02=\x12\x03d\x02\x0b\n\x0c\n\x05\x05\
-    \0\x02=\x01\x12\x03d\x02\x05\n\x0c\n\x05\x05\0\x02=\x02\x12\x03d\x08\n\n\
-    \x0b\n\x04\x05\0\x02>\x12\x03e\x02\x0b\n\x0c\n\x05\x05\0\x02>\x01\x12\
-    \x03e\x02\x05\n\x0c\n\x05\x05\0\x02>\x02\x12\x03e\x08\n\n\x0b\n\x04\x05\
-    \0\x02?\x12\x03f\x02\x0b\n\x0c\n\x05\x05\0\x02?\x01\x12\x03f\x02\x05\n\
-    \x0c\n\x05\x05\0\x02?\x02\x12\x03f\x08\n\n\x0b\n\x04\x05\0\x02@\x12\x03g\
-    \x02\x0b\n\x0c\n\x05\x05\0\x02@\x01\x12\x03g\x02\x05\n\x0c\n\x05\x05\0\
-    \x02@\x02\x12\x03g\x08\n\n\x0b\n\x04\x05\0\x02A\x12\x03h\x02\x0b\n\x0c\n\
-    \x05\x05\0\x02A\x01\x12\x03h\x02\x05\n\x0c\n\x05\x05\0\x02A\x02\x12\x03h\
-    \x08\n\n\x0b\n\x04\x05\0\x02B\x12\x03i\x02\x0b\n\x0c\n\x05\x05\0\x02B\
-    \x01\x12\x03i\x02\x05\n\x0c\n\x05\x05\0\x02B\x02\x12\x03i\x08\n\n\x0b\n\
-    \x04\x05\0\x02C\x12\x03j\x02\x0b\n\x0c\n\x05\x05\0\x02C\x01\x12\x03j\x02\
-    \x05\n\x0c\n\x05\x05\0\x02C\x02\x12\x03j\x08\n\n\x0b\n\x04\x05\0\x02D\
-    \x12\x03k\x02\x0b\n\x0c\n\x05\x05\0\x02D\x01\x12\x03k\x02\x05\n\x0c\n\
-    \x05\x05\0\x02D\x02\x12\x03k\x08\n\n\x0b\n\x04\x05\0\x02E\x12\x03l\x02\
-    \x0b\n\x0c\n\x05\x05\0\x02E\x01\x12\x03l\x02\x05\n\x0c\n\x05\x05\0\x02E\
-    \x02\x12\x03l\x08\n\n\x0b\n\x04\x05\0\x02F\x12\x03m\x02\x0b\n\x0c\n\x05\
-    \x05\0\x02F\x01\x12\x03m\x02\x05\n\x0c\n\x05\x05\0\x02F\x02\x12\x03m\x08\
-    \n\n\x0b\n\x04\x05\0\x02G\x12\x03n\x02\x0b\n\x0c\n\x05\x05\0\x02G\x01\
-    \x12\x03n\x02\x05\n\x0c\n\x05\x05\0\x02G\x02\x12\x03n\x08\n\n\n\n\x02\
-    \x04\x03\x12\x04q\0u\x01\n\n\n\x03\x04\x03\x01\x12\x03q\x08\x0b\n\x0b\n\
-    \x04\x04\x03\x02\0\x12\x03r\x02\x12\n\x0c\n\x05\x04\x03\x02\0\x06\x12\
-    \x03r\x02\t\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03r\n\r\n\x0c\n\x05\x04\
-    \x03\x02\0\x03\x12\x03r\x10\x11\n\x0b\n\x04\x04\x03\x02\x01\x12\x03s\x02\
-    \x13\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03s\x02\x06\n\x0c\n\x05\x04\
-    \x03\x02\x01\x01\x12\x03s\x07\x0e\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\
-    \x03s\x11\x12\n\x0b\n\x04\x04\x03\x02\x02\x12\x03t\x02\x1a\n\x0c\n\x05\
-    \x04\x03\x02\x02\x06\x12\x03t\x02\x0b\n\x0c\n\x05\x04\x03\x02\x02\x01\
-    \x12\x03t\x0c\x15\n\x0c\n\x05\x04\x03\x02\x02\x03\x12\x03t\x18\x19\n\n\n\
-    \x02\x05\x01\x12\x04w\0~\x01\n\n\n\x03\x05\x01\x01\x12\x03w\x05\x0f\n\
-    \x0b\n\x04\x05\x01\x02\0\x12\x03x\x02\x0e\n\x0c\n\x05\x05\x01\x02\0\x01\
-    \x12\x03x\x02\t\n\x0c\n\x05\x05\x01\x02\0\x02\x12\x03x\x0c\r\n\x0b\n\x04\
-    \x05\x01\x02\x01\x12\x03y\x02\x0e\n\x0c\n\x05\x05\x01\x02\x01\x01\x12\
-    \x03y\x02\t\n\x0c\n\x05\x05\x01\x02\x01\x02\x12\x03y\x0c\r\n\x0b\n\x04\
-    \x05\x01\x02\x02\x12\x03z\x02\x10\n\x0c\n\x05\x05\x01\x02\x02\x01\x12\
-    \x03z\x02\x0b\n\x0c\n\x05\x05\x01\x02\x02\x02\x12\x03z\x0e\x0f\n\x0b\n\
-    \x04\x05\x01\x02\x03\x12\x03{\x02\r\n\x0c\n\x05\x05\x01\x02\x03\x01\x12\
-    \x03{\x02\x08\n\x0c\n\x05\x05\x01\x02\x03\x02\x12\x03{\x0b\x0c\n\x0b\n\
-    \x04\x05\x01\x02\x04\x12\x03|\x02\r\n\x0c\n\x05\x05\x01\x02\x04\x01\x12\
-    \x03|\x02\x08\n\x0c\n\x05\x05\x01\x02\x04\x02\x12\x03|\x0b\x0c\n\x0b\n\
-    \x04\x05\x01\x02\x05\x12\x03}\x02\r\n\x0c\n\x05\x05\x01\x02\x05\x01\x12\
-    \x03}\x02\x08\n\x0c\n\x05\x05\x01\x02\x05\x02\x12\x03}\x0b\x0c\n\x0c\n\
-    \x02\x04\x04\x12\x06\x80\x01\0\x85\x01\x01\n\x0b\n\x03\x04\x04\x01\x12\
-    \x04\x80\x01\x08\x15\n\x0c\n\x04\x04\x04\x02\0\x12\x04\x81\x01\x02\x0f\n\
-    \r\n\x05\x04\x04\x02\0\x06\x12\x04\x81\x01\x02\x06\n\r\n\x05\x04\x04\x02\
-    \0\x01\x12\x04\x81\x01\x07\n\n\r\n\x05\x04\x04\x02\0\x03\x12\x04\x81\x01\
-    \r\x0e\n\x0c\n\x04\x04\x04\x02\x01\x12\x04\x82\x01\x02\x18\n\r\n\x05\x04\
-    \x04\x02\x01\x06\x12\x04\x82\x01\x02\x0c\n\r\n\x05\x04\x04\x02\x01\x01\
-    \x12\x04\x82\x01\r\x13\n\r\n\x05\x04\x04\x02\x01\x03\x12\x04\x82\x01\x16\
-    \x17\n\x0c\n\x04\x04\x04\x02\x02\x12\x04\x83\x01\x02\x13\n\r\n\x05\x04\
-    \x04\x02\x02\x05\x12\x04\x83\x01\x02\x06\n\r\n\x05\x04\x04\x02\x02\x01\
-    \x12\x04\x83\x01\x07\x0e\n\r\n\x05\x04\x04\x02\x02\x03\x12\x04\x83\x01\
-    \x11\x12\n\x0c\n\x04\x04\x04\x02\x03\x12\x04\x84\x01\x02\x1a\n\r\n\x05\
-    \x04\x04\x02\x03\x06\x12\x04\x84\x01\x02\x0b\n\r\n\x05\x04\x04\x02\x03\
-    \x01\x12\x04\x84\x01\x0c\x15\n\r\n\x05\x04\x04\x02\x03\x03\x12\x04\x84\
-    \x01\x18\x19\n\x0c\n\x02\x05\x02\x12\x06\x87\x01\0\x8d\x01\x01\n\x0b\n\
-    \x03\x05\x02\x01\x12\x04\x87\x01\x05\x0f\n\x0c\n\x04\x05\x02\x02\0\x12\
-    \x04\x88\x01\x02\x0e\n\r\n\x05\x05\x02\x02\0\x01\x12\x04\x88\x01\x02\t\n\
-    \r\n\x05\x05\x02\x02\0\x02\x12\x04\x88\x01\x0c\r\n\x0c\n\x04\x05\x02\x02\
-    \x01\x12\x04\x89\x01\x02\x0c\n\r\n\x05\x05\x02\x02\x01\x01\x12\x04\x89\
-    \x01\x02\x07\n\r\n\x05\x05\x02\x02\x01\x02\x12\x04\x89\x01\n\x0b\n\x0c\n\
-    \x04\x05\x02\x02\x02\x12\x04\x8a\x01\x02\x0b\n\r\n\x05\x05\x02\x02\x02\
-    \x01\x12\x04\x8a\x01\x02\x06\n\r\n\x05\x05\x02\x02\x02\x02\x12\x04\x8a\
-    \x01\t\n\n\x0c\n\x04\x05\x02\x02\x03\x12\x04\x8b\x01\x02\n\n\r\n\x05\x05\
-    \x02\x02\x03\x01\x12\x04\x8b\x01\x02\x05\n\r\n\x05\x05\x02\x02\x03\x02\
-    \x12\x04\x8b\x01\x08\t\n\x0c\n\x04\x05\x02\x02\x04\x12\x04\x8c\x01\x02\r\
-    \n\r\n\x05\x05\x02\x02\x04\x01\x12\x04\x8c\x01\x02\x08\n\r\n\x05\x05\x02\
-    \x02\x04\x02\x12\x04\x8c\x01\x0b\x0c\n\x0c\n\x02\x04\x05\x12\x06\x8f\x01\
-    \0\x95\x01\x01\n\x0b\n\x03\x04\x05\x01\x12\x04\x8f\x01\x08\r\n\x0c\n\x04\
-    \x04\x05\x02\0\x12\x04\x90\x01\x02\x17\n\r\n\x05\x04\x05\x02\0\x05\x12\
-    \x04\x90\x01\x02\x08\n\r\n\x05\x04\x05\x02\0\x01\x12\x04\x90\x01\t\x12\n\
-    \r\n\x05\x04\x05\x02\0\x03\x12\x04\x90\x01\x15\x16\n\x0c\n\x04\x04\x05\
-    \x02\x01\x12\x04\x91\x01\x02\x10\n\r\n\x05\x04\x05\x02\x01\x05\x12\x04\
-    \x91\x01\x02\x08\n\r\n\x05\x04\x05\x02\x01\x01\x12\x04\x91\x01\t\x0b\n\r\
-    \n\x05\x04\x05\x02\x01\x03\x12\x04\x91\x01\x0e\x0f\n\x0c\n\x04\x04\x05\
-    \x02\x02\x12\x04\x92\x01\x02\x17\n\r\n\x05\x04\x05\x02\x02\x06\x12\x04\
-    \x92\x01\x02\x0c\n\r\n\x05\x04\x05\x02\x02\x01\x12\x04\x92\x01\r\x12\n\r\
-    \n\x05\x04\x05\x02\x02\x03\x12\x04\x92\x01\x15\x16\n\x0c\n\x04\x04\x05\
-    \x02\x03\x12\x04\x93\x01\x02\x0f\n\r\n\x05\x04\x05\x02\x03\x06\x12\x04\
-    \x93\x01\x02\x06\n\r\n\x05\x04\x05\x02\x03\x01\x12\x04\x93\x01\x07\n\n\r\
-    \n\x05\x04\x05\x02\x03\x03\x12\x04\x93\x01\r\x0e\n\x0c\n\x04\x04\x05\x02\
-    \x04\x12\x04\x94\x01\x02\x12\n\r\n\x05\x04\x05\x02\x04\x05\x12\x04\x94\
-    \x01\x02\x07\n\r\n\x05\x04\x05\x02\x04\x01\x12\x04\x94\x01\x08\r\n\r\n\
-    \x05\x04\x05\x02\x04\x03\x12\x04\x94\x01\x10\x11\n\x0c\n\x02\x05\x03\x12\
-    \x06\x97\x01\0\xa6\x01\x01\n\x0b\n\x03\x05\x03\x01\x12\x04\x97\x01\x05\
-    \x0e\n\x0c\n\x04\x05\x03\x02\0\x12\x04\x98\x01\x02\x0e\n\r\n\x05\x05\x03\
-    \x02\0\x01\x12\x04\x98\x01\x02\t\n\r\n\x05\x05\x03\x02\0\x02\x12\x04\x98\
-    \x01\x0c\r\n\x0c\n\x04\x05\x03\x02\x01\x12\x04\x99\x01\x02\x0b\n\r\n\x05\
-    \x05\x03\x02\x01\x01\x12\x04\x99\x01\x02\x06\n\r\n\x05\x05\x03\x02\x01\
-    \x02\x12\x04\x99\x01\t\n\n\x0c\n\x04\x05\x03\x02\x02\x12\x04\x9a\x01\x02\
-    \n\n\r\n\x05\x05\x03\x02\x02\x01\x12\x04\x9a\x01\x02\x05\n\r\n\x05\x05\
-    \x03\x02\x02\x02\x12\x04\x9a\x01\x08\t\n\x0c\n\x04\x05\x03\x02\x03\x12\
-    \x04\x9b\x01\x02\x0c\n\r\n\x05\x05\x03\x02\x03\x01\x12\x04\x9b\x01\x02\
-    \x07\n\r\n\x05\x05\x03\x02\x03\x02\x12\x04\x9b\x01\n\x0b\n\x0c\n\x04\x05\
-    \x03\x02\x04\x12\x04\x9c\x01\x02\x0b\n\r\n\x05\x05\x03\x02\x04\x01\x12\
-    \x04\x9c\x01\x02\x06\n\r\n\x05\x05\x03\x02\x04\x02\x12\x04\x9c\x01\t\n\n\
-    \x0c\n\x04\x05\x03\x02\x05\x12\x04\x9d\x01\x02\n\n\r\n\x05\x05\x03\x02\
-    \x05\x01\x12\x04\x9d\x01\x02\x05\n\r\n\x05\x05\x03\x02\x05\x02\x12\x04\
-    \x9d\x01\x08\t\n\x0c\n\x04\x05\x03\x02\x06\x12\x04\x9e\x01\x02\x14\n\r\n\
-    \x05\x05\x03\x02\x06\x01\x12\x04\x9e\x01\x02\x0f\n\r\n\x05\x05\x03\x02\
-    \x06\x02\x12\x04\x9e\x01\x12\x13\n\x0c\n\x04\x05\x03\x02\x07\x12\x04\x9f\
-    \x01\x02\x15\n\r\n\x05\x05\x03\x02\x07\x01\x12\x04\x9f\x01\x02\x10\n\r\n\
-    \x05\x05\x03\x02\x07\x02\x12\x04\x9f\x01\x13\x14\n\x0c\n\x04\x05\x03\x02\
-    \x08\x12\x04\xa0\x01\x02\x13\n\r\n\x05\x05\x03\x02\x08\x01\x12\x04\xa0\
-    \x01\x02\x0e\n\r\n\x05\x05\x03\x02\x08\x02\x12\x04\xa0\x01\x11\x12\n\x0c\
-    \n\x04\x05\x03\x02\t\x12\x04\xa1\x01\x02\x0e\n\r\n\x05\x05\x03\x02\t\x01\
-    \x12\x04\xa1\x01\x02\x08\n\r\n\x05\x05\x03\x02\t\x02\x12\x04\xa1\x01\x0b\
-    \r\n\x0c\n\x04\x05\x03\x02\n\x12\x04\xa2\x01\x02\x0c\n\r\n\x05\x05\x03\
-    \x02\n\x01\x12\x04\xa2\x01\x02\x06\n\r\n\x05\x05\x03\x02\n\x02\x12\x04\
-    \xa2\x01\t\x0b\n\x0c\n\x04\x05\x03\x02\x0b\x12\x04\xa3\x01\x02\x19\n\r\n\
-    \x05\x05\x03\x02\x0b\x01\x12\x04\xa3\x01\x02\x13\n\r\n\x05\x05\x03\x02\
-    \x0b\x02\x12\x04\xa3\x01\x16\x18\n\x0c\n\x04\x05\x03\x02\x0c\x12\x04\xa4\
-    \x01\x02\x1a\n\r\n\x05\x05\x03\x02\x0c\x01\x12\x04\xa4\x01\x02\x14\n\r\n\
-    \x05\x05\x03\x02\x0c\x02\x12\x04\xa4\x01\x17\x19\n\x0c\n\x04\x05\x03\x02\
-    \r\x12\x04\xa5\x01\x02\r\n\r\n\x05\x05\x03\x02\r\x01\x12\x04\xa5\x01\x02\
-    \x07\n\r\n\x05\x05\x03\x02\r\x02\x12\x04\xa5\x01\n\x0c\n\x0c\n\x02\x04\
-    \x06\x12\x06\xa8\x01\0\xb7\x01\x01\n\x0b\n\x03\x04\x06\x01\x12\x04\xa8\
-    \x01\x08\r\n\x0c\n\x04\x04\x06\x02\0\x12\x04\xa9\x01\x02\x13\n\r\n\x05\
-    \x04\x06\x02\0\x06\x12\x04\xa9\x01\x02\x0b\n\r\n\x05\x04\x06\x02\0\x01\
-    \x12\x04\xa9\x01\x0c\x0e\n\r\n\x05\x04\x06\x02\0\x03\x12\x04\xa9\x01\x11\
-    \x12\n\x0c\n\x04\x04\x06\x02\x01\x12\x04\xaa\x01\x02\x10\n\r\n\x05\x04\
-    \x06\x02\x01\x05\x12\x04\xaa\x01\x02\x06\n\r\n\x05\x04\x06\x02\x01\x01\
-    \x12\x04\xaa\x01\x07\x0b\n\r\n\x05\x04\x06\x02\x01\x03\x12\x04\xaa\x01\
-    \x0e\x0f\n\x0c\n\x04\x04\x06\x02\x02\x12\x04\xab\x01\x02\x0f\n\r\n\x05\
-    \x04\x06\x02\x02\x05\x12\x04\xab\x01\x02\x06\n\r\n\x05\x04\x06\x02\x02\
-    \x01\x12\x04\xab\x01\x07\n\n\r\n\x05\x04\x06\x02\x02\x03\x12\x04\xab\x01\
-    \r\x0e\n\x0c\n\x04\x04\x06\x02\x03\x12\x04\xac\x01\x02\x13\n\r\n\x05\x04\
-    \x06\x02\x03\x05\x12\x04\xac\x01\x02\x08\n\r\n\x05\x04\x06\x02\x03\x01\
-    \x12\x04\xac\x01\t\x0e\n\r\n\x05\x04\x06\x02\x03\x03\x12\x04\xac\x01\x11\
-    \x12\n\x0c\n\x04\x04\x06\x02\x04\x12\x04\xad\x01\x02\x12\n\r\n\x05\x04\
-    \x06\x02\x04\x05\x12\x04\xad\x01\x02\x08\n\r\n\x05\x04\x06\x02\x04\x01\
-    \x12\x04\xad\x01\t\r\n\r\n\x05\x04\x06\x02\x04\x03\x12\x04\xad\x01\x10\
-    \x11\n\x0c\n\x04\x04\x06\x02\x05\x12\x04\xae\x01\x02\x0e\n\r\n\x05\x04\
-    \x06\x02\x05\x06\x12\x04\xae\x01\x02\x05\n\r\n\x05\x04\x06\x02\x05\x01\
-    \x12\x04\xae\x01\x06\t\n\r\n\x05\x04\x06\x02\x05\x03\x12\x04\xae\x01\x0c\
-    \r\n\x0c\n\x04\x04\x06\x02\x06\x12\x04\xaf\x01\x02\x19\n\r\n\x05\x04\x06\
-    \x02\x06\x06\x12\x04\xaf\x01\x02\x06\n\r\n\x05\x04\x06\x02\x06\x01\x12\
-    \x04\xaf\x01\x07\x14\n\r\n\x05\x04\x06\x02\x06\x03\x12\x04\xaf\x01\x17\
-    \x18\n\x0c\n\x04\x04\x06\x02\x07\x12\x04\xb0\x01\x02#\n\r\n\x05\x04\x06\
-    \x02\x07\x06\x12\x04\xb0\x01\x02\x0f\n\r\n\x05\x04\x06\x02\x07\x01\x12\
-    \x04\xb0\x01\x10\x1e\n\r\n\x05\x04\x06\x02\x07\x03\x12\x04\xb0\x01!\"\n\
-    \x0c\n\x04\x04\x06\x02\x08\x12\x04\xb1\x01\x02\x18\n\r\n\x05\x04\x06\x02\
-    \x08\x05\x12\x04\xb1\x01\x02\x06\n\r\n\x05\x04\x06\x02\x08\x01\x12\x04\
-    \xb1\x01\x07\x13\n\r\n\x05\x04\x06\x02\x08\x03\x12\x04\xb1\x01\x16\x17\n\
-    \x0c\n\x04\x04\x06\x02\t\x12\x04\xb2\x01\x02\x13\n\r\n\x05\x04\x06\x02\t\
-    \x06\x12\x04\xb2\x01\x02\x06\n\r\n\x05\x04\x06\x02\t\x01\x12\x04\xb2\x01\
-    \x07\r\n\r\n\x05\x04\x06\x02\t\x03\x12\x04\xb2\x01\x10\x12\n\x0c\n\x04\
-    \x04\x06\x02\n\x12\x04\xb3\x01\x02\x12\n\r\n\x05\x04\x06\x02\n\x05\x12\
-    \x04\xb3\x01\x02\x07\n\r\n\x05\x04\x06\x02\n\x01\x12\x04\xb3\x01\x08\x0c\
-    \n\r\n\x05\x04\x06\x02\n\x03\x12\x04\xb3\x01\x0f\x11\n\x0c\n\x04\x04\x06\
-    \x02\x0b\x12\x04\xb4\x01\x02\x1e\n\r\n\x05\x04\x06\x02\x0b\x05\x12\x04\
-    \xb4\x01\x02\x06\n\r\n\x05\x04\x06\x02\x0b\x01\x12\x04\xb4\x01\x07\x18\n\
-    \r\n\x05\x04\x06\x02\x0b\x03\x12\x04\xb4\x01\x1b\x1d\n\x0c\n\x04\x04\x06\
-    \x02\x0c\x12\x04\xb5\x01\x02!\n\r\n\x05\x04\x06\x02\x0c\x05\x12\x04\xb5\
-    \x01\x02\x08\n\r\n\x05\x04\x06\x02\x0c\x01\x12\x04\xb5\x01\t\x1b\n\r\n\
-    \x05\x04\x06\x02\x0c\x03\x12\x04\xb5\x01\x1e\x20\n\x0c\n\x04\x04\x06\x02\
-    \r\x12\x04\xb6\x01\x02\x13\n\r\n\x05\x04\x06\x02\r\x06\x12\x04\xb6\x01\
-    \x02\x07\n\r\n\x05\x04\x06\x02\r\x01\x12\x04\xb6\x01\x08\r\n\r\n\x05\x04\
-    \x06\x02\r\x03\x12\x04\xb6\x01\x10\x12\n\x0c\n\x02\x04\x07\x12\x06\xb9\
-    \x01\0\xc2\x01\x01\n\x0b\n\x03\x04\x07\x01\x12\x04\xb9\x01\x08\r\n\x0c\n\
-    \x04\x04\x07\x02\0\x12\x04\xba\x01\x02\x17\n\r\n\x05\x04\x07\x02\0\x06\
-    \x12\x04\xba\x01\x02\x06\n\r\n\x05\x04\x07\x02\0\x01\x12\x04\xba\x01\x07\
-    \x12\n\r\n\x05\x04\x07\x02\0\x03\x12\x04\xba\x01\x15\x16\n\x0c\n\x04\x04\
-    \x07\x02\x01\x12\x04\xbb\x01\x02\x1d\n\r\n\x05\x04\x07\x02\x01\x05\x12\
-    \x04\xbb\x01\x02\x07\n\r\n\x05\x04\x07\x02\x01\x01\x12\x04\xbb\x01\x08\
-    \x18\n\r\n\x05\x04\x07\x02\x01\x03\x12\x04\xbb\x01\x1b\x1c\n\x0c\n\x04\
-    \x04\x07\x02\x02\x12\x04\xbc\x01\x02\x1e\n\r\n\x05\x04\x07\x02\x02\x05\
-    \x12\x04\xbc\x01\x02\x08\n\r\n\x05\x04\x07\x02\x02\x01\x12\x04\xbc\x01\t\
-    \x19\n\r\n\x05\x04\x07\x02\x02\x03\x12\x04\xbc\x01\x1c\x1d\n\x0c\n\x04\
-    \x04\x07\x02\x03\x12\x04\xbd\x01\x02\x12\n\r\n\x05\x04\x07\x02\x03\x05\
-    \x12\x04\xbd\x01\x02\x08\n\r\n\x05\x04\x07\x02\x03\x01\x12\x04\xbd\x01\t\
-    \r\n\r\n\x05\x04\x07\x02\x03\x03\x12\x04\xbd\x01\x10\x11\n\x0c\n\x04\x04\
-    \x07\x02\x04\x12\x04\xbe\x01\x02\x19\n\r\n\x05\x04\x07\x02\x04\x05\x12\
-    \x04\xbe\x01\x02\x07\n\r\n\x05\x04\x07\x02\x04\x01\x12\x04\xbe\x01\x08\
-    \x14\n\r\n\x05\x04\x07\x02\x04\x03\x12\x04\xbe\x01\x17\x18\n\x0c\n\x04\
-    \x04\x07\x02\x05\x12\x04\xbf\x01\x02\x1d\n\r\n\x05\x04\x07\x02\x05\x04\
-    \x12\x04\xbf\x01\x02\n\n\r\n\x05\x04\x07\x02\x05\x06\x12\x04\xbf\x01\x0c\
-    \x11\n\r\n\x05\x04\x07\x02\x05\x01\x12\x04\xbf\x01\x12\x18\n\r\n\x05\x04\
-    \x07\x02\x05\x03\x12\x04\xbf\x01\x1b\x1c\n\x0c\n\x04\x04\x07\x02\x06\x12\
-    \x04\xc0\x01\x02\x15\n\r\n\x05\x04\x07\x02\x06\x05\x12\x04\xc0\x01\x02\
-    \x06\n\r\n\x05\x04\x07\x02\x06\x01\x12\x04\xc0\x01\x07\x10\n\r\n\x05\x04\
-    \x07\x02\x06\x03\x12\x04\xc0\x01\x13\x14\n\x0c\n\x04\x04\x07\x02\x07\x12\
-    \x04\xc1\x01\x02\x19\n\r\n\x05\x04\x07\x02\x07\x06\x12\x04\xc1\x01\x02\
-    \x0b\n\r\n\x05\x04\x07\x02\x07\x01\x12\x04\xc1\x01\x0c\x14\n\r\n\x05\x04\
-    \x07\x02\x07\x03\x12\x04\xc1\x01\x17\x18b\x06proto3\
+    \x12\x03Y\x02\x03\n\x0c\n\x05\x05\0\x02.\x02\x12\x03Y\x06\x08\n\x1a\n\
+    \x04\x05\0\x02/\x12\x03Z\x02\t\"\r\x20|CMD\x20PASTE|\n\n\x0c\n\x05\x05\0\
+    \x02/\x01\x12\x03Z\x02\x03\n\x0c\n\x05\x05\0\x02/\x02\x12\x03Z\x06\x08\n\
+    5\n\x04\x05\0\x020\x12\x03[\x02\t\"(\x20Used\x20for\x20ctrl+W\x20(delete\
+    \x20previous\x20word)\n\n\x0c\n\x05\x05\0\x020\x01\x12\x03[\x02\x03\n\
+    \x0c\n\x05\x05\0\x020\x02\x12\x03[\x06\x08\n\x18\n\x04\x05\0\x021\x12\
+    \x03\\\x02\t\"\x0b\x20|CMD\x20CUT|\n\n\x0c\n\x05\x05\0\x021\x01\x12\x03\
+    \\\x02\x03\n\x0c\n\x05\x05\0\x021\x02\x12\x03\\\x06\x08\n\x0b\n\x04\x05\
+    \0\x022\x12\x03]\x02\t\n\x0c\n\x05\x05\0\x022\x01\x12\x03]\x02\x03\n\x0c\
+    \n\x05\x05\0\x022\x02\x12\x03]\x06\x08\n\x19\n\x04\x05\0\x023\x12\x03^\
+    \x02\t\"\x0c\x20|CMD\x20UNDO|\n\n\x0c\n\x05\x05\0\x023\x01\x12\x03^\x02\
+    \x03\n\x0c\n\x05\x05\0\x023\x02\x12\x03^\x06\x08\n!\n\x04\x05\0\x024\x12\
+    \x03a\x02\n\x1a\x14\x20The\x20function\x20keys:\n\n\x0c\n\x05\x05\0\x024\
+    \x01\x12\x03a\x02\x04\n\x0c\n\x05\x05\0\x024\x02\x12\x03a\x07\t\n\x0b\n\
+    \x04\x05\0\x025\x12\x03b\x02\n\n\x0c\n\x05\x05\0\x025\x01\x12\x03b\x02\
+    \x04\n\x0c\n\x05\x05\0\x025\x02\x12\x03b\x07\t\n\x0b\n\x04\x05\0\x026\
+    \x12\x03c\x02\n\n\x0c\n\x05\x05\0\x026\x01\x12\x03c\x02\x04\n\x0c\n\x05\
+    \x05\0\x026\x02\x12\x03c\x07\t\n\x0b\n\x04\x05\0\x027\x12\x03d\x02\n\n\
+    \x0c\n\x05\x05\0\x027\x01\x12\x03d\x02\x04\n\x0c\n\x05\x05\0\x027\x02\
+    \x12\x03d\x07\t\n\x1c\n\x04\x05\0\x028\x12\x03e\x02\n\"\x0f\x20|CMD\x20R\
+    EFRESH|\n\n\x0c\n\x05\x05\0\x028\x01\x12\x03e\x02\x04\n\x0c\n\x05\x05\0\
+    \x028\x02\x12\x03e\x07\t\n\x0b\n\x04\x05\0\x029\x12\x03f\x02\n\n\x0c\n\
+    \x05\x05\0\x029\x01\x12\x03f\x02\x04\n\x0c\n\x05\x05\0\x029\x02\x12\x03f\
+    \x07\t\n\x0b\n\x04\x05\0\x02:\x12\x03g\x02\n\n\x0c\n\x05\x05\0\x02:\x01\
+    \x12\x03g\x02\x04\n\x0c\n\x05\x05\0\x02:\x02\x12\x03g\x07\t\n\x0b\n\x04\
+    \x05\0\x02;\x12\x03h\x02\n\n\x0c\n\x05\x05\0\x02;\x01\x12\x03h\x02\x04\n\
+    \x0c\n\x05\x05\0\x02;\x02\x12\x03h\x07\t\n\x0b\n\x04\x05\0\x02<\x12\x03i\
+    \x02\n\n\x0c\n\x05\x05\0\x02<\x01\x12\x03i\x02\x04\n\x0c\n\x05\x05\0\x02\
+    <\x02\x12\x03i\x07\t\n\x0b\n\x04\x05\0\x02=\x12\x03j\x02\x0b\n\x0c\n\x05\
+    \x05\0\x02=\x01\x12\x03j\x02\x05\n\x0c\n\x05\x05\0\x02=\x02\x12\x03j\x08\
+    \n\n\x0b\n\x04\x05\0\x02>\x12\x03k\x02\x0b\n\x0c\n\x05\x05\0\x02>\x01\
+    \x12\x03k\x02\x05\n\x0c\n\x05\x05\0\x02>\x02\x12\x03k\x08\n\n\x0b\n\x04\
+    \x05\0\x02?\x12\x03l\x02\x0b\n\x0c\n\x05\x05\0\x02?\x01\x12\x03l\x02\x05\
+    \n\x0c\n\x05\x05\0\x02?\x02\x12\x03l\x08\n\n\x0b\n\x04\x05\0\x02@\x12\
+    \x03m\x02\x0b\n\x0c\n\x05\x05\0\x02@\x01\x12\x03m\x02\x05\n\x0c\n\x05\
+    \x05\0\x02@\x02\x12\x03m\x08\n\n\x0b\n\x04\x05\0\x02A\x12\x03n\x02\x0b\n\
+    \x0c\n\x05\x05\0\x02A\x01\x12\x03n\x02\x05\n\x0c\n\x05\x05\0\x02A\x02\
+    \x12\x03n\x08\n\n\x0b\n\x04\x05\0\x02B\x12\x03o\x02\x0b\n\x0c\n\x05\x05\
+    \0\x02B\x01\x12\x03o\x02\x05\n\x0c\n\x05\x05\0\x02B\x02\x12\x03o\x08\n\n\
+    \x0b\n\x04\x05\0\x02C\x12\x03p\x02\x0b\n\x0c\n\x05\x05\0\x02C\x01\x12\
+    \x03p\x02\x05\n\x0c\n\x05\x05\0\x02C\x02\x12\x03p\x08\n\n\x0b\n\x04\x05\
+    \0\x02D\x12\x03q\x02\x0b\n\x0c\n\x05\x05\0\x02D\x01\x12\x03q\x02\x05\n\
+    \x0c\n\x05\x05\0\x02D\x02\x12\x03q\x08\n\n\x0b\n\x04\x05\0\x02E\x12\x03r\
+    \x02\x0b\n\x0c\n\x05\x05\0\x02E\x01\x12\x03r\x02\x05\n\x0c\n\x05\x05\0\
+    \x02E\x02\x12\x03r\x08\n\n\x0b\n\x04\x05\0\x02F\x12\x03s\x02\x0b\n\x0c\n\
+    \x05\x05\0\x02F\x01\x12\x03s\x02\x05\n\x0c\n\x05\x05\0\x02F\x02\x12\x03s\
+    \x08\n\n\x0b\n\x04\x05\0\x02G\x12\x03t\x02\x0b\n\x0c\n\x05\x05\0\x02G\
+    \x01\x12\x03t\x02\x05\n\x0c\n\x05\x05\0\x02G\x02\x12\x03t\x08\n\n!\n\x04\
+    \x05\0\x02H\x12\x03v\x02\r\"\x14\x20The\x20minus\x20key,\x20`-`\n\n\x0c\
+    \n\x05\x05\0\x02H\x01\x12\x03v\x02\x07\n\x0c\n\x05\x05\0\x02H\x02\x12\
+    \x03v\n\x0c\nD\n\x04\x05\0\x02I\x12\x03w\x02\x12\"7\x20The\x20key\x20wit\
+    h\x20`+`\x20and\x20`=`,\x20used\x20for\x20cmd+plus\x20(zoom\x20in)\n\n\
+    \x0c\n\x05\x05\0\x02I\x01\x12\x03w\x02\x0c\n\x0c\n\x05\x05\0\x02I\x02\
+    \x12\x03w\x0f\x11\n\x0b\n\x02\x04\x04\x12\x05z\0\x80\x01\x01\n\n\n\x03\
+    \x04\x04\x01\x12\x03z\x08\x0b\n\x0b\n\x04\x04\x04\x02\0\x12\x03{\x02\x12\
+    \n\x0c\n\x05\x04\x04\x02\0\x06\x12\x03{\x02\t\n\x0c\n\x05\x04\x04\x02\0\
+    \x01\x12\x03{\n\r\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03{\x10\x11\n\x0b\n\
+    \x04\x04\x04\x02\x01\x12\x03|\x02\x13\n\x0c\n\x05\x04\x04\x02\x01\x05\
+    \x12\x03|\x02\x06\n\x0c\n\x05\x04\x04\x02\x01\x01\x12\x03|\x07\x0e\n\x0c\
+    \n\x05\x04\x04\x02\x01\x03\x12\x03|\x11\x12\n\x0b\n\x04\x04\x04\x02\x02\
+    \x12\x03}\x02\x1a\n\x0c\n\x05\x04\x04\x02\x02\x06\x12\x03}\x02\x0b\n\x0c\
+    \n\x05\x04\x04\x02\x02\x01\x12\x03}\x0c\x15\n\x0c\n\x05\x04\x04\x02\x02\
+    \x03\x12\x03}\x18\x19\nP\n\x04\x04\x04\x02\x03\x12\x03\x7f\x02\x12\x1aC\
+    \x20a\x20pressed\x20event\x20generated\x20by\x20os\x20key\x20repeat\x20w\
+    hile\x20the\x20key\x20is\x20held.\n\n\x0c\n\x05\x04\x04\x02\x03\x05\x12\
+    \x03\x7f\x02\x06\n\x0c\n\x05\x04\x04\x02\x03\x01\x12\x03\x7f\x07\r\n\x0c\
+    \n\x05\x04\x04\x02\x03\x03\x12\x03\x7f\x10\x11\n\x0c\n\x02\x05\x01\x12\
+    \x06\x82\x01\0\x89\x01\x01\n\x0b\n\x03\x05\x01\x01\x12\x04\x82\x01\x05\
+    \x0f\n\x0c\n\x04\x05\x01\x02\0\x12\x04\x83\x01\x02\x0e\n\r\n\x05\x05\x01\
+    \x02\0\x01\x12\x04\x83\x01\x02\t\n\r\n\x05\x05\x01\x02\0\x02\x12\x04\x83\
+    \x01\x0c\r\n\x0c\n\x04\x05\x01\x02\x01\x12\x04\x84\x01\x02\x0e\n\r\n\x05\
+    \x05\x01\x02\x01\x01\x12\x04\x84\x01\x02\t\n\r\n\x05\x05\x01\x02\x01\x02\
+    \x12\x04\x84\x01\x0c\r\n\x0c\n\x04\x05\x01\x02\x02\x12\x04\x85\x01\x02\
+    \x10\n\r\n\x05\x05\x01\x02\x02\x01\x12\x04\x85\x01\x02\x0b\n\r\n\x05\x05\
+    \x01\x02\x02\x02\x12\x04\x85\x01\x0e\x0f\n\x0c\n\x04\x05\x01\x02\x03\x12\
+    \x04\x86\x01\x02\r\n\r\n\x05\x05\x01\x02\x03\x01\x12\x04\x86\x01\x02\x08\
+    \n\r\n\x05\x05\x01\x02\x03\x02\x12\x04\x86\x01\x0b\x0c\n\x0c\n\x04\x05\
+    \x01\x02\x04\x12\x04\x87\x01\x02\r\n\r\n\x05\x05\x01\x02\x04\x01\x12\x04\
+    \x87\x01\x02\x08\n\r\n\x05\x05\x01\x02\x04\x02\x12\x04\x87\x01\x0b\x0c\n\
+    \x0c\n\x04\x05\x01\x02\x05\x12\x04\x88\x01\x02\r\n\r\n\x05\x05\x01\x02\
+    \x05\x01\x12\x04\x88\x01\x02\x08\n\r\n\x05\x05\x01\x02\x05\x02\x12\x04\
+    \x88\x01\x0b\x0c\n\x0c\n\x02\x04\x05\x12\x06\x8b\x01\0\x94\x01\x01\n\x0b\
+    \n\x03\x04\x05\x01\x12\x04\x8b\x01\x08\x15\nG\n\x04\x04\x05\x02\0\x12\
+    \x04\x8d\x01\x02\x0f\x1a9\x20unset\x20to\x20press\x20or\x20release\x20at\
+    \x20the\x20last\x20pointer\x20position.\n\n\r\n\x05\x04\x05\x02\0\x06\
+    \x12\x04\x8d\x01\x02\x06\n\r\n\x05\x04\x05\x02\0\x01\x12\x04\x8d\x01\x07\
+    \n\n\r\n\x05\x04\x05\x02\0\x03\x12\x04\x8d\x01\r\x0e\n\x0c\n\x04\x04\x05\
+    \x02\x01\x12\x04\x8e\x01\x02\x18\n\r\n\x05\x04\x05\x02\x01\x06\x12\x04\
+    \x8e\x01\x02\x0c\n\r\n\x05\x04\x05\x02\x01\x01\x12\x04\x8e\x01\r\x13\n\r\
+    \n\x05\x04\x05\x02\x01\x03\x12\x04\x8e\x01\x16\x17\n\x0c\n\x04\x04\x05\
+    \x02\x02\x12\x04\x8f\x01\x02\x13\n\r\n\x05\x04\x05\x02\x02\x05\x12\x04\
+    \x8f\x01\x02\x06\n\r\n\x05\x04\x05\x02\x02\x01\x12\x04\x8f\x01\x07\x0e\n\
+    \r\n\x05\x04\x05\x02\x02\x03\x12\x04\x8f\x01\x11\x12\n\x0c\n\x04\x04\x05\
+    \x02\x03\x12\x04\x90\x01\x02\x1a\n\r\n\x05\x04\x05\x02\x03\x06\x12\x04\
+    \x90\x01\x02\x0b\n\r\n\x05\x04\x05\x02\x03\x01\x12\x04\x90\x01\x0c\x15\n\
+    \r\n\x05\x04\x05\x02\x03\x03\x12\x04\x90\x01\x18\x19\n\xa7\x01\n\x04\x04\
+    \x05\x02\x04\x12\x04\x93\x01\x02\x19\x1a\x98\x01\x20on\x20release,\x20th\
+    e\x20count\x20of\x20this\x20click\x20in\x20a\x20multi\x20click\x20as\x20\
+    detected\x20by\x20unity,\x20e.g.\x202\x20for\x20a\x20double\n\x20click,\
+    \x200\x20to\x20let\x20egui\x20count\x20clicks\x20from\x20the\x20frame\
+    \x20time.\n\n\r\n\x05\x04\x05\x02\x04\x05\x12\x04\x93\x01\x02\x08\n\r\n\
+    \x05\x04\x05\x02\x04\x01\x12\x04\x93\x01\t\x14\n\r\n\x05\x04\x05\x02\x04\
+    \x03\x12\x04\x93\x01\x17\x18\n\x0c\n\x02\x05\x02\x12\x06\x96\x01\0\x9c\
+    \x01\x01\n\x0b\n\x03\x05\x02\x01\x12\x04\x96\x01\x05\x0f\n\x0c\n\x04\x05\
+    \x02\x02\0\x12\x04\x97\x01\x02\x0e\n\r\n\x05\x05\x02\x02\0\x01\x12\x04\
+    \x97\x01\x02\t\n\r\n\x05\x05\x02\x02\0\x02\x12\x04\x97\x01\x0c\r\n\x0c\n\
+    \x04\x05\x02\x02\x01\x12\x04\x98\x01\x02\x0c\n\r\n\x05\x05\x02\x02\x01\
+    \x01\x12\x04\x98\x01\x02\x07\n\r\n\x05\x05\x02\x02\x01\x02\x12\x04\x98\
+    \x01\n\x0b\n\x0c\n\x04\x05\x02\x02\x02\x12\x04\x99\x01\x02\x0b\n\r\n\x05\
+    \x05\x02\x02\x02\x01\x12\x04\x99\x01\x02\x06\n\r\n\x05\x05\x02\x02\x02\
+    \x02\x12\x04\x99\x01\t\n\n\x0c\n\x04\x05\x02\x02\x03\x12\x04\x9a\x01\x02\
+    \n\n\r\n\x05\x05\x02\x02\x03\x01\x12\x04\x9a\x01\x02\x05\n\r\n\x05\x05\
+    \x02\x02\x03\x02\x12\x04\x9a\x01\x08\t\n\x0c\n\x04\x05\x02\x02\x04\x12\
+    \x04\x9b\x01\x02\r\n\r\n\x05\x05\x02\x02\x04\x01\x12\x04\x9b\x01\x02\x08\
+    \n\r\n\x05\x05\x02\x02\x04\x02\x12\x04\x9b\x01\x0b\x0c\n\x0c\n\x02\x04\
+    \x06\x12\x06\x9e\x01\0\xa5\x01\x01\n\x0b\n\x03\x04\x06\x01\x12\x04\x9e\
+    \x01\x08\r\n\x0c\n\x04\x04\x06\x02\0\x12\x04\x9f\x01\x02\x17\n\r\n\x05\
+    \x04\x06\x02\0\x05\x12\x04\x9f\x01\x02\x08\n\r\n\x05\x04\x06\x02\0\x01\
+    \x12\x04\x9f\x01\t\x12\n\r\n\x05\x04\x06\x02\0\x03\x12\x04\x9f\x01\x15\
+    \x16\n\x0c\n\x04\x04\x06\x02\x01\x12\x04\xa0\x01\x02\x10\n\r\n\x05\x04\
+    \x06\x02\x01\x05\x12\x04\xa0\x01\x02\x08\n\r\n\x05\x04\x06\x02\x01\x01\
+    \x12\x04\xa0\x01\t\x0b\n\r\n\x05\x04\x06\x02\x01\x03\x12\x04\xa0\x01\x0e\
+    \x0f\n\x0c\n\x04\x04\x06\x02\x02\x12\x04\xa1\x01\x02\x17\n\r\n\x05\x04\
+    \x06\x02\x02\x06\x12\x04\xa1\x01\x02\x0c\n\r\n\x05\x04\x06\x02\x02\x01\
+    \x12\x04\xa1\x01\r\x12\n\r\n\x05\x04\x06\x02\x02\x03\x12\x04\xa1\x01\x15\
+    \x16\n\x0c\n\x04\x04\x06\x02\x03\x12\x04\xa2\x01\x02\x0f\n\r\n\x05\x04\
+    \x06\x02\x03\x06\x12\x04\xa2\x01\x02\x06\n\r\n\x05\x04\x06\x02\x03\x01\
+    \x12\x04\xa2\x01\x07\n\n\r\n\x05\x04\x06\x02\x03\x03\x12\x04\xa2\x01\r\
+    \x0e\n=\n\x04\x04\x06\x02\x04\x12\x04\xa4\x01\x02\x12\x1a/\x20Pressure\
+    \x20in\x200..=1,\x20negative\x20if\x20not\x20available.\n\n\r\n\x05\x04\
+    \x06\x02\x04\x05\x12\x04\xa4\x01\x02\x07\n\r\n\x05\x04\x06\x02\x04\x01\
+    \x12\x04\xa4\x01\x08\r\n\r\n\x05\x04\x06\x02\x04\x03\x12\x04\xa4\x01\x10\
+    \x11\n\x0c\n\x02\x05\x03\x12\x06\xa7\x01\0\xc2\x01\x01\n\x0b\n\x03\x05\
+    \x03\x01\x12\x04\xa7\x01\x05\x0e\n\x0c\n\x04\x05\x03\x02\0\x12\x04\xa8\
+    \x01\x02\x0e\n\r\n\x05\x05\x03\x02\0\x01\x12\x04\xa8\x01\x02\t\n\r\n\x05\
+    \x05\x03\x02\0\x02\x12\x04\xa8\x01\x0c\r\n\x0c\n\x04\x05\x03\x02\x01\x12\
+    \x04\xa9\x01\x02\x0b\n\r\n\x05\x05\x03\x02\x01\x01\x12\x04\xa9\x01\x02\
+    \x06\n\r\n\x05\x05\x03\x02\x01\x02\x12\x04\xa9\x01\t\n\n\x0c\n\x04\x05\
+    \x03\x02\x02\x12\x04\xaa\x01\x02\n\n\r\n\x05\x05\x03\x02\x02\x01\x12\x04\
+    \xaa\x01\x02\x05\n\r\n\x05\x05\x03\x02\x02\x02\x12\x04\xaa\x01\x08\t\n\
+    \x0c\n\x04\x05\x03\x02\x03\x12\x04\xab\x01\x02\x0c\n\r\n\x05\x05\x03\x02\
+    \x03\x01\x12\x04\xab\x01\x02\x07\n\r\n\x05\x05\x03\x02\x03\x02\x12\x04\
+    \xab\x01\n\x0b\n\x0c\n\x04\x05\x03\x02\x04\x12\x04\xac\x01\x02\x0b\n\r\n\
+    \x05\x05\x03\x02\x04\x01\x12\x04\xac\x01\x02\x06\n\r\n\x05\x05\x03\x02\
+    \x04\x02\x12\x04\xac\x01\t\n\n\x0c\n\x04\x05\x03\x02\x05\x12\x04\xad\x01\
+    \x02\n\n\r\n\x05\x05\x03\x02\x05\x01\x12\x04\xad\x01\x02\x05\n\r\n\x05\
+    \x05\x03\x02\x05\x02\x12\x04\xad\x01\x08\t\n\x0c\n\x04\x05\x03\x02\x06\
+    \x12\x04\xae\x01\x02\x14\n\r\n\x05\x05\x03\x02\x06\x01\x12\x04\xae\x01\
+    \x02\x0f\n\r\n\x05\x05\x03\x02\x06\x02\x12\x04\xae\x01\x12\x13\n\x0c\n\
+    \x04\x05\x03\x02\x07\x12\x04\xaf\x01\x02\x15\n\r\n\x05\x05\x03\x02\x07\
+    \x01\x12\x04\xaf\x01\x02\x10\n\r\n\x05\x05\x03\x02\x07\x02\x12\x04\xaf\
+    \x01\x13\x14\nj\n\x04\x05\x03\x02\x08\x12\x04\xb1\x01\x02\x13\x1a\\\x20t\
+    he\x20pointer\x20left\x20the\x20surface,\x20e.g.\x20the\x20game\x20view,\
+    \x20send\x20it\x20so\x20hover\x20highlights\x20are\x20cleared.\n\n\r\n\
+    \x05\x05\x03\x02\x08\x01\x12\x04\xb1\x01\x02\x0e\n\r\n\x05\x05\x03\x02\
+    \x08\x02\x12\x04\xb1\x01\x11\x12\n\x0c\n\x04\x05\x03\x02\t\x12\x04\xb2\
+    \x01\x02\x0e\n\r\n\x05\x05\x03\x02\t\x01\x12\x04\xb2\x01\x02\x08\n\r\n\
+    \x05\x05\x03\x02\t\x02\x12\x04\xb2\x01\x0b\r\n\x0c\n\x04\x05\x03\x02\n\
+    \x12\x04\xb3\x01\x02\x0c\n\r\n\x05\x05\x03\x02\n\x01\x12\x04\xb3\x01\x02\
+    \x06\n\r\n\x05\x05\x03\x02\n\x02\x12\x04\xb3\x01\t\x0b\n\x0c\n\x04\x05\
+    \x03\x02\x0b\x12\x04\xb4\x01\x02\x19\n\r\n\x05\x05\x03\x02\x0b\x01\x12\
+    \x04\xb4\x01\x02\x13\n\r\n\x05\x05\x03\x02\x0b\x02\x12\x04\xb4\x01\x16\
+    \x18\n\x0c\n\x04\x05\x03\x02\x0c\x12\x04\xb5\x01\x02\x1a\n\r\n\x05\x05\
+    \x03\x02\x0c\x01\x12\x04\xb5\x01\x02\x14\n\r\n\x05\x05\x03\x02\x0c\x02\
+    \x12\x04\xb5\x01\x17\x19\n\x0c\n\x04\x05\x03\x02\r\x12\x04\xb6\x01\x02\r\
+    \n\r\n\x05\x05\x03\x02\r\x01\x12\x04\xb6\x01\x02\x07\n\r\n\x05\x05\x03\
+    \x02\r\x02\x12\x04\xb6\x01\n\x0c\n\x0c\n\x04\x05\x03\x02\x0e\x12\x04\xb7\
+    \x01\x02\x13\n\r\n\x05\x05\x03\x02\x0e\x01\x12\x04\xb7\x01\x02\r\n\r\n\
+    \x05\x05\x03\x02\x0e\x02\x12\x04\xb7\x01\x10\x12\n\x0c\n\x04\x05\x03\x02\
+    \x0f\x12\x04\xb8\x01\x02\x17\n\r\n\x05\x05\x03\x02\x0f\x01\x12\x04\xb8\
+    \x01\x02\x11\n\r\n\x05\x05\x03\x02\x0f\x02\x12\x04\xb8\x01\x14\x16\n\x86\
+    \x01\n\x04\x05\x03\x02\x10\x12\x04\xbb\x01\x02\x0c\x1ax\x20the\x20androi\
+    d\x20back\x20button,\x20an\x20escape\x20tap\x20to\x20egui,\x20apps\x20cl\
+    ose\x20with\x20`Frame::close`\x20on\x20escape\n\x20if\x20nothing\x20else\
+    \x20consumed\x20it.\n\n\r\n\x05\x05\x03\x02\x10\x01\x12\x04\xbb\x01\x02\
+    \x06\n\r\n\x05\x05\x03\x02\x10\x02\x12\x04\xbb\x01\t\x0b\n\xbc\x01\n\x04\
+    \x05\x03\x02\x11\x12\x04\xbe\x01\x02\r\x1a\xad\x01\x20the\x20window\x20g\
+    ained\x20or\x20lost\x20focus,\x20overrides\x20Input.has_focus\x20of\x20t\
+    his\x20input,\x20held\x20keys,\x20modifiers\n\x20and\x20pointer\x20butto\
+    ns\x20are\x20released\x20on\x20loss\x20so\x20they\x20don't\x20stick\x20a\
+    fter\x20alt-tab.\n\n\r\n\x05\x05\x03\x02\x11\x01\x12\x04\xbe\x01\x02\x07\
+    \n\r\n\x05\x05\x03\x02\x11\x02\x12\x04\xbe\x01\n\x0c\n\xb8\x01\n\x04\x05\
+    \x03\x02\x12\x12\x04\xc1\x01\x02\x17\x1a\xa9\x01\x20the\x20pointer\x20en\
+    tered\x20the\x20surface,\x20sent\x20to\x20egui\x20as\x20a\x20move\x20to\
+    \x20pointer_entered\x20so\x20hover\x20state\x20is\n\x20updated\x20before\
+    \x20the\x20next\x20move,\x20the\x20last\x20pointer\x20position\x20is\x20\
+    used\x20if\x20unset.\n\n\r\n\x05\x05\x03\x02\x12\x01\x12\x04\xc1\x01\x02\
+    \x11\n\r\n\x05\x05\x03\x02\x12\x02\x12\x04\xc1\x01\x14\x16\n\x0c\n\x02\
+    \x05\x04\x12\x06\xc4\x01\0\xc8\x01\x01\n\x0b\n\x03\x05\x04\x01\x12\x04\
+    \xc4\x01\x05\x0f\n\x0c\n\x04\x05\x04\x02\0\x12\x04\xc5\x01\x02\x0f\n\r\n\
+    \x05\x05\x04\x02\0\x01\x12\x04\xc5\x01\x02\n\n\r\n\x05\x05\x04\x02\0\x02\
+    \x12\x04\xc5\x01\r\x0e\n\x0c\n\x04\x05\x04\x02\x01\x12\x04\xc6\x01\x02\
+    \x0e\n\r\n\x05\x05\x04\x02\x01\x01\x12\x04\xc6\x01\x02\t\n\r\n\x05\x05\
+    \x04\x02\x01\x02\x12\x04\xc6\x01\x0c\r\n\x0c\n\x04\x05\x04\x02\x02\x12\
+    \x04\xc7\x01\x02\x0e\n\r\n\x05\x05\x04\x02\x02\x01\x12\x04\xc7\x01\x02\t\
+    \n\r\n\x05\x05\x04\x02\x02\x02\x12\x04\xc7\x01\x0c\r\n\xb2\x01\n\x02\x04\
+    \x07\x12\x06\xcc\x01\0\xd1\x01\x01\x1a\xa3\x01\x20a\x20wheel\x20or\x20tr\
+    ackpad\x20scroll\x20with\x20the\x20unit\x20of\x20delta\x20as\x20reported\
+    \x20by\x20the\x20os,\x20converted\x20to\x20zoom\x20if\n\x20ctrl\x20or\
+    \x20command\x20is\x20held\x20and\x20to\x20horizontal\x20scroll\x20if\x20\
+    shift\x20is\x20held.\n\n\x0b\n\x03\x04\x07\x01\x12\x04\xcc\x01\x08\x12\n\
+    \x0c\n\x04\x04\x07\x02\0\x12\x04\xcd\x01\x02\x16\n\r\n\x05\x04\x07\x02\0\
+    \x06\x12\x04\xcd\x01\x02\x0c\n\r\n\x05\x04\x07\x02\0\x01\x12\x04\xcd\x01\
+    \r\x11\n\r\n\x05\x04\x07\x02\0\x03\x12\x04\xcd\x01\x14\x15\n5\n\x04\x04\
+    \x07\x02\x01\x12\x04\xcf\x01\x02\x11\x1a'\x20same\x20sign\x20convention\
+    \x20as\x20Event.scroll.\n\n\r\n\x05\x04\x07\x02\x01\x06\x12\x04\xcf\x01\
+    \x02\x06\n\r\n\x05\x04\x07\x02\x01\x01\x12\x04\xcf\x01\x07\x0c\n\r\n\x05\
+    \x04\x07\x02\x01\x03\x12\x04\xcf\x01\x0f\x10\n\x0c\n\x04\x04\x07\x02\x02\
+    \x12\x04\xd0\x01\x02\x1a\n\r\n\x05\x04\x07\x02\x02\x06\x12\x04\xd0\x01\
+    \x02\x0b\n\r\n\x05\x04\x07\x02\x02\x01\x12\x04\xd0\x01\x0c\x15\n\r\n\x05\
+    \x04\x07\x02\x02\x03\x12\x04\xd0\x01\x18\x19\n\x0c\n\x02\x04\x08\x12\x06\
+    \xd3\x01\0\xe9\x01\x01\n\x0b\n\x03\x04\x08\x01\x12\x04\xd3\x01\x08\r\n\
+    \x0c\n\x04\x04\x08\x02\0\x12\x04\xd4\x01\x02\x13\n\r\n\x05\x04\x08\x02\0\
+    \x06\x12\x04\xd4\x01\x02\x0b\n\r\n\x05\x04\x08\x02\0\x01\x12\x04\xd4\x01\
+    \x0c\x0e\n\r\n\x05\x04\x08\x02\0\x03\x12\x04\xd4\x01\x11\x12\n\x0c\n\x04\
+    \x04\x08\x02\x01\x12\x04\xd5\x01\x02\x10\n\r\n\x05\x04\x08\x02\x01\x05\
+    \x12\x04\xd5\x01\x02\x06\n\r\n\x05\x04\x08\x02\x01\x01\x12\x04\xd5\x01\
+    \x07\x0b\n\r\n\x05\x04\x08\x02\x01\x03\x12\x04\xd5\x01\x0e\x0f\n\x0c\n\
+    \x04\x04\x08\x02\x02\x12\x04\xd6\x01\x02\x0f\n\r\n\x05\x04\x08\x02\x02\
+    \x05\x12\x04\xd6\x01\x02\x06\n\r\n\x05\x04\x08\x02\x02\x01\x12\x04\xd6\
+    \x01\x07\n\n\r\n\x05\x04\x08\x02\x02\x03\x12\x04\xd6\x01\r\x0e\n\x0c\n\
+    \x04\x04\x08\x02\x03\x12\x04\xd7\x01\x02\x13\n\r\n\x05\x04\x08\x02\x03\
+    \x05\x12\x04\xd7\x01\x02\x08\n\r\n\x05\x04\x08\x02\x03\x01\x12\x04\xd7\
+    \x01\t\x0e\n\r\n\x05\x04\x08\x02\x03\x03\x12\x04\xd7\x01\x11\x12\n\x0c\n\
+    \x04\x04\x08\x02\x04\x12\x04\xd8\x01\x02\x12\n\r\n\x05\x04\x08\x02\x04\
+    \x05\x12\x04\xd8\x01\x02\x08\n\r\n\x05\x04\x08\x02\x04\x01\x12\x04\xd8\
+    \x01\t\r\n\r\n\x05\x04\x08\x02\x04\x03\x12\x04\xd8\x01\x10\x11\n\x0c\n\
+    \x04\x04\x08\x02\x05\x12\x04\xd9\x01\x02\x0e\n\r\n\x05\x04\x08\x02\x05\
+    \x06\x12\x04\xd9\x01\x02\x05\n\r\n\x05\x04\x08\x02\x05\x01\x12\x04\xd9\
+    \x01\x06\t\n\r\n\x05\x04\x08\x02\x05\x03\x12\x04\xd9\x01\x0c\r\n\x0c\n\
+    \x04\x04\x08\x02\x06\x12\x04\xda\x01\x02\x19\n\r\n\x05\x04\x08\x02\x06\
+    \x06\x12\x04\xda\x01\x02\x06\n\r\n\x05\x04\x08\x02\x06\x01\x12\x04\xda\
+    \x01\x07\x14\n\r\n\x05\x04\x08\x02\x06\x03\x12\x04\xda\x01\x17\x18\n\x0c\
+    \n\x04\x04\x08\x02\x07\x12\x04\xdb\x01\x02#\n\r\n\x05\x04\x08\x02\x07\
+    \x06\x12\x04\xdb\x01\x02\x0f\n\r\n\x05\x04\x08\x02\x07\x01\x12\x04\xdb\
+    \x01\x10\x1e\n\r\n\x05\x04\x08\x02\x07\x03\x12\x04\xdb\x01!\"\n\x0c\n\
+    \x04\x04\x08\x02\x08\x12\x04\xdc\x01\x02\x18\n\r\n\x05\x04\x08\x02\x08\
+    \x05\x12\x04\xdc\x01\x02\x06\n\r\n\x05\x04\x08\x02\x08\x01\x12\x04\xdc\
+    \x01\x07\x13\n\r\n\x05\x04\x08\x02\x08\x03\x12\x04\xdc\x01\x16\x17\n\xad\
+    \x01\n\x04\x04\x08\x02\t\x12\x04\xdf\x01\x02\x13\x1a\x9e\x01\x20how\x20t\
+    o\x20move\x20the\x20content,\x20positive\x20x\x20and\x20y\x20move\x20it\
+    \x20right\x20and\x20down\x20to\x20reveal\x20what\x20is\x20left\x20and\n\
+    \x20above,\x20e.g.\x20rolling\x20the\x20wheel\x20up\x20gives\x20a\x20pos\
+    itive\x20y,\x20like\x20egui.\n\n\r\n\x05\x04\x08\x02\t\x06\x12\x04\xdf\
+    \x01\x02\x06\n\r\n\x05\x04\x08\x02\t\x01\x12\x04\xdf\x01\x07\r\n\r\n\x05\
+    \x04\x08\x02\t\x03\x12\x04\xdf\x01\x10\x12\n\x0c\n\x04\x04\x08\x02\n\x12\
+    \x04\xe0\x01\x02\x12\n\r\n\x05\x04\x08\x02\n\x05\x12\x04\xe0\x01\x02\x07\
+    \n\r\n\x05\x04\x08\x02\n\x01\x12\x04\xe0\x01\x08\x0c\n\r\n\x05\x04\x08\
+    \x02\n\x03\x12\x04\xe0\x01\x0f\x11\n\x0c\n\x04\x04\x08\x02\x0b\x12\x04\
+    \xe1\x01\x02\x1e\n\r\n\x05\x04\x08\x02\x0b\x05\x12\x04\xe1\x01\x02\x06\n\
+    \r\n\x05\x04\x08\x02\x0b\x01\x12\x04\xe1\x01\x07\x18\n\r\n\x05\x04\x08\
+    \x02\x0b\x03\x12\x04\xe1\x01\x1b\x1d\n\x0c\n\x04\x04\x08\x02\x0c\x12\x04\
+    \xe2\x01\x02!\n\r\n\x05\x04\x08\x02\x0c\x05\x12\x04\xe2\x01\x02\x08\n\r\
+    \n\x05\x04\x08\x02\x0c\x01\x12\x04\xe2\x01\t\x1b\n\r\n\x05\x04\x08\x02\
+    \x0c\x03\x12\x04\xe2\x01\x1e\x20\n\x0c\n\x04\x04\x08\x02\r\x12\x04\xe3\
+    \x01\x02\x13\n\r\n\x05\x04\x08\x02\r\x06\x12\x04\xe3\x01\x02\x07\n\r\n\
+    \x05\x04\x08\x02\r\x01\x12\x04\xe3\x01\x08\r\n\r\n\x05\x04\x08\x02\r\x03\
+    \x12\x04\xe3\x01\x10\x12\n\x0c\n\x04\x04\x08\x02\x0e\x12\x04\xe4\x01\x02\
+    \x1e\n\r\n\x05\x04\x08\x02\x0e\x06\x12\x04\xe4\x01\x02\x0c\n\r\n\x05\x04\
+    \x08\x02\x0e\x01\x12\x04\xe4\x01\r\x18\n\r\n\x05\x04\x08\x02\x0e\x03\x12\
+    \x04\xe4\x01\x1b\x1d\n>\n\x04\x04\x08\x02\x0f\x12\x04\xe6\x01\x02\x1e\
+    \x1a0\x20the\x20committed\x20text\x20which\x20replaces\x20the\x20preedit\
+    .\n\n\r\n\x05\x04\x08\x02\x0f\x05\x12\x04\xe6\x01\x02\x08\n\r\n\x05\x04\
+    \x08\x02\x0f\x01\x12\x04\xe6\x01\t\x18\n\r\n\x05\x04\x08\x02\x0f\x03\x12\
+    \x04\xe6\x01\x1b\x1d\n\x0c\n\x04\x04\x08\x02\x10\x12\x04\xe7\x01\x02\x14\
+    \n\r\n\x05\x04\x08\x02\x10\x05\x12\x04\xe7\x01\x02\x06\n\r\n\x05\x04\x08\
+    \x02\x10\x01\x12\x04\xe7\x01\x07\x0e\n\r\n\x05\x04\x08\x02\x10\x03\x12\
+    \x04\xe7\x01\x11\x13\n\x0c\n\x04\x04\x08\x02\x11\x12\x04\xe8\x01\x02\x1c\
+    \n\r\n\x05\x04\x08\x02\x11\x06\x12\x04\xe8\x01\x02\x06\n\r\n\x05\x04\x08\
+    \x02\x11\x01\x12\x04\xe8\x01\x07\x16\n\r\n\x05\x04\x08\x02\x11\x03\x12\
+    \x04\xe8\x01\x19\x1b\nf\n\x02\x04\t\x12\x06\xec\x01\0\xef\x01\x01\x1aX\
+    \x20a\x20file\x20dragged\x20over\x20the\x20window,\x20path\x20on\x20desk\
+    top,\x20mime\x20on\x20webgl,\x20empty\x20if\x20not\x20provided.\n\n\x0b\
+    \n\x03\x04\t\x01\x12\x04\xec\x01\x08\x13\n\x0c\n\x04\x04\t\x02\0\x12\x04\
+    \xed\x01\x02\x12\n\r\n\x05\x04\t\x02\0\x05\x12\x04\xed\x01\x02\x08\n\r\n\
+    \x05\x04\t\x02\0\x01\x12\x04\xed\x01\t\r\n\r\n\x05\x04\t\x02\0\x03\x12\
+    \x04\xed\x01\x10\x11\n\x0c\n\x04\x04\t\x02\x01\x12\x04\xee\x01\x02\x12\n\
+    \r\n\x05\x04\t\x02\x01\x05\x12\x04\xee\x01\x02\x08\n\r\n\x05\x04\t\x02\
+    \x01\x01\x12\x04\xee\x01\t\r\n\r\n\x05\x04\t\x02\x01\x03\x12\x04\xee\x01\
+    \x10\x11\nn\n\x02\x04\n\x12\x06\xf2\x01\0\xf6\x01\x01\x1a`\x20a\x20file\
+    \x20dropped\x20on\x20the\x20window,\x20path\x20on\x20desktop,\x20name\
+    \x20and\x20bytes\x20on\x20webgl,\x20empty\x20if\x20not\x20provided.\n\n\
+    \x0b\n\x03\x04\n\x01\x12\x04\xf2\x01\x08\x13\n\x0c\n\x04\x04\n\x02\0\x12\
+    \x04\xf3\x01\x02\x12\n\r\n\x05\x04\n\x02\0\x05\x12\x04\xf3\x01\x02\x08\n\
+    \r\n\x05\x04\n\x02\0\x01\x12\x04\xf3\x01\t\r\n\r\n\x05\x04\n\x02\0\x03\
+    \x12\x04\xf3\x01\x10\x11\n\x0c\n\x04\x04\n\x02\x01\x12\x04\xf4\x01\x02\
+    \x12\n\r\n\x05\x04\n\x02\x01\x05\x12\x04\xf4\x01\x02\x08\n\r\n\x05\x04\n\
+    \x02\x01\x01\x12\x04\xf4\x01\t\r\n\r\n\x05\x04\n\x02\x01\x03\x12\x04\xf4\
+    \x01\x10\x11\n\x0c\n\x04\x04\n\x02\x02\x12\x04\xf5\x01\x02\x12\n\r\n\x05\
+    \x04\n\x02\x02\x05\x12\x04\xf5\x01\x02\x07\n\r\n\x05\x04\n\x02\x02\x01\
+    \x12\x04\xf5\x01\x08\r\n\r\n\x05\x04\n\x02\x02\x03\x12\x04\xf5\x01\x10\
+    \x11\n\x0c\n\x02\x04\x0b\x12\x06\xf8\x01\0\x89\x02\x01\n\x0b\n\x03\x04\
+    \x0b\x01\x12\x04\xf8\x01\x08\r\n\x0c\n\x04\x04\x0b\x02\0\x12\x04\xf9\x01\
+    \x02\x17\n\r\n\x05\x04\x0b\x02\0\x06\x12\x04\xf9\x01\x02\x06\n\r\n\x05\
+    \x04\x0b\x02\0\x01\x12\x04\xf9\x01\x07\x12\n\r\n\x05\x04\x0b\x02\0\x03\
+    \x12\x04\xf9\x01\x15\x16\n\x0c\n\x04\x04\x0b\x02\x01\x12\x04\xfa\x01\x02\
+    \x1d\n\r\n\x05\x04\x0b\x02\x01\x05\x12\x04\xfa\x01\x02\x07\n\r\n\x05\x04\
+    \x0b\x02\x01\x01\x12\x04\xfa\x01\x08\x18\n\r\n\x05\x04\x0b\x02\x01\x03\
+    \x12\x04\xfa\x01\x1b\x1c\n\x0c\n\x04\x04\x0b\x02\x02\x12\x04\xfb\x01\x02\
+    \x1e\n\r\n\x05\x04\x0b\x02\x02\x05\x12\x04\xfb\x01\x02\x08\n\r\n\x05\x04\
+    \x0b\x02\x02\x01\x12\x04\xfb\x01\t\x19\n\r\n\x05\x04\x0b\x02\x02\x03\x12\
+    \x04\xfb\x01\x1c\x1d\n\x86\x02\n\x04\x04\x0b\x02\x03\x12\x04\xff\x01\x02\
+    \x1b\x1a\xf7\x01\x20seconds\x20since\x20the\x20app\x20started,\x200\x20i\
+    ncluded,\x20egui\x20advances\x20the\x20last\x20time\x20by\x20predicted_d\
+    t\x20if\x20not\n\x20set.\x20If\x20it\x20goes\x20backwards,\x20e.g.\x20af\
+    ter\x20a\x20scene\x20reload,\x20the\x20frame\x20comes\x20predicted_dt\
+    \x20after\x20the\n\x20last\x20one\x20and\x20later\x20times\x20are\x20shi\
+    fted\x20by\x20the\x20same\x20offset.\n\n\r\n\x05\x04\x0b\x02\x03\x04\x12\
+    \x04\xff\x01\x02\n\n\r\n\x05\x04\x0b\x02\x03\x05\x12\x04\xff\x01\x0b\x11\
+    \n\r\n\x05\x04\x0b\x02\x03\x01\x12\x04\xff\x01\x12\x16\n\r\n\x05\x04\x0b\
+    \x02\x03\x03\x12\x04\xff\x01\x19\x1a\n\x0c\n\x04\x04\x0b\x02\x04\x12\x04\
+    \x80\x02\x02\x19\n\r\n\x05\x04\x0b\x02\x04\x05\x12\x04\x80\x02\x02\x07\n\
+    \r\n\x05\x04\x0b\x02\x04\x01\x12\x04\x80\x02\x08\x14\n\r\n\x05\x04\x0b\
+    \x02\x04\x03\x12\x04\x80\x02\x17\x18\n\x0c\n\x04\x04\x0b\x02\x05\x12\x04\
+    \x81\x02\x02\x1d\n\r\n\x05\x04\x0b\x02\x05\x04\x12\x04\x81\x02\x02\n\n\r\
+    \n\x05\x04\x0b\x02\x05\x06\x12\x04\x81\x02\x0c\x11\n\r\n\x05\x04\x0b\x02\
+    \x05\x01\x12\x04\x81\x02\x12\x18\n\r\n\x05\x04\x0b\x02\x05\x03\x12\x04\
+    \x81\x02\x1b\x1c\n\x0c\n\x04\x04\x0b\x02\x06\x12\x04\x82\x02\x02\x15\n\r\
+    \n\x05\x04\x0b\x02\x06\x05\x12\x04\x82\x02\x02\x06\n\r\n\x05\x04\x0b\x02\
+    \x06\x01\x12\x04\x82\x02\x07\x10\n\r\n\x05\x04\x0b\x02\x06\x03\x12\x04\
+    \x82\x02\x13\x14\n\x0c\n\x04\x04\x0b\x02\x07\x12\x04\x83\x02\x02\x19\n\r\
+    \n\x05\x04\x0b\x02\x07\x06\x12\x04\x83\x02\x02\x0b\n\r\n\x05\x04\x0b\x02\
+    \x07\x01\x12\x04\x83\x02\x0c\x14\n\r\n\x05\x04\x0b\x02\x07\x03\x12\x04\
+    \x83\x02\x17\x18\n\x8d\x01\n\x04\x04\x0b\x02\x08\x12\x04\x86\x02\x02\x16\
+    \x1a\x7f\x20display\x20scale\x20of\x20the\x20os,\x20multiplied\x20with\
+    \x20pixels_per_point\x20which\x20is\x20the\x20content\x20scale(zoom)\n\
+    \x20of\x20the\x20app,\x200\x20means\x20not\x20provided.\n\n\r\n\x05\x04\
+    \x0b\x02\x08\x05\x12\x04\x86\x02\x02\x07\n\r\n\x05\x04\x0b\x02\x08\x01\
+    \x12\x04\x86\x02\x08\x11\n\r\n\x05\x04\x0b\x02\x08\x03\x12\x04\x86\x02\
+    \x14\x15\n\x0c\n\x04\x04\x0b\x02\t\x12\x04\x87\x02\x02*\n\r\n\x05\x04\
+    \x0b\x02\t\x04\x12\x04\x87\x02\x02\n\n\r\n\x05\x04\x0b\x02\t\x06\x12\x04\
+    \x87\x02\x0b\x16\n\r\n\x05\x04\x0b\x02\t\x01\x12\x04\x87\x02\x17$\n\r\n\
+    \x05\x04\x0b\x02\t\x03\x12\x04\x87\x02')\n\x0c\n\x04\x04\x0b\x02\n\x12\
+    \x04\x88\x02\x02*\n\r\n\x05\x04\x0b\x02\n\x04\x12\x04\x88\x02\x02\n\n\r\
+    \n\x05\x04\x0b\x02\n\x06\x12\x04\x88\x02\x0b\x16\n\r\n\x05\x04\x0b\x02\n\
+    \x01\x12\x04\x88\x02\x17$\n\r\n\x05\x04\x0b\x02\n\x03\x12\x04\x88\x02')b\
+    \x06proto3\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -2746,20 +3775,25 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
     file_descriptor.get(|| {
         let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
             let mut deps = ::std::vec::Vec::with_capacity(0);
-            let mut messages = ::std::vec::Vec::with_capacity(8);
+            let mut messages = ::std::vec::Vec::with_capacity(12);
             messages.push(Pos2::generated_message_descriptor_data());
+            messages.push(Vec2::generated_message_descriptor_data());
             messages.push(Rect::generated_message_descriptor_data());
             messages.push(Modifiers::generated_message_descriptor_data());
             messages.push(Key::generated_message_descriptor_data());
             messages.push(PointerButton::generated_message_descriptor_data());
             messages.push(Touch::generated_message_descriptor_data());
+            messages.push(MouseWheel::generated_message_descriptor_data());
             messages.push(Event::generated_message_descriptor_data());
+            messages.push(HoveredFile::generated_message_descriptor_data());
+            messages.push(DroppedFile::generated_message_descriptor_data());
             messages.push(Input::generated_message_descriptor_data());
-            let mut enums = ::std::vec::Vec::with_capacity(4);
+            let mut enums = ::std::vec::Vec::with_capacity(5);
             enums.push(KeyType::generated_enum_descriptor_data());
             enums.push(ButtonType::generated_enum_descriptor_data());
             enums.push(TouchPhase::generated_enum_descriptor_data());
             enums.push(EventType::generated_enum_descriptor_data());
+            enums.push(ScrollUnit::generated_enum_descriptor_data());
             ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
                 file_descriptor_proto(),
                 deps,
@@ -1,3 +1,4 @@
 // @generated
 
+#[allow(renamed_and_removed_lints)]
 pub mod input;
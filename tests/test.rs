@@ -0,0 +1,3373 @@
+use egui::{Event, Key, Modifiers, PointerButton, RawInput};
+use protobuf::{EnumOrUnknown, Message};
+use uegui::input::{
+    effective_pixels_per_point, event_from_pb_to_native, input_from_pb_to_native, key_from_pb_code,
+    parse_input_bytes, remove_duplicate_text, MultiClick,
+};
+use uegui::mesh::{repack_vertices, reverse_winding, split_mesh};
+use uegui::proto::input as pb;
+use uegui::{
+    cursor_icon_to_u32, log_level_to_unity, output_event_to_u32, panic_message, texture_id_to_u64,
+    u32_to_level_filter, u64_to_texture_id, widget_type_to_u32, App, Buffer, ButtonMap,
+    DebugWindow, Frame, FrameStats, InputOptions, InputTransform, KeyTextConvention, PointerState,
+    TextureFormat, UeguiError, UnityCallback, UnityContext, UnityContextBuilder, UnityInitializer,
+    UnityVertex, VertexColorFormat, ABI_VERSION, SAFE_MODE_FAILURES, WANTS_IME,
+    WANTS_KEYBOARD_INPUT, WANTS_POINTER_INPUT,
+};
+
+/// Id and header of every texture in a `set_textures_batch` call.
+type TextureBatch = Vec<(u64, [u32; 6])>;
+
+/// Event, widget type, label and selected state of a `widget_event` call.
+type WidgetEvent = (u32, u32, Option<String>, u32);
+
+thread_local! {
+    /// Name of every painting callback in the order unity got them.
+    static PAINT_CALLS: std::cell::RefCell<Vec<&'static str>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+thread_local! {
+    static TEXTURES: std::cell::RefCell<Vec<u64>> = const { std::cell::RefCell::new(Vec::new()) };
+    static BATCHES: std::cell::RefCell<Vec<TextureBatch>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+thread_local! {
+    /// Sum of alpha of the last texture passed to `set_texture`.
+    static TEXTURE_ALPHA: std::cell::Cell<u64> = const { std::cell::Cell::new(0) };
+    /// Format of every texture passed to `set_texture`.
+    static TEXTURE_FORMATS: std::cell::RefCell<Vec<u32>> = const { std::cell::RefCell::new(Vec::new()) };
+    /// Offset and size of every texture passed to `set_texture`.
+    static TEXTURE_RECTS: std::cell::RefCell<Vec<[u32; 4]>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+extern "system" fn set_texture(
+    id: u64,
+    offset_x: u32,
+    offset_y: u32,
+    width: u32,
+    height: u32,
+    _: u32,
+    _: u32,
+    format: u32,
+    data: *const u8,
+) {
+    PAINT_CALLS.with(|c| c.borrow_mut().push("set_texture"));
+    TEXTURE_FORMATS.with(|f| f.borrow_mut().push(format));
+    TEXTURES.with(|t| t.borrow_mut().push(id));
+    TEXTURE_RECTS.with(|r| r.borrow_mut().push([offset_x, offset_y, width, height]));
+    let data = unsafe { std::slice::from_raw_parts(data, width as usize * height as usize * 4) };
+    let alpha = data.chunks(4).map(|p| p[3] as u64).sum();
+    TEXTURE_ALPHA.with(|a| a.set(alpha));
+}
+
+extern "system" fn set_textures_batch(data: *const u8, len: u32, count: u32) {
+    let mut data = unsafe { std::slice::from_raw_parts(data, len as usize) };
+    let mut batch = Vec::new();
+    for _ in 0..count {
+        let id = u64::from_ne_bytes(data[..8].try_into().unwrap());
+        let mut header = [0; 6];
+        for (i, v) in header.iter_mut().enumerate() {
+            *v = u32::from_ne_bytes(data[8 + i * 4..12 + i * 4].try_into().unwrap());
+        }
+        data = &data[32 + header[2] as usize * header[3] as usize * 4..];
+        batch.push((id, header));
+    }
+    assert!(data.is_empty());
+    BATCHES.with(|b| b.borrow_mut().push(batch));
+}
+
+thread_local! {
+    static REMOVED_TEXTURES: std::cell::RefCell<Vec<u64>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+extern "system" fn rem_texture(id: u64) {
+    REMOVED_TEXTURES.with(|r| r.borrow_mut().push(id));
+}
+
+extern "system" fn begin_paint() {
+    PAINT_CALLS.with(|c| c.borrow_mut().push("begin_paint"));
+}
+
+thread_local! {
+    static MESHES: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+thread_local! {
+    /// Union of clip rects and bounding rect of vertices of painted meshes.
+    static MESH_BOUNDS: std::cell::Cell<(egui::Rect, egui::Rect)> =
+        const { std::cell::Cell::new((egui::Rect::NOTHING, egui::Rect::NOTHING)) };
+}
+
+extern "system" fn paint_mesh(
+    _: u64,
+    vertex_count: u32,
+    vertices: *const u8,
+    _: u32,
+    _: *const u8,
+    min_x: f32,
+    min_y: f32,
+    max_x: f32,
+    max_y: f32,
+) {
+    PAINT_CALLS.with(|c| c.borrow_mut().push("paint_mesh"));
+    MESHES.with(|m| m.set(m.get() + 1));
+    let vertices = unsafe { std::slice::from_raw_parts(vertices, vertex_count as usize * 20) };
+    let mut bounds = egui::Rect::NOTHING;
+    for vertex in vertices.chunks(20) {
+        let x = f32::from_ne_bytes(vertex[..4].try_into().unwrap());
+        let y = f32::from_ne_bytes(vertex[4..8].try_into().unwrap());
+        bounds.extend_with(egui::pos2(x, y));
+    }
+    let clip = egui::Rect::from_min_max(egui::pos2(min_x, min_y), egui::pos2(max_x, max_y));
+    MESH_BOUNDS.with(|b| {
+        let (c, v) = b.get();
+        b.set((c.union(clip), v.union(bounds)));
+    });
+}
+
+extern "system" fn end_paint() {
+    PAINT_CALLS.with(|c| c.borrow_mut().push("end_paint"));
+}
+
+thread_local! {
+    static KEYBOARD: std::cell::RefCell<Vec<(u32, Option<String>)>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+extern "system" fn show_keyboard(show: u32, text: *const u8, len: u32) {
+    let text = (!text.is_null()).then(|| {
+        let text = unsafe { std::slice::from_raw_parts(text, len as usize) };
+        String::from_utf8(text.to_vec()).unwrap()
+    });
+    KEYBOARD.with(|k| k.borrow_mut().push((show, text)));
+}
+
+thread_local! {
+    static LOGS: std::cell::RefCell<Vec<(i32, String)>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+extern "system" fn show_log(level: i32, message: *const u8, len: i32) {
+    let message = unsafe { std::slice::from_raw_parts(message, len as usize) };
+    let message = String::from_utf8(message.to_vec()).unwrap();
+    LOGS.with(|l| l.borrow_mut().push((level, message)));
+}
+
+thread_local! {
+    static CALLBACKS: std::cell::RefCell<Vec<u64>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+extern "system" fn paint_callback(
+    handle: u64,
+    _: f32,
+    _: f32,
+    _: f32,
+    _: f32,
+    _: f32,
+    _: f32,
+    _: f32,
+    _: f32,
+) {
+    CALLBACKS.with(|c| c.borrow_mut().push(handle));
+}
+
+thread_local! {
+    static CURSORS: std::cell::RefCell<Vec<u32>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+extern "system" fn set_cursor(icon: u32) {
+    CURSORS.with(|c| c.borrow_mut().push(icon));
+}
+
+thread_local! {
+    static URLS: std::cell::RefCell<Vec<(u32, String)>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+extern "system" fn open_url(new_tab: u32, url: *const u8, len: u32) {
+    let url = unsafe { std::slice::from_raw_parts(url, len as usize) };
+    let url = String::from_utf8(url.to_vec()).unwrap();
+    URLS.with(|u| u.borrow_mut().push((new_tab, url)));
+}
+
+thread_local! {
+    static CLIPBOARD: std::cell::RefCell<Vec<String>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+extern "system" fn set_clipboard(text: *const u8, len: u32) {
+    let text = unsafe { std::slice::from_raw_parts(text, len as usize) };
+    let text = String::from_utf8(text.to_vec()).unwrap();
+    CLIPBOARD.with(|c| c.borrow_mut().push(text));
+}
+
+thread_local! {
+    static CLOSES: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+extern "system" fn request_close() {
+    CLOSES.with(|c| c.set(c.get() + 1));
+}
+
+thread_local! {
+    /// Texture id and index count of every mesh in each `paint_frame` call.
+    static FRAMES: std::cell::RefCell<Vec<Vec<(u64, u32)>>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+extern "system" fn paint_frame(data: *const u8, len: u32, count: u32) {
+    let data = unsafe { std::slice::from_raw_parts(data, len as usize) };
+    let u32_at = |offset: usize| u32::from_ne_bytes(data[offset..offset + 4].try_into().unwrap());
+    let mut meshes = Vec::new();
+    for entry in data.chunks(40).take(count as usize) {
+        let id = u64::from_ne_bytes(entry[..8].try_into().unwrap());
+        let offset = entry.as_ptr() as usize - data.as_ptr() as usize;
+        let [vertex_offset, vertex_count, index_offset, index_count] =
+            [24, 28, 32, 36].map(|field| u32_at(offset + field) as usize);
+        assert_eq!(index_offset, vertex_offset + vertex_count * 20);
+        for i in 0..index_count {
+            assert!((u32_at(index_offset + i * 4) as usize) < vertex_count);
+        }
+        meshes.push((id, index_count as u32));
+    }
+    FRAMES.with(|f| f.borrow_mut().push(meshes));
+}
+
+thread_local! {
+    /// Texture count and mesh count of every `capture_ready` call.
+    static CAPTURES: std::cell::RefCell<Vec<(u32, u32)>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+extern "system" fn capture_ready(
+    _: *const u8,
+    _: u32,
+    textures_count: u32,
+    _: *const u8,
+    _: u32,
+    meshes_count: u32,
+) {
+    CAPTURES.with(|c| c.borrow_mut().push((textures_count, meshes_count)));
+}
+
+thread_local! {
+    static IME_POSITIONS: std::cell::RefCell<Vec<(u32, egui::Pos2)>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+extern "system" fn set_ime_position(visible: u32, x: f32, y: f32) {
+    IME_POSITIONS.with(|p| p.borrow_mut().push((visible, egui::pos2(x, y))));
+}
+
+thread_local! {
+    static WIDGET_EVENTS: std::cell::RefCell<Vec<WidgetEvent>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+#[allow(clippy::too_many_arguments)]
+extern "system" fn widget_event(
+    event: u32,
+    widget_type: u32,
+    label: *const u8,
+    label_len: u32,
+    _: *const u8,
+    _: u32,
+    _: f64,
+    selected: u32,
+) {
+    let label = (!label.is_null()).then(|| {
+        let label = unsafe { std::slice::from_raw_parts(label, label_len as usize) };
+        String::from_utf8(label.to_vec()).unwrap()
+    });
+    WIDGET_EVENTS.with(|w| w.borrow_mut().push((event, widget_type, label, selected)));
+}
+
+thread_local! {
+    static STATS: std::cell::RefCell<Vec<FrameStats>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+extern "system" fn frame_stats(
+    mesh_count: u32,
+    vertex_count: u32,
+    index_count: u32,
+    texture_bytes: u64,
+    cpu_micros: u64,
+    tessellate_micros: u64,
+) {
+    STATS.with(|s| {
+        s.borrow_mut().push(FrameStats {
+            mesh_count,
+            vertex_count,
+            index_count,
+            texture_bytes,
+            cpu_micros,
+            tessellate_micros,
+        })
+    });
+}
+
+thread_local! {
+    static RENDER_TARGETS: std::cell::RefCell<Vec<u64>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+extern "system" fn set_render_target(target: u64) {
+    RENDER_TARGETS.with(|r| r.borrow_mut().push(target));
+}
+
+thread_local! {
+    static REPAINTS: std::cell::RefCell<Vec<u64>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+extern "system" fn request_repaint_after(millis: u64) {
+    REPAINTS.with(|r| r.borrow_mut().push(millis));
+}
+
+thread_local! {
+    static POINTERS: std::cell::RefCell<Vec<[u32; 3]>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+extern "system" fn pointer_consumed(over_area: u32, using_pointer: u32, wants_input: u32) {
+    POINTERS.with(|p| p.borrow_mut().push([over_area, using_pointer, wants_input]));
+}
+
+thread_local! {
+    static KEYBOARD_UTF16: std::cell::RefCell<Vec<(u32, String, u32)>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+extern "system" fn show_keyboard_utf16(show: u32, text: *const u8, len: u32, utf16_len: u32) {
+    let text = if text.is_null() {
+        String::new()
+    } else {
+        let text = unsafe { std::slice::from_raw_parts(text, len as usize) };
+        String::from_utf8(text.to_vec()).unwrap()
+    };
+    KEYBOARD_UTF16.with(|k| k.borrow_mut().push((show, text, utf16_len)));
+}
+
+fn initializer() -> UnityInitializer {
+    UnityInitializer {
+        set_texture: Some(set_texture),
+        rem_texture: Some(rem_texture),
+        begin_paint: Some(begin_paint),
+        paint_mesh: Some(paint_mesh),
+        end_paint: Some(end_paint),
+        show_keyboard: Some(show_keyboard),
+        show_log: Some(show_log),
+        paint_callback: Some(paint_callback),
+        set_cursor: Some(set_cursor),
+        open_url: Some(open_url),
+        set_clipboard: Some(set_clipboard),
+        set_textures_batch: Some(set_textures_batch),
+        request_close: Some(request_close),
+        paint_frame: None,
+        capture_ready: None,
+        set_ime_position: None,
+        widget_event: None,
+        frame_stats: None,
+        set_render_target: None,
+        request_repaint_after: None,
+        pointer_consumed: None,
+        show_keyboard_utf16: None,
+        update_accessibility: None,
+    }
+}
+
+fn key_pressed(keys: &[Key]) -> RawInput {
+    RawInput {
+        events: keys
+            .iter()
+            .map(|key| Event::Key {
+                key: *key,
+                pressed: true,
+                repeat: false,
+                modifiers: Modifiers::NONE,
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+/// A list inside a scroll area which handles arrow keys, focused if the flag is set, otherwise
+/// like a shortcut of the app.
+struct FocusedList(bool);
+
+impl App for FocusedList {
+    fn update(&mut self, ctx: &egui::Context) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                let response = ui.add(egui::Label::new("item").sense(egui::Sense::click()));
+                if self.0 {
+                    response.request_focus();
+                }
+                if response.has_focus() || !self.0 {
+                    ui.input_mut(|i| {
+                        i.consume_key(Modifiers::NONE, Key::ArrowUp);
+                        i.consume_key(Modifiers::NONE, Key::ArrowDown);
+                    });
+                }
+            });
+        });
+    }
+}
+
+#[test]
+fn arrow_keys_consumed_by_focused_scroll_area() {
+    let mut context = UnityContext::new(initializer(), |_| FocusedList(false));
+    context.run_frame(RawInput::default());
+    assert!(context.consumed_keys().is_empty());
+
+    context.run_frame(key_pressed(&[Key::ArrowDown, Key::A]));
+    assert_eq!(context.consumed_keys(), &[Key::ArrowDown]);
+    assert!(context.key_consumed(Key::ArrowDown));
+    assert!(!context.key_consumed(Key::A));
+
+    context.run_frame(RawInput::default());
+    assert!(context.consumed_keys().is_empty());
+
+    // a focused widget wants keyboard input, all keys are reported
+    let mut context = UnityContext::new(initializer(), |_| FocusedList(true));
+    context.run_frame(RawInput::default());
+    context.run_frame(key_pressed(&[Key::ArrowDown, Key::A]));
+    assert_eq!(context.consumed_keys(), &[Key::ArrowDown, Key::A]);
+}
+
+#[test]
+fn keys_typed_into_text_edit_consumed() {
+    let mut context = UnityContext::new(initializer(), |_| SingleLine::default());
+    context.run_frame(RawInput::default());
+    context.run_frame(RawInput::default());
+    context.run_frame(key_pressed(&[Key::ArrowLeft, Key::Backspace, Key::A]));
+    assert_eq!(
+        context.consumed_keys(),
+        &[Key::ArrowLeft, Key::Backspace, Key::A]
+    );
+
+    let mut context = UnityContext::new(initializer(), |_| Label);
+    context.run_frame(RawInput::default());
+    context.run_frame(key_pressed(&[Key::ArrowLeft, Key::Backspace]));
+    assert!(context.consumed_keys().is_empty());
+}
+
+#[test]
+fn swapped_button_map() {
+    let map = ButtonMap::default();
+    assert_eq!(map.get(PointerButton::Primary), PointerButton::Primary);
+    assert_eq!(map.get(PointerButton::Secondary), PointerButton::Secondary);
+
+    let map = ButtonMap::left_handed();
+    assert_eq!(map.get(PointerButton::Primary), PointerButton::Secondary);
+    assert_eq!(map.get(PointerButton::Secondary), PointerButton::Primary);
+    assert_eq!(map.get(PointerButton::Middle), PointerButton::Middle);
+
+    let mut map = ButtonMap::default();
+    map.set(PointerButton::Extra1, PointerButton::Middle);
+    assert_eq!(map.get(PointerButton::Extra1), PointerButton::Middle);
+}
+
+/// Uploads a 4x2 user texture on the first frame.
+#[derive(Default)]
+struct UserTexture {
+    texture: Option<egui::TextureHandle>,
+}
+
+impl App for UserTexture {
+    fn update(&mut self, ctx: &egui::Context) {
+        self.texture.get_or_insert_with(|| {
+            let image = egui::ColorImage::new([4, 2], egui::Color32::WHITE);
+            ctx.load_texture("user", image, Default::default())
+        });
+    }
+}
+
+#[test]
+fn texture_memory_matches_uploaded_textures() {
+    let mut context = UnityContext::new(initializer(), |_| UserTexture::default());
+    context.run_frame(RawInput::default());
+    let [width, height] = context.context().fonts(|f| f.font_image_size());
+    assert_eq!(context.texture_memory(), width * height * 4 + 4 * 2 * 4);
+}
+
+#[test]
+fn texture_deltas_batched() {
+    let mut context = UnityContext::new(initializer(), |_| UserTexture::default());
+    context.run_frame(RawInput::default());
+    let batches = BATCHES.with(|b| b.take());
+    assert_eq!(batches.len(), 1);
+    let [width, height] = context.context().fonts(|f| f.font_image_size());
+    let user = context.app().texture.as_ref().unwrap().id();
+    assert_eq!(
+        batches[0],
+        [
+            (texture_id_to_u64(user), [0, 0, 4, 2, 2, 2]),
+            (
+                texture_id_to_u64(egui::TextureId::default()),
+                [0, 0, width as u32, height as u32, 2, 2]
+            ),
+        ]
+    );
+    assert!(TEXTURES.with(|t| t.take()).is_empty());
+
+    context.app().texture.as_ref().unwrap().clone().set(
+        egui::ColorImage::new([1, 1], egui::Color32::RED),
+        Default::default(),
+    );
+    context.run_frame(RawInput::default());
+    assert!(BATCHES.with(|b| b.take()).is_empty());
+    assert_eq!(TEXTURES.with(|t| t.take()), [texture_id_to_u64(user)]);
+}
+
+fn pb_pos2(x: f32, y: f32) -> pb::Pos2 {
+    pb::Pos2 {
+        x,
+        y,
+        ..Default::default()
+    }
+}
+
+fn pb_vec2(x: f32, y: f32) -> pb::Vec2 {
+    pb::Vec2 {
+        x,
+        y,
+        ..Default::default()
+    }
+}
+
+fn pb_pointer_button(button: pb::ButtonType, pressed: bool) -> pb::Event {
+    let mut e = pb::Event::new();
+    e.et = EnumOrUnknown::new(pb::EventType::POINTER_BUTTON);
+    let pointer_button = e.pointer_button.mut_or_insert_default();
+    pointer_button.pos = Some(pb_pos2(10.0, 20.0)).into();
+    pointer_button.button = EnumOrUnknown::new(button);
+    pointer_button.pressed = pressed;
+    e
+}
+
+#[test]
+fn pointer_button_without_position() {
+    let mut e = pb_pointer_button(pb::ButtonType::PRIMARY, true);
+    e.pointer_button.mut_or_insert_default().pos.clear();
+    let options = InputOptions::default();
+    let pos_of = |pointer_pos| match event_from_pb_to_native(e.clone(), &options, pointer_pos) {
+        Some(Event::PointerButton { pos, .. }) => pos,
+        _ => panic!("pointer button not converted"),
+    };
+    assert_eq!(pos_of(None), egui::Pos2::ZERO);
+    assert_eq!(pos_of(Some(egui::pos2(5.0, 6.0))), egui::pos2(5.0, 6.0));
+    let mut entered = pb::Event::new();
+    entered.et = EnumOrUnknown::new(pb::EventType::POINTER_ENTERED);
+    let mut input = pb::Input::new();
+    input.events = vec![entered];
+    let input = input_from_pb_to_native(input, &options, Some(egui::pos2(7.0, 8.0)));
+    assert_eq!(input.events, [Event::PointerMoved(egui::pos2(7.0, 8.0))]);
+
+    let mut context = UnityContext::new(initializer(), |_| Label);
+    let mut moved = pb::Event::new();
+    moved.et = EnumOrUnknown::new(pb::EventType::POINTER_MOVED);
+    moved.pointer_moved = Some(pb_pos2(30.0, 40.0)).into();
+    let frame = |context: &mut UnityContext<Label>, events: Vec<pb::Event>| {
+        let mut input = pb::Input::new();
+        input.events = events;
+        context.run_pb_frame(input);
+        context.context().input(|i| i.pointer.press_origin())
+    };
+    // the position is taken from an earlier event of the same or a previous frame
+    assert_eq!(
+        frame(&mut context, vec![moved, e.clone()]),
+        Some(egui::pos2(30.0, 40.0))
+    );
+    let mut release = pb_pointer_button(pb::ButtonType::PRIMARY, false);
+    release.pointer_button.mut_or_insert_default().pos = Some(pb_pos2(50.0, 60.0)).into();
+    frame(&mut context, vec![release]);
+    assert_eq!(frame(&mut context, vec![e]), Some(egui::pos2(50.0, 60.0)));
+}
+
+fn pb_click(pressed: bool, click_count: u32) -> pb::Event {
+    let mut e = pb_pointer_button(pb::ButtonType::PRIMARY, pressed);
+    e.pointer_button.mut_or_insert_default().click_count = click_count;
+    e
+}
+
+#[test]
+fn multi_click_added_for_egui() {
+    let mut multi_click = MultiClick::default();
+    let mut events = vec![pb_click(true, 0), pb_click(false, 2)];
+    multi_click.process(&mut events, 10.0);
+    let pressed: Vec<_> = events.iter().map(|e| e.pointer_button.pressed).collect();
+    assert_eq!(pressed, [true, false, true, false]);
+    assert_eq!(events[2].pointer_button.click_count, 0);
+
+    // a double click egui counts by itself is left alone
+    let mut multi_click = MultiClick::default();
+    let mut events = vec![pb_click(true, 0), pb_click(false, 1)];
+    multi_click.process(&mut events, 10.0);
+    assert_eq!(events.len(), 2);
+    let mut events = vec![pb_click(true, 0), pb_click(false, 2)];
+    multi_click.process(&mut events, 10.1);
+    assert_eq!(events.len(), 2);
+
+    // at most a triple click
+    let mut events = vec![pb_click(true, 0), pb_click(false, 5)];
+    MultiClick::default().process(&mut events, 10.0);
+    assert_eq!(events.len(), 6);
+}
+
+/// A clickable label, counts double clicks on it.
+#[derive(Default)]
+struct DoubleClickLabel {
+    double_clicks: usize,
+}
+
+impl App for DoubleClickLabel {
+    fn update(&mut self, ctx: &egui::Context) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let label = egui::Label::new("double click").sense(egui::Sense::click());
+            if ui.add(label).double_clicked() {
+                self.double_clicks += 1;
+            }
+        });
+    }
+}
+
+#[test]
+fn double_click_counted_by_unity() {
+    let mut context = UnityContext::new(initializer(), |_| DoubleClickLabel::default());
+    let frame = |context: &mut UnityContext<DoubleClickLabel>, time, events| {
+        let mut input = pb::Input::new();
+        input.screen_rect.mut_or_insert_default().min = Some(pb_pos2(0.0, 0.0)).into();
+        input.screen_rect.mut_or_insert_default().max = Some(pb_pos2(800.0, 600.0)).into();
+        input.time = Some(time);
+        input.events = events;
+        context.run_pb_frame(input);
+    };
+    frame(&mut context, 1.0, vec![]);
+    frame(
+        &mut context,
+        2.0,
+        vec![pb_click(true, 0), pb_click(false, 1)],
+    );
+    // delayed by unity, too late for egui to count as a double click
+    frame(
+        &mut context,
+        3.0,
+        vec![pb_click(true, 0), pb_click(false, 0)],
+    );
+    assert_eq!(context.app().double_clicks, 0);
+    frame(
+        &mut context,
+        4.0,
+        vec![pb_click(true, 0), pb_click(false, 1)],
+    );
+    frame(
+        &mut context,
+        5.0,
+        vec![pb_click(true, 0), pb_click(false, 2)],
+    );
+    assert_eq!(context.app().double_clicks, 1);
+}
+
+#[test]
+fn convert_pb_event() {
+    let options = InputOptions::default();
+    let mut e = pb::Event::new();
+    e.et = EnumOrUnknown::new(pb::EventType::TEXT);
+    e.text = "hello".into();
+    assert_eq!(
+        event_from_pb_to_native(e, &options, None),
+        Some(Event::Text("hello".into()))
+    );
+
+    let e = pb_pointer_button(pb::ButtonType::PRIMARY, true);
+    assert_eq!(
+        event_from_pb_to_native(e, &options, None),
+        Some(Event::PointerButton {
+            pos: egui::pos2(10.0, 20.0),
+            button: PointerButton::Primary,
+            pressed: true,
+            modifiers: Modifiers::NONE,
+        })
+    );
+
+    let e = pb_pointer_button(pb::ButtonType::BT_NONE, true);
+    assert_eq!(event_from_pb_to_native(e, &options, None), None);
+}
+
+#[test]
+fn convert_pb_input() {
+    let mut pb_input = pb::Input::new();
+    pb_input.screen_rect.mut_or_insert_default().min = Some(pb_pos2(0.0, 0.0)).into();
+    pb_input.screen_rect.mut_or_insert_default().max = Some(pb_pos2(800.0, 600.0)).into();
+    pb_input.pixels_per_point = 2.0;
+    pb_input.time = Some(1.5);
+    pb_input.has_focus = true;
+    pb_input
+        .events
+        .push(pb_pointer_button(pb::ButtonType::SECONDARY, false));
+
+    let input = input_from_pb_to_native(pb_input.clone(), &InputOptions::default(), None);
+    assert_eq!(
+        input.screen_rect,
+        Some(egui::Rect::from_min_max(
+            egui::pos2(0.0, 0.0),
+            egui::pos2(800.0, 600.0)
+        ))
+    );
+    assert_eq!(input.pixels_per_point, Some(2.0));
+    assert_eq!(input.time, Some(1.5));
+    assert!(input.has_focus);
+    assert_eq!(input.events.len(), 1);
+
+    let bytes = pb_input.write_to_bytes().unwrap();
+    let parsed = parse_input_bytes(&bytes, &InputOptions::default()).unwrap();
+    assert_eq!(parsed.screen_rect, input.screen_rect);
+    assert_eq!(parsed.events, input.events);
+}
+
+#[test]
+fn zero_time_kept() {
+    let mut pb_input = pb::Input::new();
+    let bytes = pb_input.write_to_bytes().unwrap();
+    let parsed = parse_input_bytes(&bytes, &InputOptions::default()).unwrap();
+    assert_eq!(parsed.time, None);
+
+    pb_input.time = Some(0.0);
+    let bytes = pb_input.write_to_bytes().unwrap();
+    let parsed = parse_input_bytes(&bytes, &InputOptions::default()).unwrap();
+    assert_eq!(parsed.time, Some(0.0));
+}
+
+#[test]
+fn time_never_goes_back() {
+    let mut context = UnityContext::new(initializer(), |_| Label);
+    let frame_at = |context: &mut UnityContext<Label>, time| {
+        context.run_frame(RawInput {
+            time,
+            ..Default::default()
+        });
+        context.context().input(|i| i.time)
+    };
+    assert_eq!(frame_at(&mut context, Some(0.0)), 0.0);
+    assert_eq!(frame_at(&mut context, Some(2.0)), 2.0);
+    let predicted_dt = RawInput::default().predicted_dt as f64;
+    assert_eq!(frame_at(&mut context, Some(1.0)), 2.0 + predicted_dt);
+    assert_eq!(frame_at(&mut context, Some(3.0)), 4.0 + predicted_dt);
+}
+
+#[test]
+fn time_resyncs_after_clock_reset() {
+    let mut context = UnityContext::new(initializer(), |_| Label);
+    context.init_log();
+    let frame_at = |context: &mut UnityContext<Label>, time| {
+        context.run_frame(RawInput {
+            time: Some(time),
+            ..Default::default()
+        });
+        context.context().input(|i| i.time)
+    };
+    let predicted_dt = RawInput::default().predicted_dt as f64;
+    frame_at(&mut context, 9.0);
+    frame_at(&mut context, 10.0);
+    LOGS.with(|l| l.take());
+    // unity's clock restarts, every later frame is before the last one without the offset
+    assert_eq!(frame_at(&mut context, 0.0), 10.0 + predicted_dt);
+    assert_eq!(frame_at(&mut context, 0.5), 10.5 + predicted_dt);
+    assert_eq!(frame_at(&mut context, 1.0), 11.0 + predicted_dt);
+    let warnings = || {
+        LOGS.with(|l| l.take())
+            .iter()
+            .filter(|(_, message)| message.contains("goes back"))
+            .count()
+    };
+    assert_eq!(warnings(), 1);
+
+    // a second reset is warned again
+    assert_eq!(frame_at(&mut context, 0.0), 11.0 + 2.0 * predicted_dt);
+    assert_eq!(warnings(), 1);
+}
+
+/// A button with tooltip, records hover state of every frame.
+#[derive(Default)]
+struct HoverButton {
+    rect: Option<egui::Rect>,
+    hovered: bool,
+}
+
+impl App for HoverButton {
+    fn update(&mut self, ctx: &egui::Context) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let response = ui.button("button").on_hover_text("tooltip");
+            self.rect = Some(response.rect);
+            self.hovered = response.hovered();
+        });
+    }
+}
+
+#[test]
+fn focus_loss_cleans_up_pointer() {
+    let mut context = UnityContext::new(initializer(), |_| HoverButton::default());
+    let focused = |events| RawInput {
+        has_focus: true,
+        events,
+        ..Default::default()
+    };
+    let unfocused = || RawInput {
+        has_focus: false,
+        ..Default::default()
+    };
+    context.run_frame(focused(vec![]));
+    let pos = context.app().rect.unwrap().center();
+    context.run_frame(focused(vec![
+        Event::PointerMoved(pos),
+        Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed: true,
+            modifiers: Modifiers::NONE,
+        },
+    ]));
+    context.run_frame(focused(vec![]));
+    assert!(context.app().hovered);
+    assert!(context.context().input(|i| i.pointer.primary_down()));
+
+    context.run_frame(unfocused());
+    context.context().input(|i| {
+        assert!(!i.pointer.any_down());
+        assert!(i.pointer.hover_pos().is_none());
+    });
+    // egui keeps the interact position for one more frame, so the release is still handled.
+    context.run_frame(unfocused());
+    assert!(!context.app().hovered);
+    assert!(!context.context().is_pointer_over_area());
+}
+
+#[test]
+fn widget_events_forwarded() {
+    let mut initializer = initializer();
+    initializer.widget_event = Some(widget_event);
+    let mut context = UnityContext::new(initializer, |_| HoverButton::default());
+    context.run_frame(RawInput::default());
+    let pos = context.app().rect.unwrap().center();
+    let click = |pressed| Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Modifiers::NONE,
+    };
+    context.run_frame(events(vec![Event::PointerMoved(pos), click(true)]));
+    context.run_frame(events(vec![click(false)]));
+    assert_eq!(
+        WIDGET_EVENTS.with(|w| w.take()),
+        [(
+            output_event_to_u32(&egui::output::OutputEvent::Clicked(egui::WidgetInfo::new(
+                egui::WidgetType::Button
+            ))),
+            widget_type_to_u32(egui::WidgetType::Button),
+            Some("button".to_owned()),
+            2
+        )]
+    );
+}
+
+#[test]
+fn texture_format_converted() {
+    let red = egui::Color32::from_rgba_premultiplied(128, 0, 0, 128);
+    let bytes = |format: TextureFormat| {
+        let mut pixels = [red];
+        format.convert(&mut pixels);
+        pixels[0].to_array()
+    };
+    assert_eq!(bytes(TextureFormat::Rgba32), [128, 0, 0, 128]);
+    assert_eq!(bytes(TextureFormat::Bgra32), [0, 0, 128, 128]);
+    assert_eq!(bytes(TextureFormat::Rgba32Straight), [255, 0, 0, 128]);
+    assert_eq!(bytes(TextureFormat::Bgra32Straight), [0, 0, 255, 128]);
+    assert_eq!(
+        TextureFormat::from_u32(3),
+        Some(TextureFormat::Bgra32Straight)
+    );
+    assert_eq!(TextureFormat::from_u32(4), None);
+
+    let mut context = UnityContext::new(initializer(), |_| Label);
+    context.set_texture_format(TextureFormat::Bgra32);
+    TEXTURE_FORMATS.with(|f| f.take());
+    let image = egui::ColorImage::new([2, 2], red);
+    context.set_texture(
+        egui::TextureId::User(1),
+        egui::epaint::ImageDelta::full(image, Default::default()),
+    );
+    assert_eq!(TEXTURE_FORMATS.with(|f| f.take()), [1]);
+}
+
+#[test]
+fn partial_delta_of_unknown_texture_skipped() {
+    let mut context = UnityContext::new(initializer(), |_| Label);
+    let id = egui::TextureId::User(7);
+    let image = |size: [usize; 2]| egui::ColorImage::new(size, egui::Color32::RED);
+    let options = egui::TextureOptions::LINEAR;
+    TEXTURE_RECTS.with(|t| t.take());
+    context.set_texture(
+        id,
+        egui::epaint::ImageDelta::partial([0, 0], image([2, 2]), options),
+    );
+    assert!(TEXTURE_RECTS.with(|t| t.take()).is_empty());
+
+    context.set_texture(id, egui::epaint::ImageDelta::full(image([4, 4]), options));
+    context.set_texture(
+        id,
+        egui::epaint::ImageDelta::partial([2, 2], image([2, 2]), options),
+    );
+    // outside of the 4x4 texture
+    context.set_texture(
+        id,
+        egui::epaint::ImageDelta::partial([3, 0], image([2, 2]), options),
+    );
+    assert_eq!(
+        TEXTURE_RECTS.with(|t| t.take()),
+        [[0, 0, 4, 4], [2, 2, 2, 2]]
+    );
+
+    context.set_textures(vec![
+        (
+            egui::TextureId::User(8),
+            egui::epaint::ImageDelta::partial([0, 0], image([1, 1]), options),
+        ),
+        (
+            id,
+            egui::epaint::ImageDelta::partial([0, 0], image([1, 1]), options),
+        ),
+    ]);
+    let batches = BATCHES.with(|b| b.take());
+    assert_eq!(batches.len(), 1);
+    assert_eq!(
+        batches[0].iter().map(|(id, _)| *id).collect::<Vec<_>>(),
+        [texture_id_to_u64(id)]
+    );
+}
+
+/// Loads a texture in the first frame and drops it in the third.
+#[derive(Default)]
+struct TextureOwner {
+    frames: usize,
+    texture: Option<egui::TextureHandle>,
+}
+
+impl App for TextureOwner {
+    fn update(&mut self, ctx: &egui::Context) {
+        self.frames += 1;
+        match self.frames {
+            1 => {
+                let image = egui::ColorImage::new([2, 2], egui::Color32::WHITE);
+                self.texture = Some(ctx.load_texture("owned", image, Default::default()));
+            }
+            3 => self.texture = None,
+            _ => {}
+        }
+        egui::CentralPanel::default().show(ctx, |ui| ui.label("label"));
+    }
+}
+
+#[test]
+fn live_textures_released() {
+    let mut context = UnityContext::new(initializer(), |_| TextureOwner::default());
+    TEXTURES.with(|t| t.take());
+    REMOVED_TEXTURES.with(|r| r.take());
+    for _ in 0..4 {
+        context.run_frame(RawInput::default());
+    }
+    let mut uploaded = TEXTURES.with(|t| t.take());
+    uploaded.extend(
+        BATCHES
+            .with(|b| b.take())
+            .into_iter()
+            .flatten()
+            .map(|(id, _)| id),
+    );
+    assert_eq!(uploaded.len(), 2);
+    // the user texture is freed by egui, the font atlas is still alive
+    let removed = REMOVED_TEXTURES.with(|r| r.take());
+    assert_eq!(removed.len(), 1);
+    assert_eq!(context.live_textures().count(), 1);
+
+    context.release_textures();
+    assert_eq!(context.live_textures().count(), 0);
+    assert_eq!(context.texture_memory(), 0);
+    let mut removed = [removed, REMOVED_TEXTURES.with(|r| r.take())].concat();
+    removed.sort();
+    uploaded.sort();
+    assert_eq!(removed, uploaded);
+
+    // nothing is left to remove on drop
+    drop(context);
+    assert!(REMOVED_TEXTURES.with(|r| r.take()).is_empty());
+}
+
+/// Pushes text in the first frame and records texts of every frame.
+#[derive(Default)]
+struct EventPusher {
+    texts: Vec<Vec<String>>,
+}
+
+impl App for EventPusher {
+    fn update(&mut self, _: &egui::Context) {}
+
+    fn update_frame(&mut self, ctx: &egui::Context, frame: &mut Frame) {
+        if self.texts.is_empty() {
+            frame.push_event(Event::Text("pushed".into()));
+            frame.push_event(Event::Text("again".into()));
+        }
+        self.texts.push(ctx.input(|i| {
+            i.events
+                .iter()
+                .filter_map(|e| match e {
+                    Event::Text(text) => Some(text.clone()),
+                    _ => None,
+                })
+                .collect()
+        }));
+    }
+}
+
+#[test]
+fn app_events_added_to_next_frame() {
+    let mut context = UnityContext::new(initializer(), |_| EventPusher::default());
+    let input = || RawInput {
+        events: vec![Event::Text("real".into())],
+        ..Default::default()
+    };
+    context.run_frame(input());
+    context.run_frame(input());
+    context.run_frame(RawInput::default());
+    assert_eq!(
+        context.app().texts,
+        [vec!["real"], vec!["pushed", "again", "real"], vec![]]
+    );
+}
+
+/// Injects text before each frame and records what the frame received and copied.
+#[derive(Default)]
+struct FrameHooks {
+    received: Vec<String>,
+    copied: Vec<String>,
+}
+
+impl App for FrameHooks {
+    fn before_frame(&mut self, input: &mut RawInput) {
+        input.events.push(Event::Text("injected".into()));
+    }
+
+    fn update(&mut self, ctx: &egui::Context) {
+        let texts: Vec<_> = ctx.input(|i| {
+            i.events
+                .iter()
+                .filter_map(|e| match e {
+                    Event::Text(text) => Some(text.clone()),
+                    _ => None,
+                })
+                .collect()
+        });
+        ctx.output_mut(|o| o.copied_text = texts.concat());
+        self.received.extend(texts);
+    }
+
+    fn after_frame(&mut self, output: &egui::FullOutput) {
+        self.copied.push(output.platform_output.copied_text.clone());
+    }
+}
+
+#[test]
+fn frame_hooks_called() {
+    let mut context = UnityContext::new(initializer(), |_| FrameHooks::default());
+    context.run_frame(RawInput::default());
+    context.run_frame(RawInput::default());
+    assert_eq!(context.app().received, ["injected", "injected"]);
+    assert_eq!(context.app().copied, ["injected", "injected"]);
+}
+
+#[test]
+fn textures_removed_once_on_drop() {
+    let mut context = UnityContext::new(initializer(), |_| TextureOwner::default());
+    TEXTURES.with(|t| t.take());
+    BATCHES.with(|b| b.take());
+    REMOVED_TEXTURES.with(|r| r.take());
+    context.run_frame(RawInput::default());
+    context.run_frame(RawInput::default());
+    let mut uploaded = TEXTURES.with(|t| t.take());
+    uploaded.extend(
+        BATCHES
+            .with(|b| b.take())
+            .into_iter()
+            .flatten()
+            .map(|(id, _)| id),
+    );
+    uploaded.sort();
+    uploaded.dedup();
+    assert_eq!(uploaded.len(), 2);
+    assert!(REMOVED_TEXTURES.with(|r| r.take()).is_empty());
+
+    drop(context);
+    let mut removed = REMOVED_TEXTURES.with(|r| r.take());
+    removed.sort();
+    assert_eq!(removed, uploaded);
+}
+
+#[test]
+fn texture_id_encoding() {
+    let ids = [
+        egui::TextureId::Managed(0),
+        egui::TextureId::Managed(1),
+        egui::TextureId::User(0),
+        egui::TextureId::User(1),
+    ];
+    let encoded: Vec<_> = ids.iter().map(|id| texture_id_to_u64(*id)).collect();
+    assert_eq!(encoded, [0, 2, 1, 3]);
+    for (id, encoded) in ids.iter().zip(encoded) {
+        assert_eq!(u64_to_texture_id(encoded), *id);
+    }
+}
+
+struct Label;
+
+impl App for Label {
+    fn update(&mut self, ctx: &egui::Context) {
+        egui::CentralPanel::default().show(ctx, |ui| ui.label("label"));
+    }
+}
+
+#[test]
+fn paint_without_repaint_request() {
+    let mut context = UnityContext::new(initializer(), |_| Label);
+    for _ in 0..3 {
+        context.run_frame(RawInput::default());
+    }
+    assert!(!context.repaint_after().is_zero());
+    let meshes = MESHES.with(|m| m.get());
+    context.run_frame(RawInput::default());
+    assert!(MESHES.with(|m| m.get()) > meshes);
+}
+
+#[test]
+fn float4_vertex_colors() {
+    let vertices = [
+        egui::epaint::Vertex {
+            pos: egui::pos2(1.0, 2.0),
+            uv: egui::pos2(0.25, 0.5),
+            color: egui::Color32::from_rgba_premultiplied(255, 0, 51, 255),
+        },
+        egui::epaint::Vertex {
+            pos: egui::pos2(3.0, 4.0),
+            uv: egui::pos2(0.75, 1.0),
+            color: egui::Color32::from_rgba_premultiplied(0, 102, 0, 153),
+        },
+    ];
+    let mut buffer = Vec::new();
+    let bytes = repack_vertices(&vertices, VertexColorFormat::Color32, &mut buffer);
+    assert_eq!(bytes.len(), vertices.len() * 20);
+
+    let bytes = repack_vertices(&vertices, VertexColorFormat::Float4, &mut buffer);
+    assert_eq!(bytes.len(), vertices.len() * 32);
+    let floats: Vec<f32> = bytes
+        .chunks(4)
+        .map(|b| f32::from_ne_bytes(b.try_into().unwrap()))
+        .collect();
+    for (v, floats) in vertices.iter().zip(floats.chunks(8)) {
+        assert_eq!(floats[..2], [v.pos.x, v.pos.y]);
+        let color: Vec<_> = v
+            .color
+            .to_array()
+            .iter()
+            .map(|c| *c as f32 / 255.0)
+            .collect();
+        assert_eq!(floats[2..6], color[..]);
+        assert_eq!(floats[6..], [v.uv.x, v.uv.y]);
+    }
+
+    let bytes = repack_vertices(&vertices, VertexColorFormat::LinearFloat4, &mut buffer);
+    assert_eq!(bytes.len(), vertices.len() * 32);
+    let floats: Vec<f32> = bytes
+        .chunks(4)
+        .map(|b| f32::from_ne_bytes(b.try_into().unwrap()))
+        .collect();
+    for (v, floats) in vertices.iter().zip(floats.chunks(8)) {
+        assert_eq!(floats[..2], [v.pos.x, v.pos.y]);
+        let rgba = egui::Rgba::from(v.color);
+        let color = [rgba.r(), rgba.g(), rgba.b(), rgba.a()];
+        for (f, c) in floats[2..6].iter().zip(color) {
+            assert!((f - c).abs() < 1e-6);
+        }
+        assert_eq!(floats[6..], [v.uv.x, v.uv.y]);
+    }
+    assert_eq!(
+        VertexColorFormat::from_u32(2),
+        Some(VertexColorFormat::LinearFloat4)
+    );
+    assert_eq!(VertexColorFormat::from_u32(3), None);
+}
+
+#[test]
+fn reverse_winding_swaps_triplets() {
+    let mut indices = vec![0, 1, 2, 2, 3, 0];
+    reverse_winding(&mut indices);
+    assert_eq!(indices, [0, 2, 1, 2, 0, 3]);
+    reverse_winding(&mut indices);
+    assert_eq!(indices, [0, 1, 2, 2, 3, 0]);
+}
+
+#[test]
+fn split_large_mesh() {
+    let mut mesh = egui::Mesh::default();
+    for i in 0..17_500 {
+        let min = egui::pos2(i as f32, 0.0);
+        mesh.add_colored_rect(
+            egui::Rect::from_min_size(min, egui::vec2(1.0, 1.0)),
+            egui::Color32::WHITE,
+        );
+    }
+    assert_eq!(mesh.vertices.len(), 70_000);
+    let triangles = mesh.indices.len() / 3;
+
+    let meshes = split_mesh(mesh);
+    assert!(meshes.len() > 1);
+    for mesh in &meshes {
+        assert!(mesh.vertices.len() <= u16::MAX as usize);
+        assert!(mesh
+            .indices
+            .iter()
+            .all(|i| (*i as usize) < mesh.vertices.len()));
+    }
+    let split: usize = meshes.iter().map(|m| m.indices.len() / 3).sum();
+    assert_eq!(split, triangles);
+}
+
+#[test]
+fn inspection_window_adds_widgets() {
+    let mut context = UnityContext::new(initializer(), |_| Label);
+    let meshes_of_frame = |context: &mut UnityContext<Label>| {
+        let meshes = MESHES.with(|m| m.get());
+        context.run_frame(RawInput::default());
+        MESHES.with(|m| m.get()) - meshes
+    };
+    meshes_of_frame(&mut context);
+    let without = meshes_of_frame(&mut context);
+    context.show_debug_window(DebugWindow::Inspection, true);
+    meshes_of_frame(&mut context);
+    let with = meshes_of_frame(&mut context);
+    assert!(with > without);
+}
+
+/// Paints a unity callback and a callback unknown to unity.
+struct Callbacks;
+
+impl App for Callbacks {
+    fn update(&mut self, ctx: &egui::Context) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let rect = egui::Rect::from_min_size(egui::pos2(10.0, 10.0), egui::vec2(20.0, 20.0));
+            ui.painter().add(egui::PaintCallback {
+                rect,
+                callback: std::sync::Arc::new(0u32),
+            });
+            ui.painter().add(UnityCallback::paint_callback(rect, 42));
+        });
+    }
+}
+
+#[test]
+fn paint_callback_forwarded() {
+    let mut context = UnityContext::new(initializer(), |_| Callbacks);
+    context.run_frame(RawInput::default());
+    assert_eq!(CALLBACKS.with(|c| c.take()), [42]);
+}
+
+fn pb_touch(phase: pb::TouchPhase, force: f32) -> pb::Event {
+    let mut e = pb::Event::new();
+    e.et = EnumOrUnknown::new(pb::EventType::TOUCH);
+    let touch = e.touch.mut_or_insert_default();
+    touch.device_id = 1;
+    touch.id = 2;
+    touch.phase = EnumOrUnknown::new(phase);
+    touch.pos = Some(pb_pos2(5.0, 6.0)).into();
+    touch.force = force;
+    e
+}
+
+#[test]
+fn convert_pb_touch() {
+    let options = InputOptions::default();
+    let e = event_from_pb_to_native(pb_touch(pb::TouchPhase::CANCEL, -1.0), &options, None);
+    assert_eq!(
+        e,
+        Some(Event::Touch {
+            device_id: egui::TouchDeviceId(1),
+            id: egui::TouchId(2),
+            phase: egui::TouchPhase::Cancel,
+            pos: egui::pos2(5.0, 6.0),
+            force: 0.0,
+        })
+    );
+    let e = event_from_pb_to_native(pb_touch(pb::TouchPhase::START, 0.5), &options, None);
+    assert!(
+        matches!(e, Some(Event::Touch { phase: egui::TouchPhase::Start, force, .. }) if force == 0.5)
+    );
+    let e = event_from_pb_to_native(pb_touch(pb::TouchPhase::TP_NONE, 0.5), &options, None);
+    assert_eq!(e, None);
+}
+
+#[test]
+fn convert_pb_key_repeat() {
+    let mut e = pb::Event::new();
+    e.et = EnumOrUnknown::new(pb::EventType::KEY);
+    let key = e.key.mut_or_insert_default();
+    key.key = EnumOrUnknown::new(pb::KeyType::Backspace);
+    key.pressed = true;
+    let options = InputOptions::default();
+    assert!(matches!(
+        event_from_pb_to_native(e.clone(), &options, None),
+        Some(Event::Key {
+            key: Key::Backspace,
+            repeat: false,
+            ..
+        })
+    ));
+    e.key.mut_or_insert_default().repeat = true;
+    assert!(matches!(
+        event_from_pb_to_native(e, &options, None),
+        Some(Event::Key {
+            key: Key::Backspace,
+            pressed: true,
+            repeat: true,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn convert_pb_symbol_keys() {
+    assert_eq!(key_from_pb_code(73), Some(Key::Minus));
+    assert_eq!(key_from_pb_code(74), Some(Key::PlusEquals));
+    // values unknown to this version are ignored
+    assert_eq!(key_from_pb_code(1000), None);
+}
+
+fn pb_wheel(unit: pb::ScrollUnit, x: f32, y: f32, modifiers: pb::Modifiers) -> pb::Event {
+    let mut e = pb::Event::new();
+    e.et = EnumOrUnknown::new(pb::EventType::MOUSE_WHEEL);
+    let wheel = e.mouse_wheel.mut_or_insert_default();
+    wheel.unit = EnumOrUnknown::new(unit);
+    wheel.delta = Some(pb_vec2(x, y)).into();
+    wheel.modifiers = Some(modifiers).into();
+    e
+}
+
+#[test]
+fn convert_pb_mouse_wheel() {
+    let options = InputOptions::default();
+    let none = pb::Modifiers::new();
+    let e = event_from_pb_to_native(
+        pb_wheel(pb::ScrollUnit::SU_POINT, 1.0, 2.0, none.clone()),
+        &options,
+        None,
+    );
+    assert_eq!(e, Some(Event::Scroll(egui::vec2(1.0, 2.0))));
+    let e = event_from_pb_to_native(
+        pb_wheel(pb::ScrollUnit::SU_LINE, 0.0, -1.0, none.clone()),
+        &options,
+        None,
+    );
+    assert_eq!(e, Some(Event::Scroll(egui::vec2(0.0, -50.0))));
+
+    let mut shift = pb::Modifiers::new();
+    shift.shift = true;
+    let e = event_from_pb_to_native(
+        pb_wheel(pb::ScrollUnit::SU_POINT, 0.0, 3.0, shift),
+        &options,
+        None,
+    );
+    assert_eq!(e, Some(Event::Scroll(egui::vec2(3.0, 0.0))));
+
+    let mut ctrl = pb::Modifiers::new();
+    ctrl.ctrl = true;
+    let e = event_from_pb_to_native(
+        pb_wheel(pb::ScrollUnit::SU_POINT, 0.0, 200.0, ctrl),
+        &options,
+        None,
+    );
+    assert!(matches!(e, Some(Event::Zoom(zoom)) if (zoom - 1.0f32.exp()).abs() < 1e-6));
+
+    // a page is the screen size in the whole input, a line without it
+    let mut input = pb::Input::new();
+    input.screen_rect.mut_or_insert_default().min = Some(pb_pos2(0.0, 0.0)).into();
+    input.screen_rect.mut_or_insert_default().max = Some(pb_pos2(300.0, 200.0)).into();
+    input
+        .events
+        .push(pb_wheel(pb::ScrollUnit::SU_PAGE, 0.0, 1.0, none.clone()));
+    let raw = input_from_pb_to_native(input, &options, None);
+    assert_eq!(raw.events, [Event::Scroll(egui::vec2(0.0, 200.0))]);
+    let e = event_from_pb_to_native(
+        pb_wheel(pb::ScrollUnit::SU_PAGE, 0.0, 1.0, none),
+        &options,
+        None,
+    );
+    assert_eq!(e, Some(Event::Scroll(egui::vec2(0.0, 50.0))));
+}
+
+#[test]
+fn convert_pb_scroll_inverted() {
+    let mut e = pb::Event::new();
+    e.et = EnumOrUnknown::new(pb::EventType::SCROLL);
+    e.scroll = Some(pb_vec2(3.0, -4.0)).into();
+    let mut options = InputOptions::default();
+    assert_eq!(
+        event_from_pb_to_native(e.clone(), &options, None),
+        Some(Event::Scroll(egui::vec2(3.0, -4.0)))
+    );
+    options.invert_scroll = true;
+    assert_eq!(
+        event_from_pb_to_native(e, &options, None),
+        Some(Event::Scroll(egui::vec2(-3.0, 4.0)))
+    );
+    let wheel = pb_wheel(pb::ScrollUnit::SU_LINE, 0.0, 1.0, pb::Modifiers::new());
+    assert_eq!(
+        event_from_pb_to_native(wheel, &options, None),
+        Some(Event::Scroll(egui::vec2(0.0, -50.0)))
+    );
+}
+
+#[test]
+fn input_events_limited() {
+    let options = InputOptions {
+        max_events_per_frame: 3,
+        ..Default::default()
+    };
+    let mut input = pb::Input::new();
+    for i in 0..10 {
+        let mut e = pb::Event::new();
+        e.et = EnumOrUnknown::new(pb::EventType::TEXT);
+        e.text = i.to_string();
+        input.events.push(e);
+    }
+    let raw = input_from_pb_to_native(input, &options, None);
+    let texts = ["0", "1", "2"].map(|t| Event::Text(t.into()));
+    assert_eq!(raw.events, texts);
+}
+
+#[test]
+fn back_button_taps_escape() {
+    let mut e = pb::Event::new();
+    e.et = EnumOrUnknown::new(pb::EventType::BACK);
+    let mut input = pb::Input::new();
+    input.events.push(e);
+    let raw = input_from_pb_to_native(input, &InputOptions::default(), None);
+    let escape = |pressed| Event::Key {
+        key: Key::Escape,
+        pressed,
+        repeat: false,
+        modifiers: Modifiers::default(),
+    };
+    assert_eq!(raw.events, vec![escape(true), escape(false)]);
+}
+
+#[test]
+fn scroll_speed_configurable() {
+    let options = InputOptions {
+        points_per_scroll_line: 20.0,
+        scroll_multiplier: 1.5,
+        ..Default::default()
+    };
+    let wheel = pb_wheel(pb::ScrollUnit::SU_LINE, 0.0, 2.0, pb::Modifiers::new());
+    assert_eq!(
+        event_from_pb_to_native(wheel, &options, None),
+        Some(Event::Scroll(egui::vec2(0.0, 60.0)))
+    );
+    let mut e = pb::Event::new();
+    e.et = EnumOrUnknown::new(pb::EventType::SCROLL);
+    e.scroll = Some(pb_vec2(2.0, 4.0)).into();
+    assert_eq!(
+        event_from_pb_to_native(e, &options, None),
+        Some(Event::Scroll(egui::vec2(3.0, 6.0)))
+    );
+}
+
+/// A code editor which keeps focus on tab.
+#[derive(Default)]
+struct CodeEditor {
+    code: String,
+}
+
+impl App for CodeEditor {
+    fn update(&mut self, ctx: &egui::Context) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let response = ui.add(egui::TextEdit::multiline(&mut self.code).lock_focus(true));
+            if ui.memory(|m| m.focus().is_none()) {
+                response.request_focus();
+            }
+        });
+    }
+}
+
+fn tab_with_text() -> Vec<Event> {
+    vec![
+        Event::Key {
+            key: Key::Tab,
+            pressed: true,
+            repeat: false,
+            modifiers: Modifiers::NONE,
+        },
+        Event::Text("\t".into()),
+    ]
+}
+
+#[test]
+fn key_and_text_inserted_once() {
+    for (convention, expected) in [
+        (KeyTextConvention::Both, "\t\t"),
+        (KeyTextConvention::PreferKey, "\t"),
+        (KeyTextConvention::PreferText, "\t"),
+    ] {
+        let mut context = UnityContext::new(initializer(), |_| CodeEditor::default());
+        context.run_frame(RawInput::default());
+        context.run_frame(RawInput::default());
+        let mut events = tab_with_text();
+        remove_duplicate_text(&mut events, convention);
+        context.run_frame(RawInput {
+            events,
+            ..Default::default()
+        });
+        assert_eq!(context.app().code, expected, "{:?}", convention);
+    }
+}
+
+#[test]
+fn text_preferred_except_for_key_only_keys() {
+    let key = |key| Event::Key {
+        key,
+        pressed: true,
+        repeat: false,
+        modifiers: Modifiers::NONE,
+    };
+    let mut context = UnityContext::new(initializer(), |_| CodeEditor::default());
+    context.run_frame(RawInput::default());
+    context.run_frame(RawInput::default());
+    let mut frame = |events: Vec<Event>| {
+        let mut events = events;
+        remove_duplicate_text(&mut events, KeyTextConvention::PreferText);
+        context.run_frame(RawInput {
+            events,
+            ..Default::default()
+        });
+        context.app().code.clone()
+    };
+    assert_eq!(frame(vec![key(Key::Space), Event::Text(" ".into())]), " ");
+    // egui ignores a text newline and inserts a text backspace as a character
+    assert_eq!(
+        frame(vec![
+            Event::Text("a".into()),
+            key(Key::Enter),
+            Event::Text("\n".into())
+        ]),
+        " a\n"
+    );
+    assert_eq!(
+        frame(vec![key(Key::Backspace), Event::Text("\u{8}".into())]),
+        " a"
+    );
+}
+
+#[test]
+fn safe_area_shrinks_screen_rect() {
+    let mut context = UnityContext::new(initializer(), |_| Label);
+    let screen_rect = egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(800.0, 600.0));
+    context.set_safe_area(20.0, 40.0, 0.0, 10.0);
+    context.run_frame(RawInput {
+        screen_rect: Some(screen_rect),
+        pixels_per_point: Some(2.0),
+        ..Default::default()
+    });
+    let expected = egui::Rect::from_min_max(egui::pos2(10.0, 20.0), egui::pos2(800.0, 595.0));
+    assert_eq!(context.context().screen_rect(), expected);
+
+    // the last screen rect from unity is kept when the insets change
+    context.set_safe_area(0.0, 0.0, 0.0, 0.0);
+    context.run_frame(RawInput::default());
+    assert_eq!(context.context().screen_rect(), screen_rect);
+}
+
+struct Cursor(egui::CursorIcon);
+
+impl App for Cursor {
+    fn update(&mut self, ctx: &egui::Context) {
+        ctx.set_cursor_icon(self.0);
+    }
+}
+
+#[test]
+fn cursor_icon_sent_on_change() {
+    let mut context = UnityContext::new(initializer(), |_| Cursor(egui::CursorIcon::Default));
+    context.run_frame(RawInput::default());
+    context.run_frame(RawInput::default());
+    assert_eq!(CURSORS.with(|c| c.take()), [0]);
+
+    context.app_mut().0 = egui::CursorIcon::Text;
+    context.run_frame(RawInput::default());
+    context.run_frame(RawInput::default());
+    assert_eq!(
+        CURSORS.with(|c| c.take()),
+        [cursor_icon_to_u32(egui::CursorIcon::Text)]
+    );
+}
+
+struct OpenUrl;
+
+impl App for OpenUrl {
+    fn update(&mut self, ctx: &egui::Context) {
+        ctx.output_mut(|o| o.open_url = Some(egui::output::OpenUrl::new_tab("https://egui.rs")));
+    }
+}
+
+#[test]
+fn open_url_forwarded() {
+    let mut context = UnityContext::new(initializer(), |_| OpenUrl);
+    context.run_frame(RawInput::default());
+    assert_eq!(URLS.with(|u| u.take()), [(1, "https://egui.rs".to_owned())]);
+}
+
+/// A focused single line text edit, records the selected text.
+#[derive(Default)]
+struct SingleLine {
+    text: String,
+    selected: String,
+}
+
+impl App for SingleLine {
+    fn update(&mut self, ctx: &egui::Context) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let output = egui::TextEdit::singleline(&mut self.text).show(ui);
+            if ui.memory(|m| m.focus().is_none()) {
+                output.response.request_focus();
+            }
+            self.selected = output
+                .cursor_range
+                .map(|range| {
+                    let [min, max] = range.as_ccursor_range().sorted();
+                    self.text
+                        .chars()
+                        .skip(min.index)
+                        .take(max.index - min.index)
+                        .collect()
+                })
+                .unwrap_or_default();
+        });
+    }
+}
+
+fn events(events: Vec<Event>) -> RawInput {
+    RawInput {
+        events,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn composition_preedit_replaced_on_commit() {
+    let mut context = UnityContext::new(initializer(), |_| SingleLine::default());
+    context.run_frame(RawInput::default());
+    context.run_frame(RawInput::default());
+
+    // unity reports the preedit without a start event
+    context.run_frame(events(vec![Event::CompositionUpdate("ni".into())]));
+    assert_eq!(context.app().text, "ni");
+    assert_eq!(context.app().selected, "ni");
+
+    context.run_frame(events(vec![Event::CompositionUpdate("nih".into())]));
+    assert_eq!(context.app().text, "nih");
+    assert_eq!(context.app().selected, "nih");
+
+    // and commits with text
+    context.run_frame(events(vec![Event::Text("你好".into())]));
+    assert_eq!(context.app().text, "你好");
+    assert_eq!(context.app().selected, "");
+
+    context.run_frame(events(vec![Event::Text("!".into())]));
+    assert_eq!(context.app().text, "你好!");
+}
+
+#[test]
+fn ime_position_follows_text_cursor() {
+    let mut initializer = initializer();
+    initializer.set_ime_position = Some(set_ime_position);
+    let mut context = UnityContext::new(initializer, |_| SingleLine::default());
+    context.run_frame(RawInput::default());
+    context.run_frame(RawInput::default());
+    let positions = IME_POSITIONS.with(|p| p.take());
+    assert_eq!(positions.len(), 1);
+    let (visible, start) = positions[0];
+    assert_eq!(visible, 1);
+
+    // unchanged position is not sent again
+    context.run_frame(RawInput::default());
+    assert!(IME_POSITIONS.with(|p| p.take()).is_empty());
+
+    context.run_frame(events(vec![Event::Text("abc".into())]));
+    context.run_frame(RawInput::default());
+    let positions = IME_POSITIONS.with(|p| p.take());
+    assert_eq!(positions.len(), 1);
+    assert!(positions[0].1.x > start.x);
+    assert_eq!(positions[0].1.y, start.y);
+}
+
+#[test]
+fn keyboard_sent_only_on_change() {
+    let mut context = UnityContext::new(initializer(), |_| Label);
+    KEYBOARD.with(|k| k.take());
+    context.run_frame(RawInput::default());
+    assert_eq!(KEYBOARD.with(|k| k.take()), [(0, None)]);
+    context.run_frame(RawInput::default());
+    assert!(KEYBOARD.with(|k| k.take()).is_empty());
+
+    let mut context = UnityContext::new(initializer(), |_| SingleLine::default());
+    context.run_frame(RawInput::default());
+    context.run_frame(RawInput::default());
+    let sent = KEYBOARD.with(|k| k.take());
+    assert_eq!(sent.last(), Some(&(1, Some("".to_owned()))));
+    context.run_frame(RawInput::default());
+    assert!(KEYBOARD.with(|k| k.take()).is_empty());
+    context.run_frame(events(vec![Event::Text("ab".into())]));
+    assert_eq!(KEYBOARD.with(|k| k.take()), [(1, Some("ab".to_owned()))]);
+    context.run_frame(RawInput::default());
+    assert!(KEYBOARD.with(|k| k.take()).is_empty());
+}
+
+#[test]
+fn composition_committed_from_pb() {
+    let mut context = UnityContext::new(initializer(), |_| SingleLine::default());
+    context.run_frame(RawInput::default());
+    context.run_frame(RawInput::default());
+    let mut run = |et, text: &str| {
+        let mut e = pb::Event::new();
+        e.et = EnumOrUnknown::new(et);
+        match et {
+            pb::EventType::COMPOSITION_UPDATE => e.composition_update = text.into(),
+            pb::EventType::COMPOSITION_END => e.composition_end = text.into(),
+            _ => {}
+        }
+        let mut input = pb::Input::new();
+        input.events.push(e);
+        let bytes = input.write_to_bytes().unwrap();
+        context.run_frame(parse_input_bytes(&bytes, &InputOptions::default()).unwrap());
+    };
+    run(pb::EventType::COMPOSITION_START, "");
+    run(pb::EventType::COMPOSITION_UPDATE, "ni");
+    run(pb::EventType::COMPOSITION_UPDATE, "nihao");
+    run(pb::EventType::COMPOSITION_END, "你好");
+    assert_eq!(context.app().text, "你好");
+    assert_eq!(context.app().selected, "");
+}
+
+#[test]
+fn dark_mode_switched() {
+    let context = UnityContext::new(initializer(), |_| Label);
+    context.set_dark_mode(false);
+    assert_eq!(context.context().style().visuals, egui::Visuals::light());
+    context.set_dark_mode(true);
+    assert_eq!(context.context().style().visuals, egui::Visuals::dark());
+}
+
+#[cfg(feature = "persistence")]
+#[test]
+fn style_round_trip() {
+    let context = UnityContext::new(initializer(), |_| Label);
+    let mut style = (*context.context().style()).clone();
+    style.spacing.item_spacing = egui::vec2(13.0, 7.0);
+    style.visuals = egui::Visuals::light();
+    context.context().set_style(style.clone());
+    let bytes = context.get_style().unwrap();
+
+    let other = UnityContext::new(initializer(), |_| Label);
+    other.set_style(&bytes).unwrap();
+    assert_eq!(
+        other.context().style().spacing.item_spacing,
+        style.spacing.item_spacing
+    );
+    assert!(!other.context().style().visuals.dark_mode);
+
+    assert!(other.set_style(b"{\"spacing\": 1").is_err());
+    assert!(other
+        .set_style(b"{\"visuals\": {\"dark_mode\": \"yes\"}}")
+        .is_err());
+    assert!(!other.context().style().visuals.dark_mode);
+}
+
+#[cfg(feature = "accesskit")]
+thread_local! {
+    static ACCESSIBILITY: std::cell::RefCell<Vec<String>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+#[cfg(feature = "accesskit")]
+extern "system" fn update_accessibility(json: *const u8, len: u32) {
+    let json = unsafe { std::slice::from_raw_parts(json, len as usize) };
+    ACCESSIBILITY.with(|a| {
+        a.borrow_mut()
+            .push(String::from_utf8(json.to_vec()).unwrap())
+    });
+}
+
+#[cfg(feature = "accesskit")]
+#[test]
+fn accessibility_tree_sent_as_json() {
+    let mut context = UnityContext::new(initializer(), |_| Label);
+    context.run_frame(RawInput::default());
+    assert!(ACCESSIBILITY.with(|a| a.take()).is_empty());
+
+    let mut context = UnityContext::new(
+        UnityInitializer {
+            update_accessibility: Some(update_accessibility),
+            ..initializer()
+        },
+        |_| Label,
+    );
+    context.run_frame(RawInput::default());
+    let updates = ACCESSIBILITY.with(|a| a.take());
+    assert_eq!(updates.len(), 1);
+    let update: accesskit::TreeUpdate = serde_json::from_str(&updates[0]).unwrap();
+    assert!(!update.nodes.is_empty());
+}
+
+#[test]
+fn fonts_added_at_runtime() {
+    let mut context = UnityContext::new(initializer(), |_| Label);
+    let hack = egui::FontDefinitions::default().font_data["Hack"]
+        .font
+        .to_vec();
+    assert!(context
+        .add_font("broken".into(), vec![1, 2, 3], egui::FontFamily::Monospace)
+        .is_err());
+    context
+        .add_font("custom".into(), hack.clone(), egui::FontFamily::Monospace)
+        .unwrap();
+    context
+        .add_font("custom".into(), hack, egui::FontFamily::Proportional)
+        .unwrap();
+    assert_eq!(context.repaint_after(), std::time::Duration::ZERO);
+    context.run_frame(RawInput::default());
+    context.run_frame(RawInput::default());
+    let families = context
+        .context()
+        .fonts(|fonts| fonts.lock().fonts.definitions().families.clone());
+    assert_eq!(families[&egui::FontFamily::Proportional][0], "custom");
+    assert!(!families[&egui::FontFamily::Monospace].contains(&"custom".to_owned()));
+    assert!(!families.values().flatten().any(|font| font == "broken"));
+}
+
+#[cfg(feature = "persistence")]
+#[test]
+fn memory_round_trip() {
+    let id = egui::Id::new("counter");
+    let context = UnityContext::new(initializer(), |_| Label);
+    context
+        .context()
+        .memory_mut(|m| m.data.insert_persisted(id, 42u32));
+    let buffer = Buffer::from_vec(context.save_memory().unwrap());
+    let bytes = unsafe { std::slice::from_raw_parts(buffer.data, buffer.len) };
+
+    let other = UnityContext::new(initializer(), |_| Label);
+    other.load_memory(bytes).unwrap();
+    unsafe { buffer.free() };
+    assert_eq!(
+        other
+            .context()
+            .memory_mut(|m| m.data.get_persisted::<u32>(id)),
+        Some(42)
+    );
+    assert!(other.load_memory(b"[1, 2").is_err());
+    assert_eq!(
+        other
+            .context()
+            .memory_mut(|m| m.data.get_persisted::<u32>(id)),
+        Some(42)
+    );
+}
+
+#[test]
+fn queued_paste_sent_once() {
+    let mut context = UnityContext::new(initializer(), |_| SingleLine::default());
+    context.run_frame(RawInput::default());
+    context.run_frame(RawInput::default());
+    let text = "x".repeat(10_000);
+    context.paste_text(text.clone());
+    context.run_frame(RawInput::default());
+    assert_eq!(context.app().text, text);
+    context.run_frame(RawInput::default());
+    assert_eq!(context.app().text, text);
+}
+
+#[test]
+fn copied_text_round_trips_through_clipboard() {
+    let mut context = UnityContext::new(initializer(), |_| SingleLine::default());
+    context.run_frame(RawInput::default());
+    context.run_frame(RawInput::default());
+    context.run_frame(events(vec![Event::Text("hello".into())]));
+    context.run_frame(RawInput::default());
+    assert!(CLIPBOARD.with(|c| c.take()).is_empty());
+
+    let select_all = Event::Key {
+        key: Key::A,
+        pressed: true,
+        repeat: false,
+        modifiers: Modifiers::COMMAND,
+    };
+    context.run_frame(events(vec![select_all, Event::Cut]));
+    assert_eq!(CLIPBOARD.with(|c| c.take()), ["hello"]);
+    assert_eq!(context.app().text, "");
+
+    let mut event = pb::Event::new();
+    event.et = EnumOrUnknown::new(pb::EventType::PASTE);
+    event.paste = "hello".into();
+    let mut input = pb::Input::new();
+    input.events.push(event);
+    let input = input_from_pb_to_native(input, &InputOptions::default(), None);
+    context.run_frame(input);
+    assert_eq!(context.app().text, "hello");
+    context.run_frame(RawInput::default());
+    assert!(CLIPBOARD.with(|c| c.take()).is_empty());
+}
+
+#[test]
+fn init_log_twice() {
+    let first = UnityContext::new(initializer(), |_| Label);
+    first.init_log();
+    let mut second = UnityContext::new(initializer(), |_| Label);
+    second.init_log();
+    drop(first);
+
+    LOGS.with(|l| l.take());
+    log::warn!("logged after reinit");
+    let logs = LOGS.with(|l| l.take());
+    assert!(logs.iter().any(|(_, m)| m.ends_with("logged after reinit")));
+
+    second.set_log_level(log::LevelFilter::Error);
+    log::warn!("filtered");
+    assert!(!LOGS
+        .with(|l| l.take())
+        .iter()
+        .any(|(_, m)| m.ends_with("filtered")));
+    assert_eq!(log::max_level(), log::LevelFilter::Error);
+
+    second.set_log_level(u32_to_level_filter(5));
+    assert_eq!(log::max_level(), log::LevelFilter::Trace);
+    log::trace!("traced");
+    assert!(LOGS
+        .with(|l| l.take())
+        .iter()
+        .any(|(_, m)| m.ends_with("traced")));
+}
+
+#[test]
+fn level_filter_from_u32() {
+    let levels: Vec<_> = (0..=6).map(u32_to_level_filter).collect();
+    assert_eq!(
+        levels,
+        [
+            log::LevelFilter::Off,
+            log::LevelFilter::Error,
+            log::LevelFilter::Warn,
+            log::LevelFilter::Info,
+            log::LevelFilter::Debug,
+            log::LevelFilter::Trace,
+            log::LevelFilter::Trace,
+        ]
+    );
+}
+
+#[test]
+fn log_levels_are_distinct() {
+    let levels: Vec<_> = log::Level::iter().map(log_level_to_unity).collect();
+    assert_eq!(levels, [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn dpi_scale_combined_with_pixels_per_point() {
+    assert_eq!(effective_pixels_per_point(0.0, 0.0), None);
+    assert_eq!(effective_pixels_per_point(2.0, 0.0), Some(2.0));
+    assert_eq!(effective_pixels_per_point(0.0, 1.5), Some(1.5));
+    assert_eq!(effective_pixels_per_point(2.0, 1.5), Some(3.0));
+
+    let mut input = pb::Input::new();
+    input.dpi_scale = 2.0;
+    input.pixels_per_point = 1.25;
+    let input = input_from_pb_to_native(input, &InputOptions::default(), None);
+    assert_eq!(input.pixels_per_point, Some(2.5));
+}
+
+#[test]
+fn null_input_buffer() {
+    let mut context = UnityContext::new(initializer(), |_| Label);
+    let buffers = [
+        Buffer {
+            data: std::ptr::null(),
+            len: 16,
+        },
+        Buffer {
+            data: std::ptr::NonNull::dangling().as_ptr(),
+            len: 0,
+        },
+    ];
+    for buffer in buffers {
+        let meshes = MESHES.with(|m| m.get());
+        // null is allowed with any length, so is a dangling pointer with 0 length
+        unsafe { context.update(buffer) }.unwrap();
+        assert!(MESHES.with(|m| m.get()) > meshes);
+    }
+}
+
+#[test]
+fn empty_input_keeps_focus() {
+    let mut context = UnityContext::new(initializer(), |_| Label);
+    let screen_rect = egui::Rect::from_min_size(Default::default(), egui::vec2(320.0, 240.0));
+    context.run_frame(RawInput {
+        screen_rect: Some(screen_rect),
+        has_focus: true,
+        modifiers: Modifiers::SHIFT,
+        events: vec![Event::PointerButton {
+            pos: egui::pos2(10.0, 10.0),
+            button: PointerButton::Primary,
+            pressed: true,
+            modifiers: Modifiers::SHIFT,
+        }],
+        ..Default::default()
+    });
+    unsafe {
+        context.update(Buffer {
+            data: std::ptr::null(),
+            len: 0,
+        })
+    }
+    .unwrap();
+    context.context().input(|i| {
+        assert!(i.pointer.primary_down());
+        assert!(i.pointer.has_pointer());
+        assert_eq!(i.modifiers, Modifiers::SHIFT);
+        assert_eq!(i.screen_rect, screen_rect);
+    });
+}
+
+#[test]
+fn empty_input_keeps_safe_area() {
+    let mut context = UnityContext::new(initializer(), |_| Label);
+    let screen_rect = egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(800.0, 600.0));
+    context.set_safe_area(20.0, 40.0, 0.0, 10.0);
+    context.run_frame(RawInput {
+        screen_rect: Some(screen_rect),
+        pixels_per_point: Some(2.0),
+        ..Default::default()
+    });
+    let expected = egui::Rect::from_min_max(egui::pos2(10.0, 20.0), egui::pos2(800.0, 595.0));
+    assert_eq!(context.context().screen_rect(), expected);
+    for _ in 0..3 {
+        unsafe {
+            context.update(Buffer {
+                data: std::ptr::null(),
+                len: 0,
+            })
+        }
+        .unwrap();
+        assert_eq!(context.context().screen_rect(), expected);
+    }
+}
+
+#[test]
+fn update_drives_unity_callbacks() {
+    let mut context = UnityContext::new(initializer(), |_| Label);
+    let mut input = pb::Input::new();
+    input.screen_rect.mut_or_insert_default().min = Some(pb_pos2(0.0, 0.0)).into();
+    input.screen_rect.mut_or_insert_default().max = Some(pb_pos2(320.0, 240.0)).into();
+    input.pixels_per_point = 1.0;
+    input.time = Some(0.0);
+    let bytes = input.write_to_bytes().unwrap();
+    let update = |context: &mut UnityContext<Label>| {
+        let buffer = Buffer {
+            data: bytes.as_ptr(),
+            len: bytes.len(),
+        };
+        PAINT_CALLS.with(|c| c.take());
+        TEXTURES.with(|t| t.take());
+        TEXTURE_RECTS.with(|t| t.take());
+        MESH_BOUNDS.with(|b| b.set((egui::Rect::NOTHING, egui::Rect::NOTHING)));
+        unsafe { context.update(buffer) }.unwrap();
+        PAINT_CALLS.with(|c| c.take())
+    };
+
+    // the font atlas is uploaded in full before the label is painted
+    let calls = update(&mut context);
+    assert_eq!(calls.first(), Some(&"begin_paint"));
+    assert_eq!(calls.get(1), Some(&"set_texture"));
+    assert_eq!(calls.last(), Some(&"end_paint"));
+    assert!(calls[2..calls.len() - 1].iter().all(|c| *c == "paint_mesh"));
+    assert!(calls.len() > 3);
+    assert_eq!(
+        TEXTURES.with(|t| t.take()),
+        [texture_id_to_u64(egui::TextureId::default())]
+    );
+    let [x, y, width, height] = TEXTURE_RECTS.with(|t| t.take())[0];
+    assert_eq!([x, y], [0, 0]);
+    assert!(width > 0 && height > 0);
+    let screen = egui::Rect::from_min_size(Default::default(), egui::vec2(320.0, 240.0));
+    let (clip, bounds) = MESH_BOUNDS.with(|b| b.get());
+    assert_eq!(clip, screen);
+    // feathering may reach half a pixel outside
+    assert!(screen.expand(1.0).contains_rect(bounds));
+
+    // nothing new to upload in the next frame
+    let calls = update(&mut context);
+    assert!(!calls.contains(&"set_texture"));
+    assert_eq!(calls.first(), Some(&"begin_paint"));
+    assert_eq!(calls.last(), Some(&"end_paint"));
+}
+
+#[test]
+fn random_input_bytes() {
+    let mut context = UnityContext::new(initializer(), |_| Label);
+    let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+    let mut errors = 0;
+    for len in 1..200 {
+        let bytes: Vec<u8> = (0..len)
+            .map(|_| {
+                seed ^= seed << 13;
+                seed ^= seed >> 7;
+                seed ^= seed << 17;
+                seed as u8
+            })
+            .collect();
+        let buffer = Buffer {
+            data: bytes.as_ptr(),
+            len: bytes.len(),
+        };
+        if let Err(err) = unsafe { context.update(buffer) } {
+            assert!(matches!(err, UeguiError::Parse(_)));
+            assert!(std::error::Error::source(&err).is_some());
+            errors += 1;
+        }
+    }
+    assert!(errors > 0);
+
+    let bytes = pb::Input::new().write_to_bytes().unwrap();
+    let buffer = Buffer {
+        data: bytes.as_ptr(),
+        len: bytes.len(),
+    };
+    let meshes = MESHES.with(|m| m.get());
+    unsafe { context.update(buffer) }.unwrap();
+    assert!(MESHES.with(|m| m.get()) > meshes);
+}
+
+struct Panicking(bool);
+
+impl App for Panicking {
+    fn update(&mut self, ctx: &egui::Context) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.label("app");
+            if self.0 {
+                panic!("broken app");
+            }
+        });
+    }
+}
+
+#[test]
+fn safe_mode_after_repeated_panics() {
+    let mut context = UnityContext::new(initializer(), |_| Panicking(true));
+    for _ in 1..SAFE_MODE_FAILURES {
+        context.run_frame(RawInput::default());
+        assert!(!context.is_safe_mode());
+    }
+    let meshes = MESHES.with(|m| m.get());
+    context.run_frame(RawInput::default());
+    assert!(context.is_safe_mode());
+    assert!(MESHES.with(|m| m.get()) > meshes);
+    context.run_frame(RawInput::default());
+    assert!(context.is_safe_mode());
+
+    context.app_mut().0 = false;
+    context.run_frame(RawInput::default());
+    assert!(!context.is_safe_mode());
+}
+
+#[test]
+fn frame_after_panic_without_events() {
+    let mut context = UnityContext::new(initializer(), |_| Panicking(true));
+    context.run_frame(RawInput {
+        time: Some(1.0),
+        modifiers: Modifiers::SHIFT,
+        events: vec![
+            Event::PointerButton {
+                pos: egui::pos2(10.0, 10.0),
+                button: PointerButton::Primary,
+                pressed: true,
+                modifiers: Modifiers::SHIFT,
+            },
+            Event::Text("a".into()),
+        ],
+        ..Default::default()
+    });
+    // the press is kept from the discarded frame but not seen again
+    context.context().input(|i| {
+        assert!(i.events.is_empty());
+        assert!(i.pointer.primary_down());
+        assert!(!i.pointer.any_pressed());
+        assert_eq!(i.time, 1.0);
+        assert_eq!(i.modifiers, Modifiers::SHIFT);
+    });
+}
+
+struct Exiting(std::rc::Rc<std::cell::Cell<u32>>);
+
+impl App for Exiting {
+    fn update(&mut self, _: &egui::Context) {}
+
+    fn on_exit(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[test]
+fn on_exit_called_once_on_drop() {
+    let exits = std::rc::Rc::new(std::cell::Cell::new(0));
+    let mut context = UnityContext::new(initializer(), |_| Exiting(exits.clone()));
+    context.run_frame(RawInput::default());
+    assert_eq!(exits.get(), 0);
+    drop(context);
+    assert_eq!(exits.get(), 1);
+}
+
+struct Styled;
+
+impl App for Styled {
+    fn setup(&mut self, ctx: &egui::Context) {
+        ctx.set_visuals(egui::Visuals::light());
+    }
+
+    fn update(&mut self, _: &egui::Context) {}
+}
+
+#[test]
+fn setup_configures_context() {
+    let context = UnityContext::new(initializer(), |ctx| {
+        assert!(ctx.style().visuals.dark_mode);
+        Styled
+    });
+    assert!(!context.context().style().visuals.dark_mode);
+}
+
+#[test]
+fn font_gamma_applied_to_font_texture() {
+    let font_alpha = |gamma| {
+        let mut context = UnityContext::new(initializer(), |_| Label);
+        context.set_font_gamma(gamma);
+        context.run_frame(RawInput::default());
+        TEXTURES.with(|t| t.take());
+        TEXTURE_ALPHA.with(|a| a.get())
+    };
+    let gamma = font_alpha(Some(1.0));
+    let linear = font_alpha(None);
+    assert!(linear > gamma);
+    assert_eq!(font_alpha(Some(0.55)), linear);
+}
+
+struct PixelArt(Option<egui::TextureHandle>);
+
+impl App for PixelArt {
+    fn update(&mut self, ctx: &egui::Context) {
+        self.0.get_or_insert_with(|| {
+            let image = egui::ColorImage::new([2, 2], egui::Color32::WHITE);
+            let options = egui::TextureOptions {
+                magnification: egui::TextureFilter::Nearest,
+                minification: egui::TextureFilter::Linear,
+            };
+            ctx.load_texture("pixel art", image, options)
+        });
+    }
+}
+
+#[test]
+fn texture_filters_sent_independently() {
+    let mut context = UnityContext::new(initializer(), |_| PixelArt(None));
+    context.run_frame(RawInput::default());
+    let id = texture_id_to_u64(context.app().0.as_ref().unwrap().id());
+    let batches = BATCHES.with(|b| b.take());
+    let (_, header) = batches[0].iter().find(|(i, _)| *i == id).unwrap();
+    assert_eq!(header[4..], [2, 1]);
+}
+
+struct Quit(bool);
+
+impl App for Quit {
+    fn update(&mut self, _: &egui::Context) {}
+
+    fn update_frame(&mut self, _: &egui::Context, frame: &mut Frame) {
+        if self.0 {
+            frame.close();
+        }
+    }
+}
+
+#[test]
+fn close_requested_from_frame() {
+    let mut context = UnityContext::new(initializer(), |_| Quit(false));
+    context.run_frame(RawInput::default());
+    assert_eq!(CLOSES.with(|c| c.get()), 0);
+
+    context.app_mut().0 = true;
+    context.run_frame(RawInput::default());
+    assert_eq!(CLOSES.with(|c| c.get()), 1);
+
+    context.app_mut().0 = false;
+    context.run_frame(RawInput::default());
+    assert_eq!(CLOSES.with(|c| c.get()), 1);
+}
+
+#[test]
+fn null_callbacks_skipped() {
+    let mut initializer = initializer();
+    initializer.paint_mesh = None;
+    initializer.set_textures_batch = None;
+    initializer.show_log = None;
+    assert_eq!(initializer.missing_callbacks(), ["paint_mesh"]);
+    let optional = initializer.null_optional_callbacks();
+    assert!(optional.contains(&"show_log") && optional.contains(&"set_textures_batch"));
+    let mut context = UnityContext::new(initializer, |_| UserTexture::default());
+    let meshes = MESHES.with(|m| m.get());
+    context.run_frame(RawInput::default());
+    assert_eq!(MESHES.with(|m| m.get()), meshes);
+    // textures fall back to set_texture without set_textures_batch
+    assert_eq!(TEXTURES.with(|t| t.take()).len(), 2);
+    assert!(BATCHES.with(|b| b.take()).is_empty());
+}
+
+#[test]
+fn null_required_callbacks_reported_on_new() {
+    let mut initializer = initializer();
+    initializer.init_log();
+    initializer.paint_mesh = None;
+    initializer.set_textures_batch = None;
+    initializer.set_cursor = None;
+    LOGS.with(|l| l.take());
+    let _context = UnityContext::new(initializer, |_| Label);
+    let errors: Vec<_> = LOGS
+        .with(|l| l.take())
+        .into_iter()
+        .filter(|(level, _)| *level == 1)
+        .collect();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0]
+        .1
+        .ends_with("unity callback `paint_mesh` is null, skipped"));
+}
+
+#[test]
+fn panic_reported_to_unity() {
+    let context = UnityContext::new(initializer(), |_| Label);
+    let err = std::panic::catch_unwind(|| panic!("broken {}", 42)).unwrap_err();
+    assert_eq!(panic_message(&*err), "broken 42");
+    LOGS.with(|l| l.take());
+    context.report_panic(&*err);
+    assert_eq!(
+        LOGS.with(|l| l.take()),
+        [(1, "egui frame aborted by panic:broken 42".to_owned())]
+    );
+}
+
+uegui::init!(prefix = prefixed, Label, |_| Label);
+
+#[test]
+fn prefixed_init() {
+    let egui = prefixed::init(initializer());
+    let update: extern "C" fn(Buffer, *mut std::ffi::c_void, u32) =
+        unsafe { std::mem::transmute(egui.update) };
+    let buffer = Buffer {
+        data: std::ptr::null(),
+        len: 0,
+    };
+    let meshes = MESHES.with(|m| m.get());
+    update(buffer, egui.app, 0);
+    assert!(MESHES.with(|m| m.get()) > meshes);
+    let buffer = Buffer {
+        data: std::ptr::null(),
+        len: 0,
+    };
+    update(buffer, egui.app, 1);
+}
+
+#[test]
+fn null_bytes_from_unity_are_empty() {
+    unsafe {
+        assert!(uegui::bytes(std::ptr::null(), 16).is_empty());
+        assert!(uegui::bytes(std::ptr::NonNull::dangling().as_ptr(), 0).is_empty());
+        assert_eq!(uegui::bytes(b"abc".as_ptr(), 2), b"ab");
+    }
+}
+
+uegui::init!(prefix = fallible, try Label, |_| Ok::<_, String>(Label));
+uegui::init!(prefix = failing, try Label, |_| Err::<Label, _>("no config"));
+
+#[test]
+fn fallible_init() {
+    let egui = fallible::init(initializer());
+    assert!(!egui.app.is_null() && !egui.update.is_null());
+    let update: extern "C" fn(Buffer, *mut std::ffi::c_void, u32) =
+        unsafe { std::mem::transmute(egui.update) };
+    let buffer = Buffer {
+        data: std::ptr::null(),
+        len: 0,
+    };
+    update(buffer, egui.app, 1);
+
+    // keeps the logger registered while no context of `failing` exists
+    let _live = UnityContext::new(initializer(), |_| Label);
+    LOGS.with(|l| l.take());
+    let egui = failing::init(initializer());
+    assert!(egui.app.is_null() && egui.update.is_null());
+    let logs = LOGS.with(|l| l.take());
+    assert!(logs
+        .iter()
+        .any(|(level, log)| *level == 1 && log.ends_with("app init failed:\"no config\"")));
+}
+
+thread_local! {
+    static OTHER_LOGS: std::cell::RefCell<Vec<String>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+extern "system" fn other_show_log(_: i32, message: *const u8, len: i32) {
+    let message = unsafe { std::slice::from_raw_parts(message, len as usize) };
+    let message = String::from_utf8(message.to_vec()).unwrap();
+    OTHER_LOGS.with(|l| l.borrow_mut().push(message));
+}
+
+struct Logging;
+
+impl App for Logging {
+    fn update(&mut self, _: &egui::Context) {
+        log::error!("logged by app");
+    }
+}
+
+#[test]
+fn logs_routed_to_running_context() {
+    let mut other = initializer();
+    other.show_log = Some(other_show_log);
+    let mut first = UnityContext::new(other, |_| Logging);
+    first.init_log();
+    let mut second = UnityContext::new(initializer(), |_| Logging);
+    second.init_log();
+    LOGS.with(|l| l.take());
+
+    first.run_frame(RawInput::default());
+    let logged = |logs: Vec<String>| logs.iter().any(|m| m.ends_with("logged by app"));
+    assert!(logged(OTHER_LOGS.with(|l| l.take())));
+    assert!(!logged(
+        LOGS.with(|l| l.take())
+            .into_iter()
+            .map(|(_, m)| m)
+            .collect()
+    ));
+
+    second.run_frame(RawInput::default());
+    assert!(!logged(OTHER_LOGS.with(|l| l.take())));
+    assert!(logged(
+        LOGS.with(|l| l.take())
+            .into_iter()
+            .map(|(_, m)| m)
+            .collect()
+    ));
+}
+
+#[test]
+fn convert_pb_files() {
+    let mut input = pb::Input::new();
+    let mut hovered = pb::HoveredFile::new();
+    hovered.mime = "image/png".into();
+    input.hovered_files.push(hovered);
+    // desktop only provides a path
+    let mut desktop = pb::DroppedFile::new();
+    desktop.path = "/tmp/icon.png".into();
+    input.dropped_files.push(desktop);
+    // webgl provides name and bytes
+    let mut web = pb::DroppedFile::new();
+    web.name = "data.bin".into();
+    web.bytes = vec![1, 2, 3];
+    input.dropped_files.push(web);
+
+    let input = input_from_pb_to_native(input, &InputOptions::default(), None);
+    assert_eq!(
+        input.hovered_files,
+        [egui::HoveredFile {
+            path: None,
+            mime: "image/png".into(),
+        }]
+    );
+    assert_eq!(input.dropped_files.len(), 2);
+    assert_eq!(
+        input.dropped_files[0].path.as_deref(),
+        Some(std::path::Path::new("/tmp/icon.png"))
+    );
+    assert_eq!(input.dropped_files[0].name, "icon.png");
+    assert!(input.dropped_files[0].bytes.is_none());
+    assert_eq!(input.dropped_files[1].path, None);
+    assert_eq!(input.dropped_files[1].name, "data.bin");
+    assert_eq!(
+        input.dropped_files[1].bytes.as_deref(),
+        Some(&[1, 2, 3][..])
+    );
+}
+
+struct Glyphs(String);
+
+impl App for Glyphs {
+    fn update(&mut self, ctx: &egui::Context) {
+        egui::CentralPanel::default().show(ctx, |ui| ui.label(&self.0));
+    }
+}
+
+#[test]
+fn font_delta_uploads_updated_rect() {
+    let mut context = UnityContext::new(initializer(), |_| Glyphs("a".into()));
+    context.run_frame(RawInput::default());
+    context.run_frame(RawInput::default());
+    let [width, height] = context.context().fonts(|f| f.font_image_size());
+    let rects = TEXTURE_RECTS.with(|r| r.take());
+    assert_eq!(rects[0], [0, 0, width as u32, height as u32]);
+
+    context.app_mut().0 = "λπΩ".into();
+    context.run_frame(RawInput::default());
+    context.run_frame(RawInput::default());
+    let rects = TEXTURE_RECTS.with(|r| r.take());
+    assert!(!rects.is_empty());
+    for [_, _, w, h] in rects {
+        assert!((w * h) < (width * height) as u32 / 4);
+    }
+}
+
+#[test]
+fn vertex_bytes_match_unity_vertex() {
+    let vertex = egui::epaint::Vertex {
+        pos: egui::pos2(1.0, 2.0),
+        uv: egui::pos2(0.25, 0.75),
+        color: egui::Color32::from_rgba_premultiplied(1, 2, 3, 4),
+    };
+    let mut buffer = Vec::new();
+    let vertices = [vertex];
+    let bytes = repack_vertices(&vertices, VertexColorFormat::Color32, &mut buffer);
+    let mut unity = UnityVertex::default();
+    unsafe {
+        std::ptr::copy_nonoverlapping(
+            bytes.as_ptr(),
+            &mut unity as *mut UnityVertex as *mut u8,
+            bytes.len(),
+        )
+    };
+    assert_eq!(unity.pos, [1.0, 2.0]);
+    assert_eq!(unity.uv, [0.25, 0.75]);
+    assert_eq!(unity.color.to_ne_bytes(), [1, 2, 3, 4]);
+}
+
+#[test]
+fn meshes_batched_into_paint_frame() {
+    let mut initializer = initializer();
+    initializer.paint_frame = Some(paint_frame);
+    let mut context = UnityContext::new(initializer, |_| Callbacks);
+    let meshes = MESHES.with(|m| m.get());
+    context.run_frame(RawInput::default());
+    assert_eq!(MESHES.with(|m| m.get()), meshes);
+    assert_eq!(CALLBACKS.with(|c| c.take()), [42]);
+    let frames = FRAMES.with(|f| f.take());
+    assert!(!frames.is_empty());
+    assert!(frames
+        .iter()
+        .flatten()
+        .all(|(id, count)| *id == 0 && count % 3 == 0));
+}
+
+/// Run a frame and count the indices painted through `paint_frame`.
+fn painted_indices<T: App>(context: &mut UnityContext<T>) -> u32 {
+    context.run_frame(RawInput::default());
+    let frames = FRAMES.with(|f| f.take());
+    frames.iter().flatten().map(|(_, count)| count).sum()
+}
+
+#[test]
+fn feathering_configurable() {
+    let mut initializer = initializer();
+    initializer.paint_frame = Some(paint_frame);
+    let mut context = UnityContext::new(initializer, |_| Label);
+    let feathered = painted_indices(&mut context);
+    let mut options = context.tessellation_options();
+    assert!(options.feathering);
+    options.feathering = false;
+    context.set_tessellation_options(options);
+    assert!(painted_indices(&mut context) < feathered);
+    assert!(!context.context().tessellation_options(|o| o.feathering));
+}
+
+#[test]
+fn pixels_per_point_set_at_runtime() {
+    let mut context = UnityContext::new(initializer(), |_| Label);
+    let input = || RawInput {
+        pixels_per_point: Some(1.0),
+        ..Default::default()
+    };
+    context.run_frame(input());
+    assert_eq!(context.context().pixels_per_point(), 1.0);
+    TEXTURE_RECTS.with(|t| t.take());
+
+    for invalid in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+        assert!(!context.set_pixels_per_point(invalid));
+    }
+    assert!(context.set_pixels_per_point(2.0));
+    context.run_frame(input());
+    assert_eq!(context.context().pixels_per_point(), 2.0);
+    // the font atlas is rebuilt for the new scale
+    assert!(!TEXTURE_RECTS.with(|t| t.take()).is_empty());
+}
+
+#[test]
+fn render_target_set_per_context() {
+    let initializer = || UnityInitializer {
+        set_render_target: Some(set_render_target),
+        ..initializer()
+    };
+    let mut hud = UnityContext::new(initializer(), |_| Label);
+    let mut screen = UnityContext::new(initializer(), |_| Label);
+    screen.set_render_target(7);
+    hud.run_frame(RawInput::default());
+    screen.run_frame(RawInput::default());
+    hud.run_frame(RawInput::default());
+    assert_eq!(RENDER_TARGETS.with(|r| r.take()), [0, 7, 0]);
+}
+
+#[test]
+fn context_configured_by_builder() {
+    let initializer = UnityInitializer {
+        set_render_target: Some(set_render_target),
+        ..initializer()
+    };
+    let options = egui::epaint::TessellationOptions {
+        feathering: false,
+        ..Default::default()
+    };
+    let mut context = UnityContext::from_builder(initializer, |_| {
+        UnityContextBuilder::new(Label)
+            .with_gamma(1.5)
+            .with_render_target(3)
+            .with_tessellation_options(options)
+    });
+    assert_eq!(context.tessellation_options(), options);
+    RENDER_TARGETS.with(|r| r.take());
+    context.run_frame(RawInput::default());
+    assert_eq!(RENDER_TARGETS.with(|r| r.take()), [3]);
+}
+
+uegui::init!(prefix = built, Label, |_| UnityContextBuilder::new(Label)
+    .with_gamma(1.2));
+
+#[test]
+fn init_with_builder() {
+    let egui = built::init(initializer());
+    let update: extern "C" fn(Buffer, *mut std::ffi::c_void, u32) =
+        unsafe { std::mem::transmute(egui.update) };
+    let buffer = Buffer {
+        data: std::ptr::null(),
+        len: 0,
+    };
+    update(buffer, egui.app, 1);
+}
+
+/// A small area in the top left corner, the rest of the screen is left to the scene.
+struct CornerArea;
+
+impl App for CornerArea {
+    fn update(&mut self, ctx: &egui::Context) {
+        egui::Area::new("corner")
+            .fixed_pos((0.0, 0.0))
+            .show(ctx, |ui| ui.label("corner"));
+    }
+}
+
+#[test]
+fn pointer_consumed_after_frame() {
+    let initializer = UnityInitializer {
+        pointer_consumed: Some(pointer_consumed),
+        ..initializer()
+    };
+    let mut context = UnityContext::new(initializer, |_| CornerArea);
+    let frame_at = |context: &mut UnityContext<CornerArea>, pos| {
+        context.run_frame(RawInput {
+            screen_rect: Some(egui::Rect::from_min_size(
+                Default::default(),
+                egui::vec2(800.0, 600.0),
+            )),
+            events: vec![Event::PointerMoved(pos)],
+            ..Default::default()
+        });
+        context.pointer_state()
+    };
+    // the area is laid out in the first frame
+    frame_at(&mut context, egui::pos2(5.0, 5.0));
+    assert!(frame_at(&mut context, egui::pos2(5.0, 5.0)).over_area);
+    let scene = frame_at(&mut context, egui::pos2(500.0, 500.0));
+    assert_eq!(scene, PointerState::default());
+    let pointers = POINTERS.with(|p| p.take());
+    assert_eq!(pointers.len(), 3);
+    assert_eq!(pointers[1][0], 1);
+    assert_eq!(pointers[2], [0, 0, 0]);
+}
+
+#[test]
+fn repaint_delay_pushed_every_frame() {
+    let initializer = UnityInitializer {
+        request_repaint_after: Some(request_repaint_after),
+        ..initializer()
+    };
+    let mut context = UnityContext::new(initializer, |_| Label);
+    for _ in 0..3 {
+        context.run_frame(RawInput::default());
+    }
+    let repaints = REPAINTS.with(|r| r.take());
+    assert_eq!(repaints.len(), 3);
+    // a static label needs no repaint once laid out
+    assert_eq!(*repaints.last().unwrap(), u64::MAX);
+    assert_eq!(context.repaint_after_millis(), u64::MAX);
+}
+
+#[test]
+fn frame_stats_reported() {
+    let mut initializer = initializer();
+    initializer.frame_stats = Some(frame_stats);
+    let mut context = UnityContext::new(initializer, |_| Label);
+    let meshes = MESHES.with(|m| m.get());
+    context.run_frame(RawInput::default());
+    let painted = MESHES.with(|m| m.get()) - meshes;
+    let stats = STATS.with(|s| s.take());
+    assert_eq!(stats, [context.stats()]);
+    assert_eq!(stats[0].mesh_count as usize, painted);
+    assert!(stats[0].vertex_count > 0);
+    assert_eq!(stats[0].index_count % 3, 0);
+    assert_eq!(stats[0].texture_bytes, context.texture_memory() as u64);
+
+    context.run_frame(RawInput::default());
+    let stats = STATS.with(|s| s.take());
+    assert_eq!(stats.len(), 1);
+    assert_eq!(stats[0].texture_bytes, 0);
+    assert_eq!(stats[0].mesh_count as usize, painted);
+}
+
+#[test]
+fn frame_captured_on_request() {
+    let mut initializer = initializer();
+    initializer.capture_ready = Some(capture_ready);
+    let mut context = UnityContext::new(initializer, |_| Label);
+    context.run_frame(RawInput::default());
+    assert!(CAPTURES.with(|c| c.take()).is_empty());
+
+    context.request_capture();
+    assert_eq!(context.repaint_after(), std::time::Duration::ZERO);
+    let meshes = MESHES.with(|m| m.get());
+    context.run_frame(RawInput::default());
+    let painted = MESHES.with(|m| m.get()) - meshes;
+    assert_eq!(CAPTURES.with(|c| c.take()), [(0, painted as u32)]);
+
+    // only the requested frame is captured
+    context.run_frame(RawInput::default());
+    assert!(CAPTURES.with(|c| c.take()).is_empty());
+}
+
+#[test]
+fn clip_rect_and_vertices_in_points() {
+    let mut context = UnityContext::new(initializer(), |_| Label);
+    let input = || RawInput {
+        screen_rect: Some(egui::Rect::from_min_size(
+            egui::Pos2::ZERO,
+            egui::vec2(100.0, 50.0),
+        )),
+        pixels_per_point: Some(2.0),
+        ..Default::default()
+    };
+    context.run_frame(input());
+    MESH_BOUNDS.with(|b| b.set((egui::Rect::NOTHING, egui::Rect::NOTHING)));
+    context.run_frame(input());
+    let (clip, bounds) = MESH_BOUNDS.with(|b| b.get());
+    // the central panel covers the screen in points
+    let screen = egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(100.0, 50.0));
+    assert!(screen.expand(1.0).contains_rect(clip));
+    assert!(screen.expand(1.0).contains_rect(bounds));
+    assert!(bounds.width() > 90.0 && bounds.height() > 40.0);
+}
+
+#[test]
+fn chunk_only_frame_keeps_focus() {
+    let mut context = UnityContext::new(initializer(), |_| Label);
+    context.run_frame(RawInput {
+        has_focus: true,
+        modifiers: Modifiers::SHIFT,
+        events: vec![Event::Key {
+            key: Key::A,
+            pressed: true,
+            repeat: false,
+            modifiers: Modifiers::SHIFT,
+        }],
+        ..Default::default()
+    });
+    let mut input = pb::Input::new();
+    let mut file = pb::DroppedFile::new();
+    file.name = "a.txt".into();
+    input.dropped_files.push(file);
+    context.push_input_chunk(&input.write_to_bytes().unwrap(), true);
+    unsafe { context.update(Buffer::null()) }.unwrap();
+    context.context().input(|i| {
+        assert_eq!(i.raw.dropped_files.len(), 1);
+        assert!(i.key_down(Key::A));
+        assert_eq!(i.modifiers, Modifiers::SHIFT);
+    });
+}
+
+#[test]
+fn chunked_input_merged_once_complete() {
+    let mut context = UnityContext::new(initializer(), |_| SingleLine::default());
+    context.run_frame(RawInput::default());
+    context.run_frame(RawInput::default());
+    let mut input = pb::Input::new();
+    for text in ["chunked ", "input"] {
+        let mut event = pb::Event::new();
+        event.et = EnumOrUnknown::new(pb::EventType::TEXT);
+        event.text = text.into();
+        input.events.push(event);
+    }
+    let bytes = input.write_to_bytes().unwrap();
+    let (first, rest) = bytes.split_at(bytes.len() / 2);
+
+    let mut frame = pb::Input::new();
+    frame.time = Some(1.0);
+    let mut event = pb::Event::new();
+    event.et = EnumOrUnknown::new(pb::EventType::TEXT);
+    event.text = "!".into();
+    frame.events.push(event);
+    let frame = frame.write_to_bytes().unwrap();
+    let buffer = || Buffer {
+        data: frame.as_ptr(),
+        len: frame.len(),
+    };
+
+    // a frame before the last chunk only gets its own input
+    context.push_input_chunk(first, false);
+    unsafe { context.update(buffer()) }.unwrap();
+    assert_eq!(context.app().text, "!");
+
+    context.push_input_chunk(rest, true);
+    unsafe {
+        context.update(Buffer {
+            data: std::ptr::null(),
+            len: 0,
+        })
+    }
+    .unwrap();
+    assert_eq!(context.app().text, "!chunked input");
+    unsafe { context.update(buffer()) }.unwrap();
+    assert_eq!(context.app().text, "!chunked input!");
+}
+
+#[test]
+fn max_texture_side_fallback() {
+    let mut context = UnityContext::from_builder(initializer(), |_| {
+        UnityContextBuilder::new(Label).with_max_texture_side(1024)
+    });
+    let update = |context: &mut UnityContext<Label>, max_texture_side: u32| {
+        let mut input = pb::Input::new();
+        input.pixels_per_point = 1.0;
+        input.max_texture_side = max_texture_side;
+        let bytes = input.write_to_bytes().unwrap();
+        unsafe {
+            context.update(Buffer {
+                data: bytes.as_ptr(),
+                len: bytes.len(),
+            })
+        }
+        .unwrap();
+        context.context().fonts(|f| f.max_texture_side())
+    };
+    assert_eq!(update(&mut context, 0), 1024);
+    assert_eq!(update(&mut context, 4096), 4096);
+    assert_eq!(update(&mut context, 0), 1024);
+    context.set_max_texture_side(None);
+    assert_eq!(update(&mut context, 0), 1024);
+}
+
+struct Animated;
+
+impl App for Animated {
+    fn update(&mut self, ctx: &egui::Context) {
+        ctx.request_repaint();
+    }
+}
+
+#[test]
+fn repaints_logged_when_debugging() {
+    let repaints = || {
+        LOGS.with(|l| l.take())
+            .into_iter()
+            .filter(|(_, message)| message.contains("repaint requested at"))
+            .count()
+    };
+    let mut label = UnityContext::new(initializer(), |_| Label);
+    label.set_debug_repaint(true);
+    label.run_frame(RawInput::default());
+    label.run_frame(RawInput::default());
+    LOGS.with(|l| l.take());
+    label.run_frame(RawInput::default());
+    assert_eq!(repaints(), 0);
+
+    let mut animated = UnityContext::new(initializer(), |_| Animated);
+    animated.run_frame(RawInput::default());
+    assert_eq!(repaints(), 0);
+    animated.set_debug_repaint(true);
+    animated.run_frame(RawInput::default());
+    animated.run_frame(RawInput::default());
+    assert_eq!(repaints(), 2);
+}
+
+#[test]
+fn keyboard_text_length_in_utf8_bytes() {
+    let mut context = UnityContext::new(initializer(), |_| SingleLine::default());
+    context.run_frame(RawInput::default());
+    context.run_frame(RawInput::default());
+    KEYBOARD.with(|k| k.take());
+    // 3 bytes each in utf8, the stub decodes the full text only with the byte length
+    context.run_frame(events(vec![Event::Text("你好".into())]));
+    assert_eq!(KEYBOARD.with(|k| k.take()), [(1, Some("你好".to_owned()))]);
+
+    let initializer = UnityInitializer {
+        show_keyboard: None,
+        show_keyboard_utf16: Some(show_keyboard_utf16),
+        ..initializer()
+    };
+    assert!(!initializer
+        .null_optional_callbacks()
+        .contains(&"show_keyboard"));
+    let mut context = UnityContext::new(initializer, |_| SingleLine::default());
+    context.run_frame(RawInput::default());
+    context.run_frame(RawInput::default());
+    KEYBOARD_UTF16.with(|k| k.take());
+    context.run_frame(events(vec![Event::Text("你好😀".into())]));
+    assert_eq!(
+        KEYBOARD_UTF16.with(|k| k.take()),
+        [(1, "你好😀".to_owned(), 4)]
+    );
+    assert!(KEYBOARD.with(|k| k.take()).is_empty());
+}
+
+/// Clears its text edit when three chars were typed, like a chat box on submit.
+#[derive(Default)]
+struct ClearOnSubmit {
+    text: String,
+}
+
+impl App for ClearOnSubmit {
+    fn update(&mut self, _: &egui::Context) {}
+
+    fn update_frame(&mut self, ctx: &egui::Context, frame: &mut Frame) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let output = egui::TextEdit::singleline(&mut self.text).show(ui);
+            if ui.memory(|m| m.focus().is_none()) {
+                output.response.request_focus();
+            }
+        });
+        if self.text.chars().count() == 3 {
+            self.text.clear();
+            frame.set_keyboard_text("");
+        }
+    }
+}
+
+#[test]
+fn keyboard_text_sent_after_app_edit() {
+    let mut initializer = initializer();
+    initializer.set_ime_position = Some(set_ime_position);
+    let mut context = UnityContext::new(initializer, |_| ClearOnSubmit::default());
+    context.run_frame(RawInput::default());
+    context.run_frame(RawInput::default());
+    context.run_frame(events(vec![Event::Text("ab".into())]));
+    KEYBOARD.with(|k| k.take());
+    IME_POSITIONS.with(|p| p.take());
+    context.run_frame(events(vec![Event::Text("c".into())]));
+    assert_eq!(KEYBOARD.with(|k| k.take()), [(1, Some("".to_owned()))]);
+    // sent again even if the cursor did not move yet
+    assert!(!IME_POSITIONS.with(|p| p.take()).is_empty());
+    context.run_frame(RawInput::default());
+    assert!(KEYBOARD.with(|k| k.take()).is_empty());
+}
+
+// panics are always caught in release builds
+#[cfg(debug_assertions)]
+#[test]
+fn panics_propagate_when_not_caught() {
+    let mut context = UnityContext::from_builder(initializer(), |_| {
+        UnityContextBuilder::new(Panicking(true)).with_catch_panics(false)
+    });
+    assert!(!context.catch_panics());
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        context.run_frame(RawInput::default())
+    }));
+    assert_eq!(panic_message(&*result.unwrap_err()), "broken app");
+
+    let mut context = UnityContext::new(initializer(), |_| Panicking(true));
+    assert!(context.catch_panics());
+    context.run_frame(RawInput::default());
+    context.set_catch_panics(false);
+    context.app_mut().0 = false;
+    context.run_frame(RawInput::default());
+    assert!(!context.is_safe_mode());
+}
+
+#[test]
+fn focus_event_releases_modifiers_mid_drag() {
+    let mut context = UnityContext::new(initializer(), |_| HoverButton::default());
+    context.run_frame(RawInput::default());
+    let pos = context.app().rect.unwrap().center();
+    let mut shift = pb::Modifiers::new();
+    shift.shift = true;
+    let frame = |events: Vec<pb::Event>| {
+        let mut input = pb::Input::new();
+        input.has_focus = true;
+        input.modifier = Some(shift.clone()).into();
+        input.events = events;
+        input
+    };
+
+    let mut moved = pb::Event::new();
+    moved.et = EnumOrUnknown::new(pb::EventType::POINTER_MOVED);
+    moved.pointer_moved = Some(pb_pos2(pos.x, pos.y)).into();
+    let mut press = pb::Event::new();
+    press.et = EnumOrUnknown::new(pb::EventType::POINTER_BUTTON);
+    let button = press.pointer_button.mut_or_insert_default();
+    button.pos = Some(pb_pos2(pos.x, pos.y)).into();
+    button.button = EnumOrUnknown::new(pb::ButtonType::PRIMARY);
+    button.pressed = true;
+    let mut key = pb::Event::new();
+    key.et = EnumOrUnknown::new(pb::EventType::KEY);
+    let held = key.key.mut_or_insert_default();
+    held.key = EnumOrUnknown::new(pb::KeyType::A);
+    held.pressed = true;
+    held.modifiers = Some(shift.clone()).into();
+    context.run_pb_frame(frame(vec![moved.clone(), press, key]));
+    let mut drag = moved;
+    drag.pointer_moved = Some(pb_pos2(pos.x + 5.0, pos.y)).into();
+    context.run_pb_frame(frame(vec![drag]));
+    context.context().input(|i| {
+        assert!(i.modifiers.shift);
+        assert!(i.key_down(Key::A));
+        assert!(i.pointer.primary_down());
+    });
+
+    // unity still reports shift in the frame focus is lost
+    let mut blur = pb::Event::new();
+    blur.et = EnumOrUnknown::new(pb::EventType::FOCUS);
+    blur.focused = false;
+    context.run_pb_frame(frame(vec![blur]));
+    context.context().input(|i| {
+        assert!(!i.modifiers.shift);
+        assert!(i.keys_down.is_empty());
+        assert!(!i.pointer.any_down());
+    });
+}
+
+#[test]
+fn input_state_peeked_from_last_frame() {
+    let mut context = UnityContext::new(initializer(), |_| CornerArea);
+    assert_eq!(context.peek_input_state(), 0);
+    let moved = |pos| RawInput {
+        screen_rect: Some(egui::Rect::from_min_size(
+            Default::default(),
+            egui::vec2(800.0, 600.0),
+        )),
+        events: vec![Event::PointerMoved(pos)],
+        ..Default::default()
+    };
+    context.run_frame(moved(egui::pos2(5.0, 5.0)));
+    context.run_frame(moved(egui::pos2(5.0, 5.0)));
+    assert_eq!(context.peek_input_state(), WANTS_POINTER_INPUT);
+    context.run_frame(moved(egui::pos2(500.0, 500.0)));
+    assert_eq!(context.peek_input_state(), 0);
+
+    let mut context = UnityContext::new(initializer(), |_| SingleLine::default());
+    context.run_frame(RawInput::default());
+    context.run_frame(RawInput::default());
+    assert_eq!(context.peek_input_state(), WANTS_KEYBOARD_INPUT | WANTS_IME);
+}
+
+#[test]
+fn pointer_enter_restores_hover() {
+    let mut context = UnityContext::new(initializer(), |_| HoverButton::default());
+    context.run_frame(RawInput::default());
+    let pos = context.app().rect.unwrap().center();
+    let frame = |context: &mut UnityContext<HoverButton>, event: pb::Event| {
+        let mut input = pb::Input::new();
+        input.has_focus = true;
+        input.events.push(event);
+        context.run_pb_frame(input);
+        context.run_frame(events(vec![]));
+        context.app().hovered
+    };
+    let gone = || {
+        let mut event = pb::Event::new();
+        event.et = EnumOrUnknown::new(pb::EventType::POINTER_GONE);
+        event
+    };
+    let mut entered = pb::Event::new();
+    entered.et = EnumOrUnknown::new(pb::EventType::POINTER_ENTERED);
+    entered.pointer_entered = Some(pb_pos2(pos.x, pos.y)).into();
+    assert!(frame(&mut context, entered));
+    assert!(!frame(&mut context, gone()));
+
+    // the last position is used without one
+    let mut entered = pb::Event::new();
+    entered.et = EnumOrUnknown::new(pb::EventType::POINTER_ENTERED);
+    assert!(frame(&mut context, entered));
+    assert!(!frame(&mut context, gone()));
+}
+
+#[test]
+fn input_transform_maps_pointer_and_clip() {
+    let options = InputOptions {
+        transform: InputTransform {
+            offset: egui::vec2(10.0, 0.0),
+            scale: egui::vec2(800.0, 600.0),
+        },
+        ..Default::default()
+    };
+    let mut event = pb::Event::new();
+    event.et = EnumOrUnknown::new(pb::EventType::POINTER_MOVED);
+    event.pointer_moved = Some(pb_pos2(0.5, 0.25)).into();
+    let mut input = pb::Input::new();
+    input.events.push(event);
+    let input = input_from_pb_to_native(input, &options, None);
+    assert_eq!(
+        input.events,
+        [Event::PointerMoved(egui::pos2(410.0, 150.0))]
+    );
+
+    let mut context = UnityContext::new(initializer(), |_| Label);
+    assert!(!context.set_input_transform(InputTransform {
+        scale: egui::vec2(0.0, 1.0),
+        ..Default::default()
+    }));
+    assert!(context.set_input_transform(InputTransform {
+        offset: egui::Vec2::ZERO,
+        scale: egui::vec2(2.0, 2.0),
+    }));
+    MESH_BOUNDS.with(|b| b.set((egui::Rect::NOTHING, egui::Rect::NOTHING)));
+    context.run_frame(RawInput {
+        screen_rect: Some(egui::Rect::from_min_size(
+            Default::default(),
+            egui::vec2(400.0, 300.0),
+        )),
+        ..Default::default()
+    });
+    let (clip, _) = MESH_BOUNDS.with(|b| b.get());
+    assert_eq!(
+        clip,
+        egui::Rect::from_min_size(Default::default(), egui::vec2(200.0, 150.0))
+    );
+}
+
+#[test]
+fn font_fallbacks_skip_missing_fonts() {
+    let mut context = UnityContext::new(initializer(), |_| Label);
+    let hack = egui::FontDefinitions::default().font_data["Hack"]
+        .font
+        .to_vec();
+    context
+        .add_font("cjk".into(), hack, egui::FontFamily::Monospace)
+        .unwrap();
+    context.set_font_fallbacks(
+        egui::FontFamily::Proportional,
+        vec![
+            "Ubuntu-Light".into(),
+            "missing".into(),
+            "cjk".into(),
+            "NotoEmoji-Regular".into(),
+        ],
+    );
+    context.set_font_fallbacks(egui::FontFamily::Monospace, vec!["missing".into()]);
+    LOGS.with(|l| l.take());
+    context.run_frame(RawInput::default());
+    context.run_frame(RawInput::default());
+    let families = context
+        .context()
+        .fonts(|fonts| fonts.lock().fonts.definitions().families.clone());
+    assert_eq!(
+        families[&egui::FontFamily::Proportional],
+        ["Ubuntu-Light", "cjk", "NotoEmoji-Regular"]
+    );
+    assert_eq!(families[&egui::FontFamily::Monospace][0], "cjk");
+    let errors = LOGS
+        .with(|l| l.take())
+        .into_iter()
+        .filter(|(level, message)| *level == 1 && message.contains("missing"))
+        .count();
+    assert_eq!(errors, 2);
+}
+
+struct SmallRect;
+
+impl App for SmallRect {
+    fn update(&mut self, ctx: &egui::Context) {
+        let rect = egui::Rect::from_min_size(egui::pos2(20.0, 20.0), egui::vec2(10.0, 10.0));
+        ctx.layer_painter(egui::LayerId::background())
+            .rect_filled(rect, 0.0, egui::Color32::RED);
+    }
+}
+
+#[test]
+fn clip_rect_narrowed_to_mesh_bounds() {
+    let mut context = UnityContext::new(initializer(), |_| SmallRect);
+    MESH_BOUNDS.with(|b| b.set((egui::Rect::NOTHING, egui::Rect::NOTHING)));
+    context.run_frame(RawInput {
+        screen_rect: Some(egui::Rect::from_min_size(
+            Default::default(),
+            egui::vec2(400.0, 300.0),
+        )),
+        ..Default::default()
+    });
+    let (clip, vertices) = MESH_BOUNDS.with(|b| b.get());
+    assert!(clip.is_positive());
+    assert_eq!(clip, vertices);
+    let rect = egui::Rect::from_min_size(egui::pos2(20.0, 20.0), egui::vec2(10.0, 10.0));
+    assert!(rect.expand(1.0).contains_rect(clip));
+}
+
+struct CopyThenPanic(bool);
+
+impl App for CopyThenPanic {
+    fn update(&mut self, ctx: &egui::Context) {
+        egui::CentralPanel::default().show(ctx, |ui| ui.label("app"));
+        if self.0 {
+            ctx.output_mut(|o| o.copied_text = "aborted".into());
+            panic!("broken app");
+        }
+    }
+}
+
+// panics are always caught in release builds
+#[cfg(debug_assertions)]
+#[test]
+fn frame_ended_after_uncaught_panic() {
+    let mut context = UnityContext::new(initializer(), |_| CopyThenPanic(true));
+    context.set_catch_panics(false);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        context.run_frame(RawInput::default())
+    }));
+    assert!(result.is_err());
+    assert_eq!(context.texture_memory(), 0);
+
+    // output of the aborted frame is dropped except the font atlas, uploaded with the next frame
+    CLIPBOARD.with(|c| c.take());
+    context.app_mut().0 = false;
+    context.run_frame(RawInput::default());
+    assert!(CLIPBOARD.with(|c| c.take()).is_empty());
+    assert!(context.texture_memory() > 0);
+}
+
+#[test]
+fn decreasing_time_advanced_from_last_frame() {
+    let mut context = UnityContext::new(initializer(), |_| Label);
+    let mut times = Vec::new();
+    for time in [10.0, 11.0, 0.5, 0.75, 12.0] {
+        let mut input = pb::Input::new();
+        input.time = Some(time);
+        input.predicted_dt = 0.25;
+        context.run_pb_frame(input);
+        times.push(context.context().input(|i| i.time));
+    }
+    // later frames keep the offset of the reset
+    assert_eq!(times, [10.0, 11.0, 11.25, 11.5, 22.75]);
+}
+
+thread_local! {
+    static EXITS: std::cell::Cell<u32> = const { std::cell::Cell::new(0) };
+}
+
+struct CountedExit;
+
+impl App for CountedExit {
+    fn update(&mut self, _: &egui::Context) {}
+
+    fn on_exit(&mut self) {
+        EXITS.with(|e| e.set(e.get() + 1));
+    }
+}
+
+uegui::init!(prefix = destroyed, CountedExit, |_| CountedExit);
+
+#[test]
+fn destroy_drops_context_once() {
+    let egui = destroyed::init(initializer());
+    let update: extern "C" fn(Buffer, *mut std::ffi::c_void, u32) =
+        unsafe { std::mem::transmute(egui.update) };
+    let buffer = || Buffer {
+        data: std::ptr::null(),
+        len: 0,
+    };
+    update(buffer(), egui.app, 0);
+    EXITS.with(|e| e.set(0));
+    destroyed::destroy(egui.app);
+    assert_eq!(EXITS.with(|e| e.get()), 1);
+    destroyed::destroy(std::ptr::null_mut());
+
+    // destroying through update still works
+    let egui = destroyed::init(initializer());
+    update(buffer(), egui.app, 1);
+    assert_eq!(EXITS.with(|e| e.get()), 2);
+}
+
+struct PanickingDisplay;
+
+impl std::fmt::Display for PanickingDisplay {
+    fn fmt(&self, _: &mut std::fmt::Formatter) -> std::fmt::Result {
+        panic!("display broken")
+    }
+}
+
+struct LogsBroken(bool);
+
+impl App for LogsBroken {
+    fn update(&mut self, _: &egui::Context) {
+        if self.0 {
+            log::error!("{}", PanickingDisplay);
+        }
+    }
+}
+
+#[test]
+fn panic_while_logging_not_logged_again() {
+    let mut context = UnityContext::new(initializer(), |_| LogsBroken(true));
+    context.init_log();
+    LOGS.with(|l| l.take());
+    context.run_frame(RawInput::default());
+    assert!(!LOGS
+        .with(|l| l.take())
+        .iter()
+        .any(|(_, message)| message.contains("display broken")));
+    assert!(!uegui::panicked_in_log());
+
+    // the logger keeps working after the panic
+    context.app_mut().0 = false;
+    context.run_frame(RawInput::default());
+    let err = std::panic::catch_unwind(|| panic!("not in log")).unwrap_err();
+    context.report_panic(&*err);
+    assert!(LOGS
+        .with(|l| l.take())
+        .iter()
+        .any(|(_, message)| message.contains("not in log")));
+}
+
+#[test]
+fn initializer_layout_pinned_to_abi_version() {
+    // a callback appended to `UnityInitializer` needs a new `ABI_VERSION`, update both here
+    // the same with or without the `accesskit` feature
+    assert_eq!(
+        (ABI_VERSION, std::mem::size_of::<UnityInitializer>()),
+        (4, 23 * std::mem::size_of::<usize>())
+    );
+    assert_eq!(
+        std::mem::size_of::<uegui::EGuiInitializer>(),
+        2 * std::mem::size_of::<usize>()
+    );
+    assert_eq!(
+        std::mem::size_of::<Buffer>(),
+        2 * std::mem::size_of::<usize>()
+    );
+}